@@ -6,7 +6,7 @@
 // spell-checker:ignore (vars) krate mangen tldr
 
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Write;
 use std::path::Path;
 
@@ -66,7 +66,7 @@ pub fn main() {
     let mut mf = File::create(Path::new(&out_dir).join("uutils_map.rs")).unwrap();
 
     mf.write_all(
-        "type UtilityMap<T> = phf::OrderedMap<&'static str, (fn(T) -> i32, fn() -> Command)>;\n\
+        "type UtilityMap<T> = phf::OrderedMap<&'static str, (fn(T) -> i32, fn() -> Command, bool)>;\n\
          \n\
          #[allow(clippy::too_many_lines)]
          #[allow(clippy::unreadable_literal)]
@@ -79,7 +79,8 @@ pub fn main() {
     let mut entries = Vec::new();
 
     for krate in &crates {
-        let map_value = format!("({krate}::uumain, {krate}::uu_app)");
+        let flush = flushes_stdout(krate.strip_prefix(OVERRIDE_PREFIX).unwrap_or(krate));
+        let map_value = format!("({krate}::uumain, {krate}::uu_app, {flush})");
         match krate.as_ref() {
             // 'test' is named uu_test to avoid collision with rust core crate 'test'.
             // It can also be invoked by name '[' for the '[ expr ] syntax'.
@@ -93,7 +94,7 @@ pub fn main() {
             "false" | "true" => {
                 entries.push((
                     krate.as_str(),
-                    format!("(r#{krate}::uumain, r#{krate}::uu_app)"),
+                    format!("(r#{krate}::uumain, r#{krate}::uu_app, {flush})"),
                 ));
             }
             _ => {
@@ -112,3 +113,12 @@ pub fn main() {
 
     mf.flush().unwrap();
 }
+
+/// Whether the utility flushes stdout on exit, i.e. its binary does not use
+/// `uucore::bin!(…, no_flush)`. The multicall binary reads this from the map so
+/// that it behaves like the individual binary.
+fn flushes_stdout(dir: &str) -> bool {
+    let main = Path::new("src/uu").join(dir).join("src/main.rs");
+    println!("cargo:rerun-if-changed={}", main.display());
+    !fs::read_to_string(&main).unwrap().contains("no_flush")
+}
@@ -0,0 +1,16 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use uucore::mode::parse_chmod;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        _ = parse_chmod(0o644, s, false, 0o022);
+        _ = parse_chmod(0o755, s, true, 0o022);
+    }
+});
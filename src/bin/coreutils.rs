@@ -4,7 +4,7 @@
 // file that was distributed with this source code.
 
 use clap::Command;
-use coreutils::validation;
+use coreutils::{links, validation};
 use itertools::Itertools as _;
 use std::cmp;
 use std::ffi::OsString;
@@ -33,9 +33,12 @@ Functions:
 
 Usage: {name} [function [arguments...]]
        {name} --list
+       {name} --install [--symlink|--hardlink] DIR
 
 {common_core_string}Options:
       --list    lists all defined functions, one per row
+      --install create a link to this binary in DIR for each function,
+                symbolic unless --hardlink is given
 
 Currently defined functions:
 
@@ -101,6 +104,10 @@ fn main() {
                 }
                 process::exit(0);
             }
+            "--install" => {
+                let names: Vec<&str> = utils.keys().copied().collect();
+                process::exit(links::install(&names, args));
+            }
             "--version" | "-V" => {
                 if let Err(e) = writeln!(io::stdout(), "coreutils {VERSION} (multi-call binary)")
                     && e.kind() != io::ErrorKind::BrokenPipe
@@ -115,14 +122,21 @@ fn main() {
         }
 
         match utils.get(util) {
-            Some(&(uumain, _)) => {
+            Some(&(uumain, _, flush)) => {
                 // TODO: plug the deactivation of the translation
                 // and load the English strings directly at compilation time in the
                 // binary to avoid the load of the flt
                 // Could be something like:
                 // #[cfg(not(feature = "only_english"))]
                 validation::setup_localization_or_exit(util);
-                process::exit(uumain(vec![util_os].into_iter().chain(args)));
+                // Restrict the process only once localization files are loaded.
+                #[cfg(feature = "feat_sandbox")]
+                uucore::sandbox::enter(util);
+                let code = uumain(vec![util_os].into_iter().chain(args));
+                if !flush {
+                    process::exit(code);
+                }
+                process::exit(uucore::close_stdout(code));
             }
             None => {
                 // GNU coreutils --help string shows help for coreutils
@@ -113,7 +113,7 @@ fn usage<T: Args>(utils: &UtilityMap<T>) {
 /// Generates the coreutils app for the utility map
 fn gen_coreutils_app<T: Args>(util_map: &UtilityMap<T>) -> Command {
     let mut command = Command::new("coreutils");
-    for (name, (_, sub_app)) in util_map {
+    for (name, (_, sub_app, _)) in util_map {
         // Recreate a small subcommand with only the relevant info
         // (name & short description)
         let about = sub_app()
@@ -360,7 +360,7 @@ fn main() -> io::Result<()> {
     }
 
     println!("Writing to utils");
-    for (&name, (_, command)) in utils {
+    for (&name, (_, command, _)) in utils {
         let (utils_name, usage_name, command) = match name {
             "[" => {
                 continue;
@@ -0,0 +1,133 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Creation of the links through which the multicall binary runs each utility.
+
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write, stderr};
+use std::path::{Path, PathBuf};
+
+use uucore::display::Quotable;
+use uucore::error::strip_errno;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LinkKind {
+    Symbolic,
+    Hard,
+}
+
+/// Handles `coreutils --install [--symlink|--hardlink] DIR`.
+///
+/// Creates a link named after each of `utils` in `DIR` that points to the
+/// running binary, symbolic unless `--hardlink` is given. Links that already
+/// lead to the binary are kept, and any other existing file is reported and
+/// left alone. Returns the exit code.
+pub fn install(utils: &[&str], args: impl Iterator<Item = OsString>) -> i32 {
+    let mut kind = LinkKind::Symbolic;
+    let mut dir = None;
+    for arg in args {
+        if arg == "--symlink" || arg == "-s" {
+            kind = LinkKind::Symbolic;
+        } else if arg == "--hardlink" {
+            kind = LinkKind::Hard;
+        } else if dir.is_none() {
+            dir = Some(PathBuf::from(arg));
+        } else {
+            let _ = writeln!(stderr(), "coreutils: extra operand {}", arg.quote());
+            return 1;
+        }
+    }
+    let Some(dir) = dir else {
+        let _ = writeln!(stderr(), "coreutils: missing directory operand");
+        return 1;
+    };
+
+    let binary = match std::env::current_exe().and_then(fs::canonicalize) {
+        Ok(binary) => binary,
+        Err(e) => {
+            let _ = writeln!(
+                stderr(),
+                "coreutils: cannot locate the running binary: {}",
+                strip_errno(&e)
+            );
+            return 1;
+        }
+    };
+    let target = match link_target(&dir, &binary) {
+        Ok(target) => target,
+        Err(e) => {
+            let _ = writeln!(
+                stderr(),
+                "coreutils: cannot access {}: {}",
+                dir.quote(),
+                strip_errno(&e)
+            );
+            return 1;
+        }
+    };
+
+    let mut status = 0;
+    for util in utils {
+        let link = dir.join(format!("{util}{}", std::env::consts::EXE_SUFFIX));
+        if is_same_file(&link, &binary) {
+            continue;
+        }
+        let result = match kind {
+            LinkKind::Symbolic => symlink(&target, &link),
+            LinkKind::Hard => fs::hard_link(&binary, &link),
+        };
+        if let Err(e) = result {
+            let _ = writeln!(
+                stderr(),
+                "coreutils: cannot create link {}: {}",
+                link.quote(),
+                strip_errno(&e)
+            );
+            status = 1;
+        }
+    }
+    status
+}
+
+/// The path symbolic links in `dir` should point to. It is relative when the
+/// binary is in `dir` itself, so that the links stay valid if the directory
+/// is moved.
+fn link_target(dir: &Path, binary: &Path) -> io::Result<PathBuf> {
+    let dir = fs::canonicalize(dir)?;
+    match binary.file_name() {
+        Some(name) if binary.parent() == Some(dir.as_path()) => Ok(PathBuf::from(name)),
+        _ => Ok(binary.to_path_buf()),
+    }
+}
+
+#[cfg(unix)]
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    match (fs::metadata(a), fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    fs::canonicalize(a).is_ok_and(|a| a == b)
+}
+
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn symlink(target: &Path, link: &Path) -> io::Result<()> {
+    std::os::windows::fs::symlink_file(target, link)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn symlink(_target: &Path, _link: &Path) -> io::Result<()> {
+    Err(io::ErrorKind::Unsupported.into())
+}
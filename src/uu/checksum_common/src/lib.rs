@@ -187,6 +187,10 @@ pub fn checksum_main(
         if binary_flag || text_flag {
             return Err(ChecksumError::BinaryTextConflict.into());
         }
+        // Check files are always read line by line, so GNU rejects --zero.
+        if matches.get_flag(options::ZERO) {
+            return Err(ChecksumError::ZeroCheck.into());
+        }
 
         // Execute the checksum validation based on the presence of files or the use of stdin
 
@@ -36,6 +36,8 @@ enum ChmodError {
     NoSuchFile(PathBuf),
     #[error("{}", translate!("chmod-error-preserve-root", "file" => _0.quote()))]
     PreserveRoot(PathBuf),
+    #[error("{}", translate!("chmod-error-preserve-root-alias", "file" => _0.quote()))]
+    PreserveRootAlias(PathBuf),
     #[error("{}", translate!("chmod-error-permission-denied", "file" => _0.quote()))]
     PermissionDenied(PathBuf),
     #[error("{}", translate!("chmod-error-new-permissions", "file" => _0.maybe_quote(), "actual" => _1.clone(), "expected" => _2.clone()))]
@@ -421,7 +423,12 @@ impl Chmoder {
     }
 
     fn is_root(file: impl AsRef<Path>) -> bool {
+        // A bind mount of '/' doesn't canonicalize to '/', but it is the same directory.
         matches!(fs::canonicalize(&file), Ok(p) if p == Path::new("/"))
+            || matches!(
+                (FileInformation::from_path(&file, true), FileInformation::from_path("/", true)),
+                (Ok(file), Ok(root)) if file == root
+            )
     }
 
     // Non-safe traversal implementation for platforms without safe_traversal support
@@ -552,6 +559,11 @@ impl Chmoder {
         }
 
         let entries = dir_fd.read_dir()?;
+        let root_meta = if self.preserve_root {
+            fs::metadata("/").ok()
+        } else {
+            None
+        };
 
         // Determine if we should follow symlinks (doesn't depend on entry_name)
         let should_follow_symlink = self.traverse_symlinks == TraverseSymlinks::All;
@@ -572,6 +584,17 @@ impl Chmoder {
                 continue;
             };
 
+            // Like GNU, refuse to descend into '/' reached through a symlink (with -L)
+            // or a bind mount.
+            if meta.is_dir()
+                && root_meta
+                    .as_ref()
+                    .is_some_and(|root| root.dev() == meta.dev() && root.ino() == meta.ino())
+            {
+                show!(ChmodError::PreserveRootAlias(entry_path));
+                continue;
+            }
+
             if entry_path.is_symlink() {
                 r = self
                     .handle_symlink_during_safe_recursion(
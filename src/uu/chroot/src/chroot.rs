@@ -13,7 +13,7 @@ use std::io::{Error, ErrorKind};
 use std::os::unix::process::CommandExt;
 use std::path::{Path, PathBuf};
 use std::process;
-use uucore::entries::{Locate, Passwd, grp2gid, usr2gid, usr2uid};
+use uucore::entries::{Locate, Passwd, grp2gid, usr2uid};
 use uucore::error::{UResult, UUsageError};
 use uucore::fs::{MissingHandling, ResolveMode, canonicalize};
 use uucore::libc::{self, setgid, setgroups, setuid};
@@ -258,7 +258,11 @@ pub fn uu_app() -> Command {
 /// According to the documentation of GNU `chroot`, "POSIX requires that
 /// these commands first attempt to resolve the specified string as a
 /// name, and only once that fails, then try to interpret it as an ID."
+/// A leading `+` skips the name lookup.
 fn name_to_uid(name: &str) -> Result<libc::uid_t, ChrootError> {
+    if let Some(id) = name.strip_prefix('+') {
+        return id.parse().map_err(|_| ChrootError::NoSuchUser);
+    }
     match usr2uid(name) {
         Ok(uid) => Ok(uid),
         Err(_) => name
@@ -272,7 +276,11 @@ fn name_to_uid(name: &str) -> Result<libc::uid_t, ChrootError> {
 /// According to the documentation of GNU `chroot`, "POSIX requires that
 /// these commands first attempt to resolve the specified string as a
 /// name, and only once that fails, then try to interpret it as an ID."
+/// A leading `+` skips the name lookup.
 fn name_to_gid(name: &str) -> Result<libc::gid_t, ChrootError> {
+    if let Some(id) = name.strip_prefix('+') {
+        return id.parse().map_err(|_| ChrootError::NoSuchGroup);
+    }
     match grp2gid(name) {
         Ok(gid) => Ok(gid),
         Err(_) => name
@@ -281,16 +289,12 @@ fn name_to_gid(name: &str) -> Result<libc::gid_t, ChrootError> {
     }
 }
 
-/// Get the list of group IDs for the given user.
-///
-/// According to the GNU documentation, "the supplementary groups are
-/// set according to the system defined list for that user". This
-/// function gets that list.
-fn supplemental_gids(uid: libc::uid_t) -> Vec<libc::gid_t> {
-    match Passwd::locate(uid) {
-        Err(_) => vec![],
-        Ok(passwd) => passwd.belongs_to(),
-    }
+/// Get the GIDs of the groups given to `--groups`.
+fn group_list_to_gids(groups: &[String]) -> Result<Vec<libc::gid_t>, ChrootError> {
+    groups
+        .iter()
+        .map(|group| name_to_gid(group).map_err(|_| ChrootError::InvalidGroup(group.clone())))
+        .collect()
 }
 
 /// Set the supplemental group IDs for this process.
@@ -333,86 +337,83 @@ fn set_uid(uid: libc::uid_t) -> std::io::Result<()> {
     }
 }
 
-/// What to do when the `--groups` argument is missing.
-enum Strategy {
-    /// Do nothing.
-    Nothing,
-    /// Use the list of supplemental groups for the given user.
-    ///
-    /// If the `bool` parameter is `false` and the list of groups for
-    /// the given user is empty, then this will result in an error.
-    FromUID(libc::uid_t, bool),
+/// The IDs of the user and group given to `--userspec`, each `None` if it
+/// was not given.
+struct ResolvedUserSpec {
+    uid: Option<libc::uid_t>,
+    gid: Option<libc::gid_t>,
+    /// The passwd entry of the user, if it has one.
+    passwd: Option<Passwd>,
 }
 
-/// Set supplemental groups when the `--groups` argument is not specified.
-fn handle_missing_groups(strategy: Strategy) -> Result<(), ChrootError> {
-    match strategy {
-        Strategy::Nothing => Ok(()),
-        Strategy::FromUID(uid, false) => {
-            let gids = supplemental_gids(uid);
-            if gids.is_empty() {
-                Err(ChrootError::NoGroupSpecified(uid))
-            } else {
-                set_supplemental_gids(&gids).map_err(ChrootError::SetGroupsFailed)
-            }
-        }
-        Strategy::FromUID(uid, true) => {
-            let gids = supplemental_gids(uid);
-            set_supplemental_gids(&gids).map_err(ChrootError::SetGroupsFailed)
+/// Resolve the user and group given to `--userspec` with the user and group
+/// databases of the current root directory.
+///
+/// When only a user is given, its group is the one of its passwd entry, so a
+/// numeric user ID without an entry needs a group too.
+fn resolve_userspec(userspec: Option<&UserSpec>) -> Result<ResolvedUserSpec, ChrootError> {
+    let (uid, gid) = match userspec {
+        None | Some(UserSpec::NeitherGroupNorUser) => (None, None),
+        Some(UserSpec::UserOnly(user)) => (Some(name_to_uid(user)?), None),
+        Some(UserSpec::GroupOnly(group)) => (None, Some(name_to_gid(group)?)),
+        Some(UserSpec::UserAndGroup(user, group)) => {
+            (Some(name_to_uid(user)?), Some(name_to_gid(group)?))
         }
-    }
-}
+    };
 
-/// Set supplemental groups for this process.
-fn set_supplemental_gids_with_strategy(
-    strategy: Strategy,
-    groups: Option<&Vec<String>>,
-) -> Result<(), ChrootError> {
-    match groups {
-        None => handle_missing_groups(strategy),
-        Some(groups) => {
-            let mut gids = vec![];
-            for group in groups {
-                gids.push(name_to_gid(group)?);
-            }
-            set_supplemental_gids(&gids).map_err(ChrootError::SetGroupsFailed)
-        }
-    }
+    let passwd = uid.and_then(|uid| Passwd::locate(uid).ok());
+    let gid = match (uid, gid) {
+        (Some(uid), None) => Some(
+            passwd
+                .as_ref()
+                .ok_or(ChrootError::NoGroupSpecified(uid))?
+                .gid,
+        ),
+        _ => gid,
+    };
+    Ok(ResolvedUserSpec { uid, gid, passwd })
 }
 
 /// Change the root, set the user ID, and set the group IDs for this process.
+///
+/// Like GNU, users and groups are looked up both before and after changing
+/// the root. The first lookup loads the NSS modules which may be missing in
+/// the new root, and its result for `--groups` is kept in case the groups
+/// don't exist there. The second one is authoritative, as the IDs may differ
+/// in the new root.
 fn set_context(options: &Options) -> UResult<()> {
-    match &options.userspec {
-        None | Some(UserSpec::NeitherGroupNorUser) => {
-            let strategy = Strategy::Nothing;
-            set_supplemental_gids_with_strategy(strategy, options.groups.as_ref())?;
-            enter_chroot(&options.newroot, options.skip_chdir)?;
-        }
-        Some(UserSpec::UserOnly(user)) => {
-            let uid = name_to_uid(user)?;
-            let gid = usr2gid(user).map_err(|_| ChrootError::NoGroupSpecified(uid))?;
-            let strategy = Strategy::FromUID(uid, false);
-            set_supplemental_gids_with_strategy(strategy, options.groups.as_ref())?;
-            enter_chroot(&options.newroot, options.skip_chdir)?;
-            set_gid(gid).map_err(|e| ChrootError::SetGidFailed(user.to_owned(), e))?;
-            set_uid(uid).map_err(|e| ChrootError::SetUserFailed(user.to_owned(), e))?;
-        }
-        Some(UserSpec::GroupOnly(group)) => {
-            let gid = name_to_gid(group)?;
-            let strategy = Strategy::Nothing;
-            set_supplemental_gids_with_strategy(strategy, options.groups.as_ref())?;
-            enter_chroot(&options.newroot, options.skip_chdir)?;
-            set_gid(gid).map_err(|e| ChrootError::SetGidFailed(group.to_owned(), e))?;
-        }
-        Some(UserSpec::UserAndGroup(user, group)) => {
-            let uid = name_to_uid(user)?;
-            let gid = name_to_gid(group)?;
-            let strategy = Strategy::FromUID(uid, true);
-            set_supplemental_gids_with_strategy(strategy, options.groups.as_ref())?;
-            enter_chroot(&options.newroot, options.skip_chdir)?;
-            set_gid(gid).map_err(|e| ChrootError::SetGidFailed(group.to_owned(), e))?;
-            set_uid(uid).map_err(|e| ChrootError::SetUserFailed(user.to_owned(), e))?;
-        }
+    let _ = resolve_userspec(options.userspec.as_ref());
+    let outside_groups = options.groups.as_deref().map(group_list_to_gids);
+
+    enter_chroot(&options.newroot, options.skip_chdir)?;
+
+    let ResolvedUserSpec { uid, gid, passwd } = resolve_userspec(options.userspec.as_ref())?;
+    let groups = match options.groups.as_deref().map(group_list_to_gids) {
+        Some(Ok(gids)) => Some(gids),
+        Some(Err(e)) => match outside_groups {
+            Some(Ok(gids)) if !gids.is_empty() => Some(gids),
+            _ => return Err(e.into()),
+        },
+        // Without --groups, a user gets the groups it is a member of along
+        // with its group, or none if it has no passwd entry.
+        None => uid.map(|_| {
+            passwd
+                .map(|mut passwd| {
+                    passwd.gid = gid.unwrap_or(passwd.gid);
+                    passwd.belongs_to()
+                })
+                .unwrap_or_default()
+        }),
+    };
+
+    if let Some(groups) = groups {
+        set_supplemental_gids(&groups).map_err(ChrootError::SetGroupsFailed)?;
+    }
+    if let Some(gid) = gid {
+        set_gid(gid).map_err(|e| ChrootError::SetGidFailed(gid.to_string(), e))?;
+    }
+    if let Some(uid) = uid {
+        set_uid(uid).map_err(|e| ChrootError::SetUserFailed(uid.to_string(), e))?;
     }
     Ok(())
 }
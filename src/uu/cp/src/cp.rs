@@ -2549,7 +2549,7 @@ fn copy_file(
                 OverwriteMode::Clobber(ClobberMode::RemoveDestination)
             )
             && !is_symlink_loop(dest)
-            && std::env::var_os("POSIXLY_CORRECT").is_none()
+            && !uucore::posix::posixly_correct()
         {
             return Err(CpError::Error(
                 translate!("cp-error-not-writing-dangling-symlink", "dest" => dest.quote()),
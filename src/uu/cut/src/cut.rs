@@ -5,6 +5,7 @@
 
 // spell-checker:ignore (ToDO) delim sourcefiles undelimited
 
+use bstr::ByteSlice;
 use bstr::io::BufReadExt;
 use clap::{Arg, ArgAction, ArgMatches, Command, builder::ValueParser};
 use std::ffi::OsString;
@@ -13,6 +14,7 @@ use std::io::{BufRead, BufReader, BufWriter, IsTerminal, Read, Write, stdin, std
 use std::path::Path;
 use uucore::display::Quotable;
 use uucore::error::{FromIo, UResult, USimpleError, set_exit_code};
+use uucore::i18n::{UEncoding, get_ctype_encoding};
 use uucore::line_ending::LineEnding;
 use uucore::os_str_as_bytes;
 
@@ -104,6 +106,60 @@ fn cut_bytes<R: Read, W: Write>(
     Ok(())
 }
 
+/// Select characters rather than bytes in UTF-8 locales. Each byte of an invalid sequence is a
+/// character of its own, like in the C locale.
+fn cut_characters<R: Read, W: Write>(
+    reader: R,
+    out: &mut W,
+    ranges: &[Range],
+    opts: &Options,
+) -> UResult<()> {
+    if get_ctype_encoding() != UEncoding::Utf8 {
+        return cut_bytes(reader, out, ranges, opts);
+    }
+
+    let newline_char = opts.line_ending.into();
+    let mut buf_in = BufReader::new(reader);
+    let out_delim = opts.out_delimiter.unwrap_or(b"\t");
+    // Byte offset of each character of the line, followed by the length of the line
+    let mut offsets = Vec::new();
+
+    let result = buf_in.for_byte_record(newline_char, |line| {
+        offsets.clear();
+        for (start, end, ch) in line.char_indices() {
+            if ch == char::REPLACEMENT_CHARACTER && &line[start..end] != "\u{FFFD}".as_bytes() {
+                offsets.extend(start..end);
+            } else {
+                offsets.push(start);
+            }
+        }
+        let char_count = offsets.len();
+        offsets.push(line.len());
+
+        let mut print_delim = false;
+        for &Range { low, high } in ranges {
+            if low > char_count {
+                break;
+            }
+            if print_delim {
+                out.write_all(out_delim)?;
+            } else if opts.out_delimiter.is_some() {
+                print_delim = true;
+            }
+            let high = high.min(char_count);
+            out.write_all(&line[offsets[low - 1]..offsets[high]])?;
+        }
+        out.write_all(&[newline_char])?;
+        Ok(true)
+    });
+
+    if let Err(e) = result {
+        return Err(USimpleError::new(1, e.to_string()));
+    }
+
+    Ok(())
+}
+
 /// Output delimiter is explicitly specified
 fn cut_fields_explicit_out_delim<R: Read, W: Write, M: Matcher>(
     reader: R,
@@ -459,7 +515,7 @@ where
 
             show_if_err!(match mode {
                 Mode::Bytes(ranges, opts) => cut_bytes(stdin(), &mut out, ranges, opts),
-                Mode::Characters(ranges, opts) => cut_bytes(stdin(), &mut out, ranges, opts),
+                Mode::Characters(ranges, opts) => cut_characters(stdin(), &mut out, ranges, opts),
                 Mode::Fields(ranges, opts) => cut_fields(stdin(), &mut out, ranges, opts),
             });
 
@@ -482,8 +538,9 @@ where
                     .map_err_context(|| filename.maybe_quote().to_string())
                     .and_then(|file| {
                         match &mode {
-                            Mode::Bytes(ranges, opts) | Mode::Characters(ranges, opts) => {
-                                cut_bytes(file, &mut out, ranges, opts)
+                            Mode::Bytes(ranges, opts) => cut_bytes(file, &mut out, ranges, opts),
+                            Mode::Characters(ranges, opts) => {
+                                cut_characters(file, &mut out, ranges, opts)
                             }
                             Mode::Fields(ranges, opts) => cut_fields(file, &mut out, ranges, opts),
                         }
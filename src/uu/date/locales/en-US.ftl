@@ -105,6 +105,8 @@ date-error-setting-date-not-supported-macos = setting the date is not supported
 date-error-setting-date-not-supported-redox = setting the date is not supported by Redox
 date-error-cannot-set-date = cannot set date
 date-error-extra-operand = extra operand '{$operand}'
+date-error-multiple-date-sources = the options to specify dates for printing are mutually exclusive
+date-error-print-and-set = the options to print and set the time may not be used together
 date-error-write = write error: {$error}
 date-error-format-modifier-width-too-large = format modifier width '{$width}' is too large for specifier '%{$specifier}'
 date-error-format-missing-plus = the argument {$arg} lacks a leading '+';
@@ -100,6 +100,8 @@ date-error-setting-date-not-supported-macos = la définition de la date n'est pa
 date-error-setting-date-not-supported-redox = la définition de la date n'est pas prise en charge par Redox
 date-error-cannot-set-date = impossible de définir la date
 date-error-extra-operand = opérande supplémentaire '{$operand}'
+date-error-multiple-date-sources = les options pour spécifier les dates à afficher sont mutuellement exclusives
+date-error-print-and-set = les options pour afficher et définir l'heure ne peuvent pas être utilisées ensemble
 date-error-write = erreur d'écriture: {$error}
 date-error-format-modifier-width-too-large = la largeur du modificateur de format '{$width}' est trop grande pour le spécificateur '%{$specifier}'
 date-error-format-missing-plus = l'argument {$arg} ne commence pas par un signe '+';
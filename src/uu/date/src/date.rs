@@ -20,7 +20,8 @@ use std::path::PathBuf;
 use std::sync::OnceLock;
 use uucore::display::Quotable;
 use uucore::error::FromIo;
-use uucore::error::{UResult, USimpleError};
+use uucore::error::{UResult, USimpleError, UUsageError};
+use uucore::format::show_spec_context;
 #[cfg(feature = "i18n-datetime")]
 use uucore::i18n::datetime::{localize_format_string, should_use_icu_locale};
 use uucore::translate;
@@ -68,13 +69,16 @@ struct DebugOptions {
     debug: bool,
     /// Warn when midnight is used without explicit time specification
     warn_midnight: bool,
+    /// Dates are interpreted and shown in UTC (`-u`)
+    utc: bool,
 }
 
 impl DebugOptions {
-    fn new(debug: bool, warn_midnight: bool) -> Self {
+    fn new(debug: bool, warn_midnight: bool, utc: bool) -> Self {
         Self {
             debug,
             warn_midnight,
+            utc,
         }
     }
 }
@@ -223,6 +227,107 @@ fn strip_parenthesized_comments(input: &str) -> Cow<'_, str> {
     Cow::Owned(result)
 }
 
+/// Month and weekday abbreviations that GNU also accepts with a trailing period ("Mon.", "Sep.").
+const PERIOD_ABBREVIATIONS: &[&str] = &[
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec", "sun",
+    "mon", "tue", "wed", "thu", "fri", "sat",
+];
+
+/// Relative units that "hence" may follow, without their plural 's'.
+const RELATIVE_UNITS: &[&str] = &[
+    "year",
+    "month",
+    "fortnight",
+    "week",
+    "day",
+    "hour",
+    "minute",
+    "min",
+    "second",
+    "sec",
+];
+
+/// Rewrite the words of the GNU date grammar that the parser does not know into equivalent ones.
+///
+/// - "hence" after a relative item is the counterpart of "ago" and changes nothing:
+///   "2 days hence" -> "2 days "
+/// - A three-letter month or weekday abbreviation may be followed by a period:
+///   "Mon. Mar.9" -> "Mon  Mar 9"
+///
+/// Words are runs of letters and periods, as in GNU's lexer.
+fn normalize_gnu_words(input: &str) -> Cow<'_, str> {
+    let mut result = String::with_capacity(input.len());
+    let mut changed = false;
+    // The previous word, if only whitespace separates it from the current position.
+    let mut previous: Option<&str> = None;
+    let mut rest = input;
+
+    while let Some(c) = rest.chars().next() {
+        if !c.is_ascii_alphabetic() {
+            if !c.is_whitespace() {
+                previous = None;
+            }
+            result.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic() && c != '.')
+            .unwrap_or(rest.len());
+        let (word, tail) = rest.split_at(len);
+        rest = tail;
+
+        let lower = word.to_ascii_lowercase();
+        if lower == "hence"
+            && previous.is_some_and(|prev| {
+                let prev = prev.to_ascii_lowercase();
+                let unit = prev.strip_suffix('s').unwrap_or(&prev);
+                RELATIVE_UNITS.contains(&unit)
+            })
+        {
+            changed = true;
+        } else if let Some(abbreviation) = lower.strip_suffix('.')
+            && PERIOD_ABBREVIATIONS.contains(&abbreviation)
+        {
+            result.push_str(&word[..3]);
+            result.push(' ');
+            changed = true;
+        } else {
+            result.push_str(word);
+        }
+        previous = Some(word);
+    }
+
+    if changed {
+        Cow::Owned(result)
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Split a leading `TZ="ZONE"` off a date string, like GNU's
+/// `TZ="America/New_York" tomorrow`. Within the quotes, `\\` and `\"` escape a
+/// backslash and a quote.
+///
+/// Returns the zone name, the zone and the rest of the string, or `None` if there is no such
+/// prefix or the zone is neither a known time zone name nor a valid POSIX `TZ` string.
+fn split_tz_prefix(input: &str) -> Option<(String, TimeZone, &str)> {
+    let quoted = input.strip_prefix("TZ=\"")?;
+    let mut name = String::new();
+    let mut chars = quoted.char_indices();
+    let end = loop {
+        match chars.next()? {
+            (_, '\\') => name.push(chars.next()?.1),
+            (i, '"') => break i,
+            (_, c) => name.push(c),
+        }
+    };
+    let tz = TimeZone::get(&name)
+        .or_else(|_| TimeZone::posix(&name))
+        .ok()?;
+    Some((name, tz, &quoted[end + 1..]))
+}
+
 /// Parse military timezone with optional hour offset.
 /// Pattern: single letter (a-z except j) optionally followed by 1-2 digits.
 /// Returns Some(total_hours_in_utc) or None if pattern doesn't match.
@@ -285,6 +390,22 @@ fn parse_military_timezone_with_offset(s: &str) -> Option<(i32, DayDelta)> {
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let matches = uucore::clap_localization::handle_clap_result(uu_app(), args)?;
 
+    // Like GNU, report conflicting date sources with a usage error rather than clap's message.
+    let date_sources = [OPT_DATE, OPT_FILE, OPT_REFERENCE]
+        .into_iter()
+        .filter(|id| matches.contains_id(id))
+        .count()
+        + usize::from(matches.get_flag(OPT_RESOLUTION));
+    if date_sources > 1 {
+        return Err(UUsageError::new(
+            1,
+            translate!("date-error-multiple-date-sources"),
+        ));
+    }
+    if date_sources > 0 && matches.contains_id(OPT_SET) {
+        return Err(UUsageError::new(1, translate!("date-error-print-and-set")));
+    }
+
     let date_source = if let Some(date_os) = matches.get_one::<std::ffi::OsString>(OPT_DATE) {
         // Convert OsString to String, handling invalid UTF-8 with GNU-compatible error
         let date = date_os.to_str().ok_or_else(|| {
@@ -366,7 +487,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
     let set_to = match matches
         .get_one::<String>(OPT_SET)
-        .map(|s| parse_date(s, &now, DebugOptions::new(debug_mode, true)))
+        .map(|s| parse_human_date(s, &now, utc, debug_mode))
     {
         None => None,
         Some(Err((input, _err))) => {
@@ -386,170 +507,78 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         debug: debug_mode,
     };
 
-    if let Some(date) = settings.set_to {
-        return set_system_datetime(convert_for_set(date, settings.utc));
-    }
-
     // Iterate over all dates - whether it's a single date or a file.
-    let dates: Box<dyn Iterator<Item = _>> = match settings.date_source {
-        DateSource::Human(ref input) => {
-            // GNU compatibility (Comments in parentheses)
-            let input = strip_parenthesized_comments(input);
-            let input = input.trim();
-
-            // GNU compatibility (Empty string):
-            // An empty string (or whitespace-only) should be treated as midnight today.
-            let is_empty_or_whitespace = input.is_empty();
-
-            // GNU compatibility (Military timezone 'J'):
-            // 'J' is reserved for local time in military timezones.
-            // GNU date accepts it and treats it as midnight today (00:00:00).
-            let is_military_j = input.eq_ignore_ascii_case("j");
-
-            // GNU compatibility (Military timezone with optional hour offset):
-            // Single letter (a-z except j) optionally followed by 1-2 digits.
-            // Letter represents midnight in that military timezone (UTC offset).
-            // Digits represent additional hours to add.
-            // Examples: "m" -> noon UTC (12:00); "m9" -> 21:00 UTC; "a5" -> 04:00 UTC
-            let military_tz_with_offset = parse_military_timezone_with_offset(input);
-
-            // GNU compatibility (Pure numbers in date strings):
-            // - Manual: https://www.gnu.org/software/coreutils/manual/html_node/Pure-numbers-in-date-strings.html
-            // - Semantics: a pure decimal number denotes today's time-of-day (HH or HHMM).
-            //   Examples: "0"/"00" => 00:00 today; "7"/"07" => 07:00 today; "0700" => 07:00 today.
-            // For all other forms, fall back to the general parser.
-            //
-            // GNU compatibility (Military timezone 'J' after a time):
-            // 'J' is local time, so "<digits>j"/"<digits>J" is the same time-of-day form
-            // as the bare "<digits>" input ("9j" == "9"). Strip it before the digit check.
-            let time_digits = input.strip_suffix(['j', 'J']).unwrap_or(input);
-            let is_pure_digits = !time_digits.is_empty()
-                && time_digits.len() <= 4
-                && time_digits.chars().all(|c| c.is_ascii_digit());
-
-            let date = if is_empty_or_whitespace || input == "-" || is_military_j {
-                // Treat empty string, single hyphen, or 'J' as midnight today in local time
-                let date_part =
-                    strtime::format("%F", &now).unwrap_or_else(|_| String::from("1970-01-01"));
-                let offset = if settings.utc {
-                    String::from("+00:00")
-                } else {
-                    strtime::format("%:z", &now).unwrap_or_default()
-                };
-                let composed = if offset.is_empty() {
-                    format!("{date_part} 00:00")
-                } else {
-                    format!("{date_part} 00:00 {offset}")
-                };
-                if settings.debug {
-                    let _ = writeln!(
-                        stderr(),
-                        "date: warning: using midnight as starting time: 00:00:00"
-                    );
-                }
-                parse_date(composed, &now, DebugOptions::new(settings.debug, false))
-            } else if let Some((total_hours, day_delta)) = military_tz_with_offset {
-                // Military timezone with optional hour offset
-                // Convert to UTC time: midnight + military_tz_offset + additional_hours
-
-                // When calculating a military timezone with an optional hour offset, midnight may
-                // be crossed in either direction. `day_delta` indicates whether the date remains
-                // the same, moves to the previous day, or advances to the next day.
-                // Changing day can result in error, this closure will help handle these errors
-                // gracefully.
-                let format_date_with_epoch_fallback = |date: Result<Zoned, _>| -> String {
-                    date.and_then(|d| strtime::format("%F", &d))
-                        .unwrap_or_else(|_| String::from("1970-01-01"))
-                };
-                let date_part = match day_delta {
-                    DayDelta::Same => format_date_with_epoch_fallback(Ok(now.clone())),
-                    DayDelta::Next => format_date_with_epoch_fallback(now.tomorrow()),
-                    DayDelta::Previous => format_date_with_epoch_fallback(now.yesterday()),
-                };
-                let composed = format!("{date_part} {total_hours:02}:00:00 +00:00");
-                parse_date(composed, &now, DebugOptions::new(settings.debug, false))
-            } else if is_pure_digits {
-                // Derive HH and MM from the digits
-                let (hh_opt, mm_opt) = if time_digits.len() <= 2 {
-                    (time_digits.parse::<u32>().ok(), Some(0u32))
-                } else {
-                    let (h, m) = time_digits.split_at(time_digits.len() - 2);
-                    (h.parse::<u32>().ok(), m.parse::<u32>().ok())
-                };
+    let dates: Box<dyn Iterator<Item = _>> = if let Some(ref date) = settings.set_to {
+        // Like GNU, print the requested date even when the clock could not be set.
+        if let Err(e) = set_system_datetime(convert_for_set(date.clone(), settings.utc)) {
+            show!(e);
+        }
+        Box::new(std::iter::once(Ok(date.clone())))
+    } else {
+        match settings.date_source {
+            DateSource::Human(ref input) => {
+                let date = parse_human_date(input, &now, settings.utc, settings.debug);
 
-                if let (Some(hh), Some(mm)) = (hh_opt, mm_opt) {
-                    // Compose a concrete datetime string for today with zone offset.
-                    // Use the already-determined 'now' and settings.utc to select offset.
-                    let date_part =
-                        strtime::format("%F", &now).unwrap_or_else(|_| String::from("1970-01-01"));
-                    // If -u, force +00:00; otherwise use the local offset of 'now'.
-                    let offset = if settings.utc {
-                        String::from("+00:00")
-                    } else {
-                        strtime::format("%:z", &now).unwrap_or_default()
-                    };
-                    let composed = if offset.is_empty() {
-                        format!("{date_part} {hh:02}:{mm:02}")
-                    } else {
-                        format!("{date_part} {hh:02}:{mm:02} {offset}")
-                    };
-                    parse_date(composed, &now, DebugOptions::new(settings.debug, false))
-                } else {
-                    // Fallback on parse failure of digits
-                    parse_date(input, &now, DebugOptions::new(settings.debug, true))
+                let iter = std::iter::once(date);
+                Box::new(iter)
+            }
+            DateSource::Stdin => parse_dates_from_reader(
+                std::io::stdin(),
+                &now,
+                DebugOptions::new(settings.debug, true, settings.utc),
+            ),
+            DateSource::File(ref path) => {
+                if path.is_dir() {
+                    return Err(USimpleError::new(
+                        2,
+                        translate!("date-error-expected-file-got-directory", "path" => path.quote()),
+                    ));
                 }
-            } else {
-                parse_date(input, &now, DebugOptions::new(settings.debug, true))
-            };
-
-            let iter = std::iter::once(date);
-            Box::new(iter)
-        }
-        DateSource::Stdin => parse_dates_from_reader(
-            std::io::stdin(),
-            &now,
-            DebugOptions::new(settings.debug, true),
-        ),
-        DateSource::File(ref path) => {
-            if path.is_dir() {
-                return Err(USimpleError::new(
-                    2,
-                    translate!("date-error-expected-file-got-directory", "path" => path.quote()),
-                ));
+                let file = File::open(path)
+                    .map_err_context(|| path.as_os_str().maybe_quote().to_string())?;
+                parse_dates_from_reader(
+                    file,
+                    &now,
+                    DebugOptions::new(settings.debug, true, settings.utc),
+                )
             }
-            let file =
-                File::open(path).map_err_context(|| path.as_os_str().maybe_quote().to_string())?;
-            parse_dates_from_reader(file, &now, DebugOptions::new(settings.debug, true))
-        }
-        DateSource::FileMtime(ref path) => {
-            let metadata = std::fs::metadata(path)
-                .map_err_context(|| path.as_os_str().maybe_quote().to_string())?;
-            let mtime = metadata.modified()?;
-            let ts = Timestamp::try_from(mtime).map_err(|e| {
+            DateSource::FileMtime(ref path) => {
+                let metadata = std::fs::metadata(path)
+                    .map_err_context(|| path.as_os_str().maybe_quote().to_string())?;
+                let mtime = metadata.modified()?;
+                let ts = Timestamp::try_from(mtime).map_err(|e| {
                 USimpleError::new(
                     1,
                     translate!("date-error-cannot-set-date", "path" => path.quote(), "error" => e),
                 )
             })?;
-            let date = ts.to_zoned(TimeZone::try_system().unwrap_or(TimeZone::UTC));
-            let iter = std::iter::once(Ok(date));
-            Box::new(iter)
-        }
-        DateSource::Resolution => {
-            let resolution = get_clock_resolution();
-            let date = resolution.to_zoned(TimeZone::system());
-            let iter = std::iter::once(Ok(date));
-            Box::new(iter)
-        }
-        DateSource::Now => {
-            let iter = std::iter::once(Ok(now));
-            Box::new(iter)
+                let date = ts.to_zoned(TimeZone::try_system().unwrap_or(TimeZone::UTC));
+                let iter = std::iter::once(Ok(date));
+                Box::new(iter)
+            }
+            DateSource::Resolution => {
+                let resolution = get_clock_resolution();
+                let date = resolution.to_zoned(TimeZone::system());
+                let iter = std::iter::once(Ok(date));
+                Box::new(iter)
+            }
+            DateSource::Now => {
+                let iter = std::iter::once(Ok(now));
+                Box::new(iter)
+            }
         }
     };
 
     let format_string = make_format_string(&settings);
     let mut stdout = BufWriter::new(std::io::stdout().lock());
 
+    // Only dates read from a date string get the parsing details of --debug.
+    let parsed = settings.set_to.is_some()
+        || matches!(
+            settings.date_source,
+            DateSource::Human(_) | DateSource::File(_) | DateSource::Stdin
+        );
+
     // Format all the dates
     let config = Config::new().custom(PosixCustom::new()).lenient(true);
     for date in dates {
@@ -560,6 +589,12 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                 } else {
                     date
                 };
+                if settings.debug {
+                    if parsed {
+                        debug_final(&date, settings.utc);
+                    }
+                    let _ = writeln!(stderr(), "date: output format: '{format_string}'");
+                }
                 let skip_localization =
                     matches!(settings.format, Format::Rfc5322 | Format::Rfc3339(_));
                 match format_date_with_locale_aware_months(
@@ -573,10 +608,13 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                     })?,
                     Err(e) => {
                         let _ = stdout.flush();
-                        return Err(USimpleError::new(
-                            1,
-                            translate!("date-error-invalid-format", "format" => format_string, "error" => e),
-                        ));
+                        let mut message = translate!("date-error-invalid-format", "format" => format_string, "error" => e);
+                        if show_spec_context()
+                            && let Some(context) = e.directive_context(format_string)
+                        {
+                            message = format!("{message}\n{context}");
+                        }
+                        return Err(USimpleError::new(1, message));
                     }
                 }
             }
@@ -619,7 +657,6 @@ pub fn uu_app() -> Command {
                 .long(OPT_FILE)
                 .value_name("DATEFILE")
                 .value_hint(clap::ValueHint::FilePath)
-                .conflicts_with(OPT_DATE)
                 .help(translate!("date-help-file")),
         )
         .arg(
@@ -637,7 +674,6 @@ pub fn uu_app() -> Command {
         .arg(
             Arg::new(OPT_RESOLUTION)
                 .long(OPT_RESOLUTION)
-                .conflicts_with_all([OPT_DATE, OPT_FILE])
                 .overrides_with(OPT_RESOLUTION)
                 .help(translate!("date-help-resolution"))
                 .action(ArgAction::SetTrue),
@@ -671,7 +707,6 @@ pub fn uu_app() -> Command {
                 .long(OPT_REFERENCE)
                 .value_name("FILE")
                 .value_hint(clap::ValueHint::AnyPath)
-                .conflicts_with_all([OPT_DATE, OPT_FILE, OPT_RESOLUTION])
                 .help(translate!("date-help-reference")),
         )
         .arg(
@@ -752,7 +787,7 @@ fn format_date_with_locale_aware_months(
     config: &Config<PosixCustom>,
     #[cfg(feature = "i18n-datetime")] skip_localization: bool,
     #[cfg(not(feature = "i18n-datetime"))] _skip_localization: bool,
-) -> Result<String, String> {
+) -> Result<String, format_modifiers::FormatError> {
     // Apply locale-aware name substitution (month/day names) before modifier
     // processing, so that formats like "%-e" don't bypass localization of "%b"/"%A".
     // The owned String is kept in `localized` so `fmt` can borrow from it for the
@@ -774,13 +809,11 @@ fn format_date_with_locale_aware_months(
 
     // Check if format string has GNU modifiers (width/flags) and format if present
     if let Some(result) = format_modifiers::format_with_modifiers_if_present(date, fmt, config) {
-        return result.map_err(|e| e.to_string());
+        return result;
     }
 
     let broken_down = BrokenDownTime::from(date);
-    broken_down
-        .to_string_with_config(config, fmt)
-        .map_err(|e| e.to_string())
+    Ok(broken_down.to_string_with_config(config, fmt)?)
 }
 
 /// Return the appropriate format string for the given settings.
@@ -910,6 +943,12 @@ fn try_parse_with_abbreviation<S: AsRef<str>>(date_str: S, now: &Zoned) -> Optio
     let tz = resolve_tz_abbreviation(last_word)?;
 
     let date_part = s.trim_end_matches(last_word).trim();
+    // A zone on its own stands for midnight today in that zone.
+    let date_part = if date_part.is_empty() {
+        "00:00"
+    } else {
+        date_part
+    };
 
     // Reject inputs that specify a timezone twice, e.g. "EST EST" or "EST PST":
     // GNU `date` considers these invalid. If what remains after stripping the
@@ -934,8 +973,123 @@ fn try_parse_with_abbreviation<S: AsRef<str>>(date_str: S, now: &Zoned) -> Optio
     Some(zoned.with_time_zone(now.time_zone().clone()))
 }
 
-/// Parse a `String` into a `DateTime`.
-/// If it fails, return a tuple of the `String` along with its `ParseError`.
+/// Parse a date string given with `--date` or `--set`, handling the GNU forms that
+/// `parse_datetime` does not know about before falling back to it.
+fn parse_human_date(
+    input: &str,
+    now: &Zoned,
+    utc: bool,
+    debug: bool,
+) -> Result<Zoned, (String, parse_datetime::ParseDateTimeError)> {
+    // GNU compatibility (Comments in parentheses)
+    let input = strip_parenthesized_comments(input);
+    let input = input.trim();
+
+    // GNU compatibility (Empty string):
+    // An empty string (or whitespace-only) should be treated as midnight today.
+    let is_empty_or_whitespace = input.is_empty();
+
+    // GNU compatibility (Military timezone 'J'):
+    // 'J' is reserved for local time in military timezones.
+    // GNU date accepts it and treats it as midnight today (00:00:00).
+    let is_military_j = input.eq_ignore_ascii_case("j");
+
+    // GNU compatibility (Military timezone with optional hour offset):
+    // Single letter (a-z except j) optionally followed by 1-2 digits.
+    // Letter represents midnight in that military timezone (UTC offset).
+    // Digits represent additional hours to add.
+    // Examples: "m" -> noon UTC (12:00); "m9" -> 21:00 UTC; "a5" -> 04:00 UTC
+    let military_tz_with_offset = parse_military_timezone_with_offset(input);
+
+    // GNU compatibility (Pure numbers in date strings):
+    // - Manual: https://www.gnu.org/software/coreutils/manual/html_node/Pure-numbers-in-date-strings.html
+    // - Semantics: a pure decimal number denotes today's time-of-day (HH or HHMM).
+    //   Examples: "0"/"00" => 00:00 today; "7"/"07" => 07:00 today; "0700" => 07:00 today.
+    // For all other forms, fall back to the general parser.
+    //
+    // GNU compatibility (Military timezone 'J' after a time):
+    // 'J' is local time, so "<digits>j"/"<digits>J" is the same time-of-day form
+    // as the bare "<digits>" input ("9j" == "9"). Strip it before the digit check.
+    let time_digits = input.strip_suffix(['j', 'J']).unwrap_or(input);
+    let is_pure_digits = !time_digits.is_empty()
+        && time_digits.len() <= 4
+        && time_digits.chars().all(|c| c.is_ascii_digit());
+
+    if is_empty_or_whitespace || input == "-" || is_military_j {
+        // Treat empty string, single hyphen, or 'J' as midnight today in local time
+        let date_part = strtime::format("%F", now).unwrap_or_else(|_| String::from("1970-01-01"));
+        let offset = if utc {
+            String::from("+00:00")
+        } else {
+            strtime::format("%:z", now).unwrap_or_default()
+        };
+        let composed = if offset.is_empty() {
+            format!("{date_part} 00:00")
+        } else {
+            format!("{date_part} 00:00 {offset}")
+        };
+        if debug {
+            let _ = writeln!(
+                stderr(),
+                "date: warning: using midnight as starting time: 00:00:00"
+            );
+        }
+        parse_date(composed, now, DebugOptions::new(debug, false, utc))
+    } else if let Some((total_hours, day_delta)) = military_tz_with_offset {
+        // Military timezone with optional hour offset
+        // Convert to UTC time: midnight + military_tz_offset + additional_hours
+
+        // When calculating a military timezone with an optional hour offset, midnight may
+        // be crossed in either direction. `day_delta` indicates whether the date remains
+        // the same, moves to the previous day, or advances to the next day.
+        // Changing day can result in error, this closure will help handle these errors
+        // gracefully.
+        let format_date_with_epoch_fallback = |date: Result<Zoned, _>| -> String {
+            date.and_then(|d| strtime::format("%F", &d))
+                .unwrap_or_else(|_| String::from("1970-01-01"))
+        };
+        let date_part = match day_delta {
+            DayDelta::Same => format_date_with_epoch_fallback(Ok(now.clone())),
+            DayDelta::Next => format_date_with_epoch_fallback(now.tomorrow()),
+            DayDelta::Previous => format_date_with_epoch_fallback(now.yesterday()),
+        };
+        let composed = format!("{date_part} {total_hours:02}:00:00 +00:00");
+        parse_date(composed, now, DebugOptions::new(debug, false, utc))
+    } else if is_pure_digits {
+        // Derive HH and MM from the digits
+        let (hh_opt, mm_opt) = if time_digits.len() <= 2 {
+            (time_digits.parse::<u32>().ok(), Some(0u32))
+        } else {
+            let (h, m) = time_digits.split_at(time_digits.len() - 2);
+            (h.parse::<u32>().ok(), m.parse::<u32>().ok())
+        };
+
+        if let (Some(hh), Some(mm)) = (hh_opt, mm_opt) {
+            // Compose a concrete datetime string for today with zone offset.
+            // Use the already-determined 'now' and utc to select offset.
+            let date_part =
+                strtime::format("%F", now).unwrap_or_else(|_| String::from("1970-01-01"));
+            // If -u, force +00:00; otherwise use the local offset of 'now'.
+            let offset = if utc {
+                String::from("+00:00")
+            } else {
+                strtime::format("%:z", now).unwrap_or_default()
+            };
+            let composed = if offset.is_empty() {
+                format!("{date_part} {hh:02}:{mm:02}")
+            } else {
+                format!("{date_part} {hh:02}:{mm:02} {offset}")
+            };
+            parse_date(composed, now, DebugOptions::new(debug, false, utc))
+        } else {
+            // Fallback on parse failure of digits
+            parse_date(input, now, DebugOptions::new(debug, true, utc))
+        }
+    } else {
+        parse_date(input, now, DebugOptions::new(debug, true, utc))
+    }
+}
+
 /// Helper function to parse dates from a line-based reader (stdin or file)
 ///
 /// Takes any `Read` source, reads it line by line, and parses each line as a date.
@@ -953,6 +1107,8 @@ fn parse_dates_from_reader<R: Read + 'static>(
     )
 }
 
+/// Parse a `String` into a `DateTime`.
+/// If it fails, return a tuple of the `String` along with its `ParseError`.
 ///
 /// **Update for parse_datetime 0.13:**
 /// - parse_datetime 0.11: returned `chrono::DateTime` → required conversion to `jiff::Zoned`
@@ -971,8 +1127,21 @@ fn parse_date<S: AsRef<str> + Clone>(
         let _ = writeln!(stderr(), "date: input string: {input_str}");
     }
 
+    // The parser only honors a `TZ="..."` prefix for absolute times, so resolve it here and parse
+    // the rest relative to the current time in that zone.
+    let (tz_name, local_now, rest) = match split_tz_prefix(input_str) {
+        Some((name, tz, rest)) => (Some(name), Cow::Owned(now.with_time_zone(tz)), rest),
+        None => (None, Cow::Borrowed(now), input_str),
+    };
+    let normalized = normalize_gnu_words(rest);
+    let input_tz = || match &tz_name {
+        Some(name) => format!("TZ=\"{name}\" in date string"),
+        None => debug_input_timezone(dbg_opts.utc),
+    };
+
     // First, try to parse any timezone abbreviations
-    if let Some(zoned) = try_parse_with_abbreviation(input_str, now) {
+    if let Some(zoned) = try_parse_with_abbreviation(&normalized, &local_now) {
+        let zoned = zoned.with_time_zone(now.time_zone().clone());
         if dbg_opts.debug {
             let mut err = stderr().lock();
             let _ = writeln!(
@@ -985,13 +1154,24 @@ fn parse_date<S: AsRef<str> + Clone>(
                 "date: parsed time part: {}",
                 strtime::format("%H:%M:%S", &zoned).unwrap_or_default()
             );
-            let tz_display = zoned.time_zone().iana_name().unwrap_or("system default");
-            let _ = writeln!(err, "date: input timezone: {tz_display}");
+            let offset = normalized
+                .split_whitespace()
+                .last()
+                .and_then(resolve_tz_abbreviation)
+                .map_or_else(|| zoned.offset(), |tz| tz.to_offset(zoned.timestamp()));
+            let _ = writeln!(
+                err,
+                "date: input timezone: parsed date/time string ({})",
+                debug_offset(offset)
+            );
         }
         return Ok(zoned);
     }
 
-    match parse_datetime::parse_datetime_at_date(now.clone(), input_str) {
+    match parse_datetime::parse_datetime_at_date(
+        local_now.clone().into_owned(),
+        normalized.as_ref(),
+    ) {
         // Convert to system timezone for display
         // (parse_datetime returns a value in the input's timezone)
         Ok(parsed) => {
@@ -1019,7 +1199,14 @@ fn parse_date<S: AsRef<str> + Clone>(
                 );
 
                 // Show timezone information
-                let _ = writeln!(err, "date: input timezone: system default");
+                let input_tz = if normalized.trim_start().starts_with('@') {
+                    "'@timespec' - always UTC".to_string()
+                } else if date.time_zone() != local_now.time_zone() {
+                    format!("parsed date/time string ({})", debug_offset(date.offset()))
+                } else {
+                    input_tz()
+                };
+                let _ = writeln!(err, "date: input timezone: {input_tz}");
 
                 // Check if time component was specified, if not warn about midnight usage
                 // Only warn for date-only inputs (no time specified), but not for epoch formats (@N)
@@ -1041,6 +1228,64 @@ fn parse_date<S: AsRef<str> + Clone>(
     }
 }
 
+/// Where the time zone used to interpret a date string comes from, worded like GNU's `--debug`.
+fn debug_input_timezone(utc: bool) -> String {
+    if utc {
+        return "TZ=\"UTC0\" environment value or -u".to_string();
+    }
+    match std::env::var("TZ") {
+        Ok(tz) => format!("TZ=\"{tz}\" environment value"),
+        Err(_) => "system default".to_string(),
+    }
+}
+
+/// Format a UTC offset like GNU's `--debug`: "+00", "-05", "+05:30".
+fn debug_offset(offset: Offset) -> String {
+    let seconds = offset.seconds();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    match (minutes, seconds) {
+        (0, 0) => format!("{sign}{hours:02}"),
+        (_, 0) => format!("{sign}{hours:02}:{minutes:02}"),
+        _ => format!("{sign}{hours:02}:{minutes:02}:{seconds:02}"),
+    }
+}
+
+/// Print the resulting time of a parsed date string, like GNU's `--debug`.
+fn debug_final(date: &Zoned, utc: bool) {
+    let timezone = if utc {
+        "Universal Time".to_string()
+    } else {
+        debug_input_timezone(false)
+    };
+    // Like a `timespec`, the nanoseconds are never negative.
+    let timestamp = date.timestamp();
+    let (mut seconds, mut nanoseconds) = (timestamp.as_second(), timestamp.subsec_nanosecond());
+    if nanoseconds < 0 {
+        seconds -= 1;
+        nanoseconds += 1_000_000_000;
+    }
+    let in_utc = date.with_time_zone(TimeZone::UTC);
+    let mut err = stderr().lock();
+    let _ = writeln!(err, "date: timezone: {timezone}");
+    let _ = writeln!(
+        err,
+        "date: final: {seconds}.{nanoseconds:09} (epoch-seconds)"
+    );
+    let _ = writeln!(
+        err,
+        "date: final: (Y-M-D) {} (UTC)",
+        strtime::format("%Y-%m-%d %H:%M:%S", &in_utc).unwrap_or_default()
+    );
+    let _ = writeln!(
+        err,
+        "date: final: (Y-M-D) {} (UTC{})",
+        strtime::format("%Y-%m-%d %H:%M:%S", date).unwrap_or_default(),
+        debug_offset(date.offset())
+    );
+}
+
 #[cfg(not(any(unix, windows)))]
 fn get_clock_resolution() -> Timestamp {
     unimplemented!("getting clock resolution not implemented (unsupported target)");
@@ -1125,9 +1370,19 @@ fn set_system_datetime(date: Zoned) -> UResult<()> {
         tv_nsec: ts.subsec_nanosecond() as _,
     };
 
-    clock_settime(ClockId::Realtime, timespec)
-        .map_err(std::io::Error::from)
-        .map_err_context(|| translate!("date-error-cannot-set-date"))
+    // Without CAP_SYS_TIME this fails with EPERM, which GNU reports as "Operation not permitted"
+    // rather than the generic "Permission denied".
+    clock_settime(ClockId::Realtime, timespec).map_err(|e| {
+        let e = std::io::Error::from(e);
+        USimpleError::new(
+            1,
+            format!(
+                "{}: {}",
+                translate!("date-error-cannot-set-date"),
+                uucore::error::strip_errno(&e)
+            ),
+        )
+    })
 }
 
 #[cfg(windows)]
@@ -1136,6 +1391,8 @@ fn set_system_datetime(date: Zoned) -> UResult<()> {
 /// * <https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-setsystemtime>
 /// * <https://docs.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-systemtime>
 fn set_system_datetime(date: Zoned) -> UResult<()> {
+    // SetSystemTime takes the time in UTC.
+    let date = date.with_time_zone(TimeZone::UTC);
     let system_time = SYSTEMTIME {
         wYear: date.year() as u16,
         wMonth: date.month() as u16,
@@ -1197,8 +1454,12 @@ mod tests {
     #[test]
     fn test_abbreviation_resolves_relative_date_against_now() {
         let now = "2025-03-15T20:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
-        let result =
-            parse_date("yesterday 10:00 GMT", &now, DebugOptions::new(false, false)).unwrap();
+        let result = parse_date(
+            "yesterday 10:00 GMT",
+            &now,
+            DebugOptions::new(false, false, false),
+        )
+        .unwrap();
         assert_eq!(result.date(), jiff::civil::date(2025, 3, 14));
     }
 
@@ -1209,7 +1470,7 @@ mod tests {
         let date = parse_date(
             "Sat 20 Mar 2021 14:53:01 AWST",
             &now,
-            DebugOptions::new(false, false),
+            DebugOptions::new(false, false, false),
         )
         .unwrap();
         let utc = convert_for_set(date, true);
@@ -1249,4 +1510,34 @@ mod tests {
         assert_eq!(strip_parenthesized_comments("a(b(c)d"), "a"); // Nested unbalanced
         assert_eq!(strip_parenthesized_comments("a(b)c(d)e(f"), "ace"); // Multiple groups, last unmatched
     }
+
+    #[test]
+    fn test_normalize_gnu_words() {
+        assert_eq!(normalize_gnu_words("2 days ago"), "2 days ago");
+        assert_eq!(normalize_gnu_words("2 days hence"), "2 days ");
+        assert_eq!(
+            normalize_gnu_words("last YEAR Hence 10:00"),
+            "last YEAR  10:00"
+        );
+        assert_eq!(normalize_gnu_words("hence 2 days"), "hence 2 days");
+        assert_eq!(normalize_gnu_words("2 hence"), "2 hence");
+        assert_eq!(normalize_gnu_words("Mon. Mar.9"), "Mon  Mar 9");
+        assert_eq!(normalize_gnu_words("10 a.m."), "10 a.m.");
+        assert_eq!(normalize_gnu_words("tues."), "tues.");
+    }
+
+    #[test]
+    fn test_split_tz_prefix() {
+        let (name, tz, rest) = split_tz_prefix("TZ=\"Asia/Tokyo\" tomorrow").unwrap();
+        assert_eq!(name, "Asia/Tokyo");
+        assert_eq!(tz.iana_name(), Some("Asia/Tokyo"));
+        assert_eq!(rest, " tomorrow");
+        let (name, tz, rest) = split_tz_prefix(r#"TZ="UTC\0"10:00"#).unwrap();
+        assert_eq!(name, "UTC0");
+        assert_eq!(tz.to_offset(Timestamp::UNIX_EPOCH), Offset::UTC);
+        assert_eq!(rest, "10:00");
+        assert!(split_tz_prefix("TZ=\"Asia/Tokyo").is_none());
+        assert!(split_tz_prefix("TZ=\"No/Such/Zone\" 10:00").is_none());
+        assert!(split_tz_prefix("tomorrow").is_none());
+    }
 }
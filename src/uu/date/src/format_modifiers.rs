@@ -36,6 +36,7 @@
 use jiff::Zoned;
 use jiff::fmt::strtime::{BrokenDownTime, Config, PosixCustom};
 use std::fmt;
+use uucore::format::SpecLocation;
 use uucore::translate;
 
 /// Upper bound on the field width we will allocate for. Anything wider is
@@ -52,6 +53,12 @@ pub enum FormatError {
     JiffError(jiff::Error),
     /// Field width calculation overflowed or required allocation failed
     FieldWidthTooLarge { width: usize, specifier: String },
+    /// An error in the `directive` found at byte `offset` of the format string
+    Directive {
+        directive: String,
+        offset: usize,
+        source: Box<Self>,
+    },
 }
 
 impl fmt::Display for FormatError {
@@ -67,10 +74,47 @@ impl fmt::Display for FormatError {
                     "specifier" => specifier
                 )
             ),
+            Self::Directive { source, .. } => source.fmt(f),
         }
     }
 }
 
+impl FormatError {
+    /// The location and suggestion lines for the directive in error, if known.
+    ///
+    /// `format_string` is the format as given by the user; the directive is
+    /// looked up in it if the format was rewritten before formatting.
+    pub fn directive_context(&self, format_string: &str) -> Option<String> {
+        let Self::Directive {
+            directive,
+            offset,
+            source,
+        } = self
+        else {
+            return None;
+        };
+        let offset = if format_string
+            .get(*offset..)
+            .is_some_and(|rest| rest.starts_with(directive.as_str()))
+        {
+            *offset
+        } else {
+            format_string.find(directive.as_str())?
+        };
+        let location = SpecLocation {
+            format: format_string.as_bytes().to_vec(),
+            offset,
+        };
+        let underline = location.underline(directive.len());
+        Some(match source.as_ref() {
+            Self::FieldWidthTooLarge { specifier, .. } => {
+                format!("{underline}\n  did you mean '%{specifier}'?")
+            }
+            _ => underline,
+        })
+    }
+}
+
 impl From<jiff::Error> for FormatError {
     fn from(e: jiff::Error) -> Self {
         Self::JiffError(e)
@@ -233,19 +277,14 @@ fn format_with_modifiers(
             }
 
             if let Some(parsed) = parse_format_spec(&format_string[i..]) {
-                // Format the base specifier first, reusing `base_format`.
-                base_format.clear();
-                base_format.push('%');
-                base_format.push_str(parsed.spec);
-                let formatted = broken_down.to_string_with_config(config, &base_format)?;
-
-                if !parsed.flags.is_empty() || parsed.width.is_some() {
-                    let modified = apply_modifiers(&formatted, &parsed)?;
-                    result.push_str(&modified);
-                } else {
-                    result.push_str(&formatted);
-                }
-
+                let formatted =
+                    format_directive(date, &broken_down, &parsed, config, &mut base_format)
+                        .map_err(|e| FormatError::Directive {
+                            directive: format_string[i..i + parsed.len].to_string(),
+                            offset: i,
+                            source: Box::new(e),
+                        })?;
+                result.push_str(&formatted);
                 i += parsed.len;
                 continue;
             }
@@ -260,6 +299,114 @@ fn format_with_modifiers(
     Ok(result)
 }
 
+/// Format a single parsed directive, reusing `base_format` for the bare specifier.
+fn format_directive(
+    date: &Zoned,
+    broken_down: &BrokenDownTime,
+    parsed: &ParsedSpec<'_>,
+    config: &Config<PosixCustom>,
+    base_format: &mut String,
+) -> Result<String, FormatError> {
+    // Fractions and offsets follow their own padding rules.
+    let special = match parsed.spec {
+        "N" => Some(format_nanoseconds(date.subsec_nanosecond(), parsed)?),
+        "z" | ":z" | "::z" | ":::z" => format_offset_unpadded(date.offset().seconds(), parsed)?,
+        _ => None,
+    };
+    if let Some(special) = special {
+        return Ok(special);
+    }
+
+    // Format the base specifier first, reusing `base_format`.
+    base_format.clear();
+    base_format.push('%');
+    base_format.push_str(parsed.spec);
+    let formatted = broken_down.to_string_with_config(config, base_format.as_str())?;
+
+    if !parsed.flags.is_empty() || parsed.width.is_some() {
+        apply_modifiers(&formatted, parsed)
+    } else {
+        Ok(formatted)
+    }
+}
+
+/// The padding flag that applies to a spec: the last of `-`, `_`, `0` and `+`.
+fn padding_flag(flags: &str) -> Option<char> {
+    flags
+        .chars()
+        .rev()
+        .find(|c| matches!(c, '-' | '_' | '0' | '+'))
+}
+
+/// Format `%N` like GNU: the width is the number of digits of the fraction to show (9 by
+/// default), extended with zeros beyond nanoseconds. `_` turns trailing zeros into spaces and,
+/// with an explicit width, `-` drops them.
+fn format_nanoseconds(nanoseconds: i32, parsed: &ParsedSpec<'_>) -> Result<String, FormatError> {
+    let digits = parsed.width.unwrap_or(9);
+    if digits > MAX_FORMAT_WIDTH {
+        return Err(field_width_too_large(digits, parsed.spec));
+    }
+    let mut result = try_alloc_padded(0, digits.max(9), digits, parsed.spec)?;
+    result.push_str(&format!("{nanoseconds:09}"));
+    result.truncate(digits);
+    result.extend(std::iter::repeat_n(
+        '0',
+        digits.saturating_sub(result.len()),
+    ));
+
+    let significant = result.trim_end_matches('0').len().max(1);
+    match padding_flag(parsed.flags) {
+        Some('_') => {
+            result.truncate(significant);
+            result.extend(std::iter::repeat_n(' ', digits.saturating_sub(significant)));
+        }
+        Some('-') if parsed.width.is_some() => result.truncate(significant),
+        _ => {}
+    }
+    Ok(result)
+}
+
+/// Format `%z`, `%:z`, `%::z` and `%:::z` with the `-` or `_` flag like GNU: the hours lose
+/// their leading zero, and `_` pads with spaces in front of the sign instead.
+///
+/// Returns `None` for the other flags, which the generic padding handles.
+fn format_offset_unpadded(
+    offset_seconds: i32,
+    parsed: &ParsedSpec<'_>,
+) -> Result<Option<String>, FormatError> {
+    let Some(flag) = padding_flag(parsed.flags).filter(|c| matches!(c, '-' | '_')) else {
+        return Ok(None);
+    };
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let seconds = offset_seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+    let (text, default_width) = match parsed.spec {
+        "z" => ((hours * 100 + minutes).to_string(), 5),
+        colons => {
+            let text = match colons {
+                ":z" => format!("{hours}:{minutes:02}"),
+                "::z" => format!("{hours}:{minutes:02}:{seconds:02}"),
+                _ if seconds != 0 => format!("{hours}:{minutes:02}:{seconds:02}"),
+                _ if minutes != 0 => format!("{hours}:{minutes:02}"),
+                _ => hours.to_string(),
+            };
+            // The width of the zero-padded form, with a two-digit hour.
+            let width = 1 + text.len() + usize::from(hours < 10);
+            (text, width)
+        }
+    };
+
+    if flag == '-' {
+        return Ok(Some(format!("{sign}{text}")));
+    }
+    let width = parsed.width.unwrap_or(default_width);
+    if width > MAX_FORMAT_WIDTH {
+        return Err(field_width_too_large(width, parsed.spec));
+    }
+    Ok(Some(format!("{:>width$}", format!("{sign}{text}"))))
+}
+
 /// Returns true if the specifier produces text output (default pad is space)
 /// rather than numeric output (default pad is zero).
 fn is_text_specifier(specifier: &str) -> bool {
@@ -883,13 +1030,38 @@ mod tests {
         let huge = "9".repeat(40);
         let format = format!("%{huge}Y");
         let err = format_with_modifiers(&date, &format, &config).unwrap_err();
+        let FormatError::Directive {
+            directive,
+            offset,
+            source,
+        } = &err
+        else {
+            panic!("expected a located error, got {err:?}");
+        };
+        assert_eq!((directive.as_str(), *offset), (format.as_str(), 0));
         assert!(matches!(
-            err,
+            source.as_ref(),
             FormatError::FieldWidthTooLarge { width, specifier }
-            if width == usize::MAX && specifier == "Y"
+            if *width == usize::MAX && specifier == "Y"
         ));
     }
 
+    #[test]
+    fn test_directive_context() {
+        let date = make_test_date(1999, 6, 1, 0);
+        let config = get_config();
+        let err = format_with_modifiers(&date, "a %99999Y b", &config).unwrap_err();
+        assert_eq!(
+            err.directive_context("a %99999Y b").unwrap(),
+            "  a %99999Y b\n    ^^^^^^^ column 3\n  did you mean '%Y'?"
+        );
+        // The directive is found again in a format that was rewritten.
+        assert_eq!(
+            err.directive_context("%b %99999Y").unwrap(),
+            "  %b %99999Y\n     ^^^^^^^ column 4\n  did you mean '%Y'?"
+        );
+    }
+
     #[test]
     fn test_try_alloc_padded_rejects_width_above_supported_max() {
         // A target length exactly at the cap is allowed; one byte over is rejected.
@@ -1072,4 +1244,46 @@ mod tests {
             assert_eq!(has_gnu_modifiers(input), *expected, "input = {input:?}");
         }
     }
+
+    #[test]
+    fn test_nanoseconds_precision_and_flags() {
+        let check = |nanoseconds, flags, width, expected: &str| {
+            let parsed = spec(flags, width, "N");
+            assert_eq!(
+                format_nanoseconds(nanoseconds, &parsed).unwrap(),
+                expected,
+                "%{flags}{width:?}N of {nanoseconds}"
+            );
+        };
+        check(123_456_789, "", None, "123456789");
+        check(123_456_789, "", Some(3), "123");
+        check(123_456_789, "", Some(12), "123456789000");
+        check(50_000_000, "-", None, "050000000");
+        check(50_000_000, "-", Some(9), "05");
+        check(50_000_000, "_", None, "05       ");
+        check(50_000_000, "_", Some(3), "05 ");
+        check(50_000_000, "+", Some(4), "0500");
+        check(0, "-", Some(3), "0");
+        check(0, "_-0", Some(3), "000");
+    }
+
+    #[test]
+    fn test_offset_without_padding() {
+        let check = |offset, flags, width, specifier, expected: Option<&str>| {
+            let parsed = spec(flags, width, specifier);
+            assert_eq!(
+                format_offset_unpadded(offset, &parsed).unwrap().as_deref(),
+                expected
+            );
+        };
+        check(19800, "-", None, "z", Some("+530"));
+        check(19800, "_", None, "z", Some(" +530"));
+        check(19800, "_", Some(7), "z", Some("   +530"));
+        check(-12600, "-", None, ":z", Some("-3:30"));
+        check(0, "_", None, "::z", Some(" +0:00:00"));
+        check(32400, "_", None, ":::z", Some(" +9"));
+        check(19800, "-", None, ":::z", Some("+5:30"));
+        check(19800, "0", None, "z", None);
+        check(19800, "", Some(6), "z", None);
+    }
 }
@@ -160,10 +160,12 @@ impl Default for BlockSize {
     }
 }
 
+const BLOCK_SIZE_ENV_VARS: [&str; 3] = ["DF_BLOCK_SIZE", "BLOCK_SIZE", "BLOCKSIZE"];
+
 pub(crate) fn read_block_size(matches: &ArgMatches) -> Result<BlockSize, ParseSizeError> {
     if matches.contains_id(OPT_BLOCKSIZE) {
         let s = matches.get_one::<String>(OPT_BLOCKSIZE).unwrap();
-        let bytes = parse_size_u64(s)?;
+        let bytes = parse_size_u64(parse_block_size::split_grouping(s).0)?;
 
         if bytes > 0 {
             Ok(BlockSize::Bytes(bytes))
@@ -172,8 +174,7 @@ pub(crate) fn read_block_size(matches: &ArgMatches) -> Result<BlockSize, ParseSi
         }
     } else if matches.get_flag(OPT_PORTABILITY) {
         Ok(BlockSize::default())
-    } else if let Some(bytes) =
-        parse_block_size::block_size_from_env(&["DF_BLOCK_SIZE", "BLOCK_SIZE", "BLOCKSIZE"]).found()
+    } else if let Some(bytes) = parse_block_size::block_size_from_env(&BLOCK_SIZE_ENV_VARS).found()
     {
         Ok(BlockSize::Bytes(bytes))
     } else {
@@ -181,6 +182,18 @@ pub(crate) fn read_block_size(matches: &ArgMatches) -> Result<BlockSize, ParseSi
     }
 }
 
+/// Whether the sizes are printed with thousands separators, which the block
+/// size asks for with a leading `'`.
+pub(crate) fn read_grouping(matches: &ArgMatches) -> bool {
+    if let Some(s) = matches.get_one::<String>(OPT_BLOCKSIZE) {
+        parse_block_size::split_grouping(s).1
+    } else if matches.get_flag(OPT_PORTABILITY) {
+        false
+    } else {
+        parse_block_size::grouping_from_env(&BLOCK_SIZE_ENV_VARS)
+    }
+}
+
 impl fmt::Display for BlockSize {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -25,7 +25,7 @@ use std::io::stdout;
 use std::path::Path;
 use thiserror::Error;
 
-use crate::blocks::{BlockSize, read_block_size};
+use crate::blocks::{BlockSize, read_block_size, read_grouping};
 use crate::columns::{Column, ColumnError};
 use crate::filesystem::Filesystem;
 use crate::filesystem::FsError;
@@ -63,6 +63,8 @@ struct Options {
     show_all_fs: bool,
     human_readable: Option<HumanReadable>,
     block_size: BlockSize,
+    /// Whether to print the sizes with thousands separators.
+    grouping: bool,
     header_mode: HeaderMode,
 
     /// Optional list of filesystem types to include in the output table.
@@ -93,6 +95,7 @@ impl Default for Options {
             show_local_fs: Default::default(),
             show_all_fs: Default::default(),
             block_size: BlockSize::default(),
+            grouping: Default::default(),
             human_readable: Option::default(),
             header_mode: HeaderMode::default(),
             include: Option::default(),
@@ -175,6 +178,7 @@ impl Options {
                 ParseSizeError::ParseFailure(s) => OptionsError::InvalidBlockSize(s),
                 ParseSizeError::PhysicalMem(s) => OptionsError::InvalidBlockSize(s),
             })?,
+            grouping: read_grouping(matches),
             header_mode: {
                 if matches.get_flag(OPT_HUMAN_READABLE_BINARY)
                     || matches.get_flag(OPT_HUMAN_READABLE_DECIMAL)
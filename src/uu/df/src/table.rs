@@ -14,6 +14,7 @@ use crate::columns::{Alignment, Column};
 use crate::filesystem::Filesystem;
 use crate::{BlockSize, Options};
 use uucore::fsext::{FsUsage, MountInfo};
+use uucore::i18n::decimal::apply_grouping;
 use uucore::translate;
 
 use std::ffi::OsString;
@@ -314,10 +315,13 @@ impl<'a> RowFormatter<'a> {
                 bytes_column.bytes
             };
             to_magnitude_and_suffix(size.into(), SuffixType::HumanReadable(h), true)
+        } else if self.options.grouping {
+            apply_grouping(&size.to_string())
         } else {
             size.to_string()
         };
-        Cell::from_ascii_string(s)
+        // The grouping separator of the locale may be a wide character.
+        Cell::from_string(s)
     }
 
     /// Get a string giving the scaled version of the input number.
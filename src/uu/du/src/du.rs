@@ -27,9 +27,12 @@ use thiserror::Error;
 use uucore::display::{Quotable, print_verbatim};
 use uucore::error::{FromIo, UError, UResult, USimpleError, set_exit_code};
 use uucore::fsext::{MetadataTimeField, metadata_get_time};
+use uucore::i18n::decimal::apply_grouping;
 use uucore::line_ending::LineEnding;
 #[cfg(all(unix, not(target_os = "redox")))]
-use uucore::safe_traversal::{DirFd, SymlinkBehavior};
+use uucore::safe_traversal::{
+    DirFd, SymlinkBehavior, WalkErrorKind, WalkEvent, Walker, default_max_open_dirs,
+};
 use uucore::translate;
 
 use uucore::parser::parse_block_size;
@@ -114,6 +117,9 @@ enum SizeFormat {
     HumanDecimal,
     HumanBinary,
     BlockSize(u64),
+    /// A block size given with a leading `'`, for sizes with thousands
+    /// separators.
+    GroupedBlockSize(u64),
 }
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
@@ -301,12 +307,13 @@ fn get_file_info(path: &Path, _metadata: &Metadata) -> Option<FileInfo> {
     result
 }
 
+const BLOCK_SIZE_ENV_VARS: [&str; 3] = ["DU_BLOCK_SIZE", "BLOCK_SIZE", "BLOCKSIZE"];
+
 fn read_block_size(s: Option<&str>) -> UResult<u64> {
     if let Some(s) = s {
-        parse_size_u64(s)
+        parse_size_u64(parse_block_size::split_grouping(s).0)
             .map_err(|e| USimpleError::new(1, format_error_message(&e, s, options::BLOCK_SIZE)))
-    } else if let Some(bytes) =
-        parse_block_size::block_size_from_env(&["DU_BLOCK_SIZE", "BLOCK_SIZE", "BLOCKSIZE"]).found()
+    } else if let Some(bytes) = parse_block_size::block_size_from_env(&BLOCK_SIZE_ENV_VARS).found()
     {
         Ok(bytes)
     } else {
@@ -333,50 +340,19 @@ fn time_from_raw_stat(
 fn safe_du(
     path: &Path,
     options: &TraversalOptions,
-    depth: usize,
     seen_inodes: &mut HashSet<FileInfo>,
     print_tx: &mpsc::Sender<UResult<StatPrintInfo>>,
-    parent_fd: Option<&DirFd>,
-    initial_stat: Option<std::io::Result<Stat>>,
+    initial_stat: std::io::Result<Stat>,
 ) -> Result<Stat, Box<mpsc::SendError<UResult<StatPrintInfo>>>> {
-    // The caller provides an already-computed stat for this entry, which lets us
-    // avoid re-stating it here. For subdirectories this saves both a redundant
-    // `fstatat` and an expensive full-path `lstat` per directory (the dominant cost
-    // when traversing deep trees). The root directory may instead carry an error,
-    // in which case we fall back to opening it directly with a DirFd.
-    let initial_stat = initial_stat.unwrap_or_else(|| Stat::new(path, None, options));
-    let mut my_stat = match initial_stat {
-        Ok(mut s) => {
-            // For subdirectories the `metadata` field is a cheap placeholder (the
-            // parent directory's metadata). It is only consulted by `--time`, so we
-            // only pay for a real stat of this entry when time reporting is asked for.
-            if options.time.is_some()
-                && parent_fd.is_some()
-                && let Ok(md) = fs::symlink_metadata(&s.path)
-            {
-                s.metadata = md;
-            }
-            s
-        }
+    // The caller provides an already-computed stat for the root. If that
+    // failed, fall back to opening it directly with a DirFd.
+    let my_stat = match initial_stat {
+        Ok(s) => s,
         Err(_e) => {
-            // Only the root path can reach this branch (subdirectories always pass a
-            // valid stat). Try using our DirFd method for the root directory.
-            match DirFd::open(path, SymlinkBehavior::Follow) {
-                Ok(dir_fd) => match Stat::new_from_dirfd(&dir_fd, path, options.time) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        let error = e.map_err_context(
-                            || translate!("du-error-cannot-access", "path" => path.quote()),
-                        );
-                        if let Err(send_error) = print_tx.send(Err(error)) {
-                            return Err(Box::new(send_error));
-                        }
-                        return Err(Box::new(mpsc::SendError(Err(USimpleError::new(
-                            0,
-                            "Error already handled",
-                        )))));
-                    }
-                },
+            let stat = DirFd::open(path, SymlinkBehavior::Follow)
+                .and_then(|dir_fd| Stat::new_from_dirfd(&dir_fd, path, options.time));
+            match stat {
+                Ok(s) => s,
                 Err(e) => {
                     let error = e.map_err_context(
                         || translate!("du-error-cannot-access", "path" => path.quote()),
@@ -396,28 +372,12 @@ fn safe_du(
         return Ok(my_stat);
     }
 
-    // Open the directory using DirFd
-    let open_result = match parent_fd {
-        Some(parent) => parent.open_subdir(
-            path.file_name().unwrap_or(path.as_os_str()),
-            SymlinkBehavior::Follow,
-        ),
-        None => DirFd::open(path, SymlinkBehavior::Follow),
-    };
-
-    let dir_fd = match open_result {
-        Ok(fd) => fd,
-        Err(e) => {
-            print_tx.send(Err(e.map_err_context(
-                || translate!("du-error-cannot-read-directory", "path" => path.quote()),
-            )))?;
-            return Ok(my_stat);
-        }
-    };
-
-    // Read directory entries
-    let entries = match dir_fd.read_dir() {
-        Ok(entries) => entries,
+    // Walk the tree iteratively, so that deep trees neither exhaust the stack
+    // nor the open file limit.
+    let walker = DirFd::open(path, SymlinkBehavior::Follow)
+        .and_then(|dir_fd| Walker::new(dir_fd, path, default_max_open_dirs()));
+    let walker = match walker {
+        Ok(walker) => walker,
         Err(e) => {
             print_tx.send(Err(e.map_err_context(
                 || translate!("du-error-cannot-read-directory", "path" => path.quote()),
@@ -425,41 +385,69 @@ fn safe_du(
             return Ok(my_stat);
         }
     };
+    walk_du(walker, my_stat, options, seen_inodes, print_tx)
+}
 
-    'file_loop: for entry_name in entries {
-        const S_IFMT: u32 = 0o170_000;
-        const S_IFDIR: u32 = 0o040_000;
-        const S_IFLNK: u32 = 0o120_000;
+/// Sum up the sizes reported by `walker`, printing entries as they complete.
+///
+/// `stack` holds the directories currently being summed, the root at the
+/// bottom.
+#[cfg(all(unix, not(target_os = "redox")))]
+fn walk_du(
+    mut walker: Walker,
+    root_stat: Stat,
+    options: &TraversalOptions,
+    seen_inodes: &mut HashSet<FileInfo>,
+    print_tx: &mpsc::Sender<UResult<StatPrintInfo>>,
+) -> Result<Stat, Box<mpsc::SendError<UResult<StatPrintInfo>>>> {
+    fn add(parent: &mut Stat, child: &Stat) {
+        parent.size += child.size;
+        parent.blocks += child.blocks;
+        parent.inodes += child.inodes;
+        parent.latest_time = match (parent.latest_time, child.latest_time) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, None) => a,
+            (None, b) => b,
+        };
+    }
 
-        // First get the lstat (without following symlinks) to check if it's a symlink
-        let lstat = match dir_fd.stat_at(&entry_name, SymlinkBehavior::NoFollow) {
-            Ok(stat) => stat,
-            Err(e) => {
-                let entry_path = path.join(&entry_name);
-                print_tx.send(Err(e.map_err_context(
-                    || translate!("du-error-cannot-access", "path" => entry_path.quote()),
-                )))?;
+    let mut stack = vec![root_stat];
+    while let Some(event) = walker.next() {
+        let (path, entry_stat, depth, is_dir) = match event {
+            WalkEvent::PreDir { path, stat, depth } => (path, stat, depth, true),
+            WalkEvent::Entry { path, stat, depth } => (path, stat, depth, false),
+            WalkEvent::PostDir { depth: 0, .. } => continue,
+            WalkEvent::PostDir { depth, .. } => {
+                let this_stat = stack.pop().expect("directory was entered");
+                let parent = stack.last_mut().expect("root is never left early");
+                if !options.separate_dirs {
+                    add(parent, &this_stat);
+                }
+                print_tx.send(Ok(StatPrintInfo {
+                    stat: this_stat,
+                    depth,
+                }))?;
+                continue;
+            }
+            // Only possible through bind mounts; the directory has already
+            // been counted.
+            WalkEvent::Cycle { .. } => continue,
+            WalkEvent::Error {
+                path, error, kind, ..
+            } => {
+                let message = match kind {
+                    WalkErrorKind::Stat => {
+                        translate!("du-error-cannot-access", "path" => path.quote())
+                    }
+                    WalkErrorKind::ReadDir => {
+                        translate!("du-error-cannot-read-directory", "path" => path.quote())
+                    }
+                };
+                print_tx.send(Err(error.map_err_context(|| message)))?;
                 continue;
             }
         };
 
-        // Check if it's a symlink
-        #[allow(clippy::unnecessary_cast)]
-        let is_symlink = (lstat.st_mode as u32 & S_IFMT) == S_IFLNK;
-
-        // Handle symlinks with -L option
-        // For safe traversal with -L, we skip symlinks to directories entirely
-        // and let the non-safe traversal handle them at the top level
-        if is_symlink && options.dereference == Deref::All {
-            // Skip symlinks to directories when using safe traversal with -L
-            // They will be handled by regular traversal
-            continue;
-        }
-
-        #[allow(clippy::unnecessary_cast)]
-        let is_dir = (lstat.st_mode as u32 & S_IFMT) == S_IFDIR;
-        let entry_stat = lstat;
-
         #[allow(clippy::unnecessary_cast)]
         let file_info = (entry_stat.st_ino != 0).then_some(FileInfo {
             file_id: entry_stat.st_ino as u128,
@@ -471,115 +459,76 @@ fn safe_du(
             .time
             .and_then(|time| time_from_raw_stat(&safe_metadata, time));
 
-        // For safe traversal, we need to handle stats differently
-        // We can't use std::fs::Metadata since that requires the full path
-        let this_stat = if is_dir {
-            // For directories, recurse using safe_du
-            Stat {
-                path: path.join(&entry_name),
-                size: 0,
-                #[allow(clippy::unnecessary_cast)]
-                blocks: entry_stat.st_blocks as u64,
-                inodes: 1,
-                inode: file_info,
-                // We need a fake metadata - create one from symlink_metadata of parent
-                // This is a workaround since we can't get real metadata without the full path
-                metadata: my_stat.metadata.clone(),
-                latest_time,
-            }
-        } else {
-            // For files
-            Stat {
-                path: path.join(&entry_name),
-                #[allow(clippy::unnecessary_cast)]
-                size: entry_stat.st_size as u64,
-                #[allow(clippy::unnecessary_cast)]
-                blocks: entry_stat.st_blocks as u64,
-                inodes: 1,
-                inode: file_info,
-                metadata: my_stat.metadata.clone(),
-                latest_time,
-            }
+        let parent = stack.last().expect("root is never left early");
+        let mut this_stat = Stat {
+            #[allow(clippy::unnecessary_cast)]
+            size: if is_dir { 0 } else { entry_stat.st_size as u64 },
+            #[allow(clippy::unnecessary_cast)]
+            blocks: entry_stat.st_blocks as u64,
+            inodes: 1,
+            inode: file_info,
+            // A std::fs::Metadata needs the full path; reuse the parent's,
+            // which is only consulted by --time for directories (see below).
+            metadata: parent.metadata.clone(),
+            latest_time,
+            path,
         };
 
         // Check excludes
-        for pattern in &options.excludes {
-            if pattern.matches(&this_stat.path.to_string_lossy())
+        let entry_name = this_stat.path.file_name().unwrap_or_default();
+        if options.excludes.iter().any(|pattern| {
+            pattern.matches(&this_stat.path.to_string_lossy())
                 || pattern.matches(&entry_name.to_string_lossy())
-            {
-                if options.verbose {
-                    println!(
-                        "{}",
-                        translate!("du-verbose-ignored", "path" => this_stat.path.quote())
-                    );
-                }
-                continue 'file_loop;
+        }) {
+            if options.verbose {
+                println!(
+                    "{}",
+                    translate!("du-verbose-ignored", "path" => this_stat.path.quote())
+                );
             }
+            walker.prune();
+            continue;
         }
 
         // Handle inodes
         if let Some(inode) = this_stat.inode {
             if seen_inodes.contains(&inode) && !options.count_links {
+                walker.prune();
                 continue;
             }
             seen_inodes.insert(inode);
         }
 
-        // Process directories recursively
         if is_dir {
             if options.one_file_system
-                && let (Some(this_inode), Some(my_inode)) = (this_stat.inode, my_stat.inode)
-                && this_inode.dev_id != my_inode.dev_id
+                && let (Some(this_inode), Some(parent_inode)) = (
+                    this_stat.inode,
+                    stack.last().and_then(|parent| parent.inode),
+                )
+                && this_inode.dev_id != parent_inode.dev_id
             {
+                walker.prune();
                 continue;
             }
-
-            // Reuse the stat we already computed for this entry instead of
-            // re-stating it inside the recursive call.
-            let sub_path = this_stat.path.clone();
-            let this_stat = safe_du(
-                &sub_path,
-                options,
-                depth + 1,
-                seen_inodes,
-                print_tx,
-                Some(&dir_fd),
-                Some(Ok(this_stat)),
-            )?;
-
-            if !options.separate_dirs {
-                my_stat.size += this_stat.size;
-                my_stat.blocks += this_stat.blocks;
-                my_stat.inodes += this_stat.inodes;
-                my_stat.latest_time = match (my_stat.latest_time, this_stat.latest_time) {
-                    (Some(a), Some(b)) => Some(a.max(b)),
-                    (a, None) => a,
-                    (None, b) => b,
-                }
+            if options.time.is_some()
+                && let Ok(md) = fs::symlink_metadata(&this_stat.path)
+            {
+                this_stat.metadata = md;
             }
-            print_tx.send(Ok(StatPrintInfo {
-                stat: this_stat,
-                depth: depth + 1,
-            }))?;
+            stack.push(this_stat);
         } else {
-            my_stat.size += this_stat.size;
-            my_stat.blocks += this_stat.blocks;
-            my_stat.inodes += 1;
-            my_stat.latest_time = match (my_stat.latest_time, this_stat.latest_time) {
-                (Some(a), Some(b)) => Some(a.max(b)),
-                (a, None) => a,
-                (None, b) => b,
-            };
+            let parent = stack.last_mut().expect("root is never left early");
+            add(parent, &this_stat);
             if options.all {
                 print_tx.send(Ok(StatPrintInfo {
                     stat: this_stat,
-                    depth: depth + 1,
+                    depth,
                 }))?;
             }
         }
     }
 
-    Ok(my_stat)
+    Ok(stack.pop().expect("root is on the stack"))
 }
 
 // this takes `my_stat` to avoid having to stat files multiple times.
@@ -902,6 +851,14 @@ impl StatPrinter {
                     size.div_ceil(block_size).to_string()
                 }
             }
+            SizeFormat::GroupedBlockSize(block_size) => {
+                let size = if self.inodes {
+                    size
+                } else {
+                    size.div_ceil(block_size)
+                };
+                apply_grouping(&size.to_string())
+            }
         }
     }
 
@@ -999,7 +956,15 @@ fn parse_block_size_arg_or_default_fallback(matches: &ArgMatches) -> UResult<Siz
         return Err(std::io::Error::other(translate!("du-error-invalid-block-size-argument", "option" => options::BLOCK_SIZE, "value" => block_size_str.map_or("???BUG", |v| v).quote()))
         .into());
     }
-    Ok(SizeFormat::BlockSize(block_size))
+    let grouping = match block_size_str {
+        Some(s) => parse_block_size::split_grouping(s).1,
+        None => parse_block_size::grouping_from_env(&BLOCK_SIZE_ENV_VARS),
+    };
+    if grouping {
+        Ok(SizeFormat::GroupedBlockSize(block_size))
+    } else {
+        Ok(SizeFormat::BlockSize(block_size))
+    }
 }
 
 fn parse_size_format(matches: &ArgMatches) -> UResult<SizeFormat> {
@@ -1186,15 +1151,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             // Use safe traversal (Unix except Redox, when not using -L)
             #[cfg(all(unix, not(target_os = "redox")))]
             {
-                match safe_du(
-                    &path,
-                    &traversal_options,
-                    0,
-                    &mut seen_inodes,
-                    &print_tx,
-                    None,
-                    Some(stat),
-                ) {
+                match safe_du(&path, &traversal_options, &mut seen_inodes, &print_tx, stat) {
                     Ok(stat) => {
                         print_tx
                             .send(Ok(StatPrintInfo { stat, depth: 0 }))
@@ -2,6 +2,10 @@ env-about = Set each NAME to VALUE in the environment and run COMMAND
 env-usage = env [OPTION]... [-] [NAME=VALUE]... [COMMAND [ARG]...]
 env-after-help = A mere - implies -i. If no COMMAND, print the resulting environment.
 
+  SIG may be a signal name like 'PIPE', or a signal number like '13'.
+  Without SIG, all known signals are included. Multiple signals can be
+  comma-separated.
+
 # Help messages
 env-help-ignore-environment = start with an empty environment
 env-help-chdir = change working directory to DIR
@@ -10,11 +14,11 @@ env-help-file = read and set variables from a ".env"-style configuration file (p
 env-help-unset = remove variable from the environment
 env-help-debug = print verbose information for each processing step
 env-help-split-string = process and split S into separate arguments; used to pass multiple arguments on shebang lines
-env-help-argv0 = Override the zeroth argument passed to the command being executed. Without this option a default value of `command` is used.
+env-help-argv0 = pass ARG as the zeroth argument of COMMAND
 env-help-ignore-signal = set handling of SIG signal(s) to do nothing
 env-help-default-signal = reset handling of SIG signal(s) to the default action
 env-help-block-signal = block delivery of SIG signal(s) while running COMMAND
-env-help-list-signal-handling = list signal handling changes requested by preceding options
+env-help-list-signal-handling = list non default signal handling to stderr
 
 # Error messages
 env-error-missing-closing-quote = no terminating quote in -S string at position { $position } for quote '{ $quote }'
@@ -39,6 +43,8 @@ env-error-argv0-not-supported = --argv0 is currently not supported on this platf
 env-error-permission-denied = { $program }: Permission denied
 env-error-unknown = unknown error: { $error }
 env-error-failed-set-signal-action = failed to set signal action for signal { $signal }: { $error }
+env-error-failed-get-signal-mask = failed to get signal process mask: { $error }
+env-error-failed-set-signal-mask = failed to set signal process mask: { $error }
 
 # Warning messages
 env-warning-no-name-specified = no name specified for value { $value }
@@ -2,6 +2,10 @@ env-about = Définir chaque NOM à VALEUR dans l'environnement et exécuter COMM
 env-usage = env [OPTION]... [-] [NOM=VALEUR]... [COMMANDE [ARG]...]
 env-after-help = Un simple - implique -i. Si aucune COMMANDE, afficher l'environnement résultant.
 
+  SIG peut être un nom de signal comme 'PIPE', ou un numéro de signal comme '13'.
+  Sans SIG, tous les signaux connus sont inclus. Plusieurs signaux peuvent être
+  séparés par des virgules.
+
 # Messages d'aide
 env-help-ignore-environment = commencer avec un environnement vide
 env-help-chdir = changer le répertoire de travail vers RÉP
@@ -10,11 +14,11 @@ env-help-file = lire et définir les variables à partir d'un fichier de configu
 env-help-unset = supprimer la variable de l'environnement
 env-help-debug = afficher des informations détaillées pour chaque étape de traitement
 env-help-split-string = traiter et diviser S en arguments séparés ; utilisé pour passer plusieurs arguments sur les lignes shebang
-env-help-argv0 = Remplacer le zéroième argument passé à la commande en cours d'exécution. Sans cette option, une valeur par défaut de `command` est utilisée.
+env-help-argv0 = passer ARG comme argument zéro de COMMANDE
 env-help-ignore-signal = définir la gestion du/des signal/signaux SIG pour ne rien faire
 env-help-default-signal = réinitialiser la gestion du/des signal/signaux SIG à l'action par défaut
 env-help-block-signal = bloquer la livraison du/des signal/signaux SIG pendant l'exécution de COMMAND
-env-help-list-signal-handling = lister les traitements de signaux modifiés par les options précédentes
+env-help-list-signal-handling = lister sur la sortie d'erreur les traitements de signaux non par défaut
 
 # Messages d'erreur
 env-error-missing-closing-quote = aucune guillemet de fermeture dans la chaîne -S à la position { $position } pour la guillemet '{ $quote }'
@@ -39,6 +43,8 @@ env-error-argv0-not-supported = --argv0 n'est actuellement pas pris en charge su
 env-error-permission-denied = { $program } : Permission refusée
 env-error-unknown = erreur inconnue : { $error }
 env-error-failed-set-signal-action = échec de la définition de l'action du signal pour le signal { $signal } : { $error }
+env-error-failed-get-signal-mask = échec de l'obtention du masque de signaux du processus : { $error }
+env-error-failed-set-signal-mask = échec de la définition du masque de signaux du processus : { $error }
 
 # Messages d'avertissement
 env-warning-no-name-specified = aucun nom spécifié pour la valeur { $value }
@@ -26,7 +26,7 @@ use nix::sys::signal::{SigSet, SigmaskHow, Signal, sigprocmask};
 use nix::unistd::execvp;
 use std::borrow::Cow;
 #[cfg(unix)]
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 use std::env;
 #[cfg(unix)]
 use std::ffi::CString;
@@ -38,9 +38,11 @@ use std::io::stderr;
 use std::mem::zeroed;
 #[cfg(unix)]
 use std::os::unix::ffi::OsStrExt;
+#[cfg(unix)]
+use std::ptr;
 
 use uucore::display::{Quotable, print_all_env_vars};
-use uucore::error::{ExitCode, UError, UResult, USimpleError, UUsageError};
+use uucore::error::{ExitCode, UError, UResult, USimpleError, UUsageError, strip_errno};
 use uucore::line_ending::LineEnding;
 #[cfg(unix)]
 use uucore::signals::{
@@ -99,6 +101,12 @@ mod options {
     pub const LIST_SIGNAL_HANDLING: &str = "list-signal-handling";
 }
 
+/// Value of a signal option given without `=SIG`, meaning every signal.
+///
+/// Command line arguments cannot contain a NUL byte, so this cannot clash with
+/// an explicit (possibly empty) signal list.
+const ALL_SIGNALS_VALUE: &str = "\0";
+
 struct Options<'a> {
     ignore_env: bool,
     line_ending: LineEnding,
@@ -109,11 +117,7 @@ struct Options<'a> {
     program: Vec<&'a OsStr>,
     argv0: Option<&'a OsStr>,
     #[cfg(unix)]
-    ignore_signal: SignalRequest,
-    #[cfg(unix)]
-    default_signal: SignalRequest,
-    #[cfg(unix)]
-    block_signal: SignalRequest,
+    signals: SignalRequests,
     #[cfg(unix)]
     list_signal_handling: bool,
 }
@@ -147,29 +151,26 @@ fn parse_program_opt<'a>(opts: &mut Options<'a>, opt: &'a OsStr) -> UResult<()>
 #[cfg(unix)]
 fn parse_signal_value(signal_name: &str) -> UResult<usize> {
     let signal_name_upcase = signal_name.to_uppercase();
-    let optional_signal_value = signal_by_name_or_value(&signal_name_upcase);
-    let error = USimpleError::new(
-        125,
-        translate!("env-error-invalid-signal", "signal" => signal_name.quote()),
-    );
-    match optional_signal_value {
-        Some(sig_val) => {
-            if sig_val == 0 {
-                Err(error)
-            } else {
-                Ok(sig_val)
-            }
-        }
-        None => Err(error),
+    match signal_by_name_or_value(&signal_name_upcase) {
+        Some(sig_val) if sig_val != 0 && signal_is_valid(sig_val) => Ok(sig_val),
+        _ => Err(UUsageError::new(
+            125,
+            translate!("env-error-invalid-signal", "signal" => signal_name.quote()),
+        )),
     }
 }
 
+/// Parse the comma separated signal list of a signal option, or every signal
+/// if the option was given without a value.
 #[cfg(unix)]
-fn parse_signal_opt(target: &mut SignalRequest, opt: &OsStr) -> UResult<()> {
-    if opt.is_empty() {
-        return Ok(());
+fn parse_signal_opt(opt: &OsStr) -> UResult<Vec<usize>> {
+    if opt == ALL_SIGNALS_VALUE {
+        return Ok((1..=signal_number_upper_bound())
+            .filter(|&sig| signal_is_valid(sig))
+            .collect());
     }
 
+    let mut signals = Vec::new();
     for sig in opt
         .as_bytes()
         .split(|&b| b == b',')
@@ -177,122 +178,89 @@ fn parse_signal_opt(target: &mut SignalRequest, opt: &OsStr) -> UResult<()> {
         .map(OsStr::from_bytes)
     {
         let sig_str = sig.to_str().ok_or_else(|| {
-            USimpleError::new(
-                1,
+            UUsageError::new(
+                125,
                 translate!("env-error-invalid-signal", "signal" => sig.quote()),
             )
         })?;
-        let sig_val = parse_signal_value(sig_str)?;
-        target.signals.insert(sig_val);
-    }
-
-    Ok(())
-}
-
-#[cfg(unix)]
-#[derive(Default, Debug)]
-struct SignalRequest {
-    apply_all: bool,
-    signals: BTreeSet<usize>,
-}
-
-#[cfg(unix)]
-impl SignalRequest {
-    fn is_empty(&self) -> bool {
-        !self.apply_all && self.signals.is_empty()
+        signals.push(parse_signal_value(sig_str)?);
     }
 
-    fn for_each_signal<F>(&self, mut f: F) -> UResult<()>
-    where
-        F: FnMut(usize, bool) -> UResult<()>,
-    {
-        if self.is_empty() {
-            return Ok(());
-        }
-        for &sig in &self.signals {
-            f(sig, true)?;
-        }
-        if self.apply_all {
-            for sig_value in 1..=signal_number_upper_bound() {
-                if self.signals.contains(&sig_value) {
-                    continue;
-                }
-                // SIGKILL (9) and SIGSTOP (17 on mac, 19 on linux) cannot be caught or ignored
-                if sig_value == libc::SIGKILL as usize || sig_value == libc::SIGSTOP as usize {
-                    continue;
-                }
-                f(sig_value, false)?;
-            }
-        }
-        Ok(())
-    }
+    Ok(signals)
 }
 
 #[cfg(unix)]
-#[derive(Copy, Clone)]
-enum SignalActionKind {
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum SignalAction {
     Default,
     Ignore,
-    Block,
 }
 
 #[cfg(unix)]
-#[derive(Copy, Clone)]
-struct SignalActionRecord {
-    kind: SignalActionKind,
+#[derive(Copy, Clone, Debug)]
+struct SignalActionRequest {
+    action: SignalAction,
+    /// Whether the signal was named explicitly, in which case a failure to
+    /// change its handler is an error.
     explicit: bool,
 }
 
+/// The signal handling changes requested on the command line.
+///
+/// Like GNU env, the options are applied in the order they were given, so the
+/// last option naming a signal determines what happens to it.
 #[cfg(unix)]
-#[derive(Default)]
-struct SignalActionLog {
-    records: BTreeMap<usize, SignalActionRecord>,
-}
-
-#[cfg(unix)]
-impl SignalActionLog {
-    fn record(&mut self, sig_value: usize, kind: SignalActionKind, explicit: bool) {
-        self.records
-            .entry(sig_value)
-            .and_modify(|entry| {
-                entry.kind = kind;
-                if explicit {
-                    entry.explicit = true;
-                }
-            })
-            .or_insert(SignalActionRecord { kind, explicit });
-    }
+#[derive(Default, Debug)]
+struct SignalRequests {
+    actions: BTreeMap<usize, SignalActionRequest>,
+    /// `true` to block the signal, `false` to unblock it.
+    mask: BTreeMap<usize, bool>,
 }
 
 #[cfg(unix)]
-fn build_signal_request(
-    matches: &clap::ArgMatches,
-    option: &str,
-    signal_apply_all: &BTreeSet<&str>,
-) -> UResult<SignalRequest> {
-    let mut request = SignalRequest::default();
-    let mut provided_values = 0usize;
-
-    let mut explicit_empty = false;
-    if let Some(iter) = matches.get_many::<OsString>(option) {
-        for opt in iter {
-            if opt.is_empty() {
-                if !signal_apply_all.contains(option) {
-                    explicit_empty = true;
+impl SignalRequests {
+    fn from_matches(matches: &clap::ArgMatches) -> UResult<Self> {
+        let mut occurrences = Vec::new();
+        for option in [
+            options::IGNORE_SIGNAL,
+            options::DEFAULT_SIGNAL,
+            options::BLOCK_SIGNAL,
+        ] {
+            if let (Some(indices), Some(values)) = (
+                matches.indices_of(option),
+                matches.get_many::<OsString>(option),
+            ) {
+                occurrences.extend(indices.zip(values).map(|(i, value)| (i, option, value)));
+            }
+        }
+        occurrences.sort_by_key(|&(i, _, _)| i);
+
+        let mut requests = Self::default();
+        for (_, option, value) in occurrences {
+            let explicit = value != ALL_SIGNALS_VALUE;
+            for sig in parse_signal_opt(value)? {
+                match option {
+                    options::IGNORE_SIGNAL => {
+                        requests.set_action(sig, SignalAction::Ignore, explicit);
+                    }
+                    options::DEFAULT_SIGNAL => {
+                        requests.set_action(sig, SignalAction::Default, explicit);
+                        requests.mask.insert(sig, false);
+                    }
+                    _ => {
+                        requests.mask.insert(sig, true);
+                    }
                 }
-                continue;
             }
-            provided_values += 1;
-            parse_signal_opt(&mut request, opt)?;
         }
-    }
 
-    let present = matches.contains_id(option);
-    if present && provided_values == 0 && !explicit_empty {
-        request.apply_all = true;
+        Ok(requests)
     }
 
-    Ok(request)
+    fn set_action(&mut self, sig: usize, action: SignalAction, explicit: bool) {
+        self.actions
+            .insert(sig, SignalActionRequest { action, explicit });
+    }
 }
 
 #[cfg(unix)]
@@ -411,7 +379,7 @@ pub fn uu_app() -> Command {
                 .overrides_with(options::ARGV0)
                 .short('a')
                 .long(options::ARGV0)
-                .value_name("a")
+                .value_name("ARG")
                 .action(ArgAction::Set)
                 .value_parser(ValueParser::os_string())
                 .help(translate!("env-help-argv0")),
@@ -428,7 +396,7 @@ pub fn uu_app() -> Command {
                 .num_args(0..=1)
                 .require_equals(true)
                 .action(ArgAction::Append)
-                .default_missing_value("")
+                .default_missing_value(ALL_SIGNALS_VALUE)
                 .value_parser(ValueParser::os_string())
                 .help(translate!("env-help-ignore-signal")),
         )
@@ -439,7 +407,7 @@ pub fn uu_app() -> Command {
                 .num_args(0..=1)
                 .require_equals(true)
                 .action(ArgAction::Append)
-                .default_missing_value("")
+                .default_missing_value(ALL_SIGNALS_VALUE)
                 .value_parser(ValueParser::os_string())
                 .help(translate!("env-help-default-signal")),
         )
@@ -450,7 +418,7 @@ pub fn uu_app() -> Command {
                 .num_args(0..=1)
                 .require_equals(true)
                 .action(ArgAction::Append)
-                .default_missing_value("")
+                .default_missing_value(ALL_SIGNALS_VALUE)
                 .value_parser(ValueParser::os_string())
                 .help(translate!("env-help-block-signal")),
         )
@@ -509,10 +477,43 @@ fn debug_print_args(args: &[OsString]) {
     }
 }
 
+fn debug_print_split_strings(split_strings: &[(OsString, Vec<OsString>)]) {
+    let mut error = stderr().lock();
+    for (input, words) in split_strings {
+        let _ = writeln!(error, "split -S:  {}", input.quote());
+        for (i, word) in words.iter().enumerate() {
+            let prefix = if i == 0 { " into:" } else { "     &" };
+            let _ = writeln!(error, "{prefix}    {}", word.quote());
+        }
+    }
+}
+
+/// Split the payload of a `-S` argument into words, remembering the result so
+/// that it can be shown when `-v` is given.
+fn split_string_arg(
+    payload: &NativeIntStr,
+    all_args: &mut Vec<OsString>,
+    split_strings: &mut Vec<(OsString, Vec<OsString>)>,
+) -> UResult<()> {
+    let words: Vec<OsString> = parse_args_from_str(payload)?
+        .into_iter()
+        .map(from_native_int_representation_owned)
+        .collect();
+    all_args.extend(words.iter().cloned());
+    if !words.is_empty() {
+        split_strings.push((
+            from_native_int_representation_owned(payload.to_owned()),
+            words,
+        ));
+    }
+    Ok(())
+}
+
 fn check_and_handle_string_args(
     arg: &OsString,
     prefix_to_test: &str,
     all_args: &mut Vec<OsString>,
+    split_strings: &mut Vec<(OsString, Vec<OsString>)>,
     do_debug_print_args: Option<&Vec<OsString>>,
     require_non_empty_payload: bool,
     strip_optional_leading_equals: bool,
@@ -537,12 +538,7 @@ fn check_and_handle_string_args(
             remaining_arg
         };
 
-        let arg_strings = parse_args_from_str(remaining_arg)?;
-        all_args.extend(
-            arg_strings
-                .into_iter()
-                .map(from_native_int_representation_owned),
-        );
+        split_string_arg(remaining_arg, all_args, split_strings)?;
 
         Ok(true)
     } else {
@@ -555,13 +551,12 @@ struct EnvAppData {
     do_debug_printing: bool,
     do_input_debug_printing: Option<bool>,
     had_string_argument: bool,
+    split_strings: Vec<(OsString, Vec<OsString>)>,
 }
 
 struct ParsedArguments {
     original_args: Vec<OsString>,
     matches: clap::ArgMatches,
-    #[cfg(unix)]
-    signal_apply_all: BTreeSet<&'static str>,
 }
 
 impl EnvAppData {
@@ -615,6 +610,7 @@ impl EnvAppData {
                     b,
                     "--split-string",
                     &mut all_args,
+                    &mut self.split_strings,
                     None,
                     true,
                     true,
@@ -622,10 +618,28 @@ impl EnvAppData {
                 {
                     self.had_string_argument = true;
                 }
-                b if check_and_handle_string_args(b, "-S", &mut all_args, None, true, false)? => {
+                b if check_and_handle_string_args(
+                    b,
+                    "-S",
+                    &mut all_args,
+                    &mut self.split_strings,
+                    None,
+                    true,
+                    false,
+                )? =>
+                {
                     self.had_string_argument = true;
                 }
-                b if check_and_handle_string_args(b, "-vS", &mut all_args, None, true, false)? => {
+                b if check_and_handle_string_args(
+                    b,
+                    "-vS",
+                    &mut all_args,
+                    &mut self.split_strings,
+                    None,
+                    true,
+                    false,
+                )? =>
+                {
                     self.do_debug_printing = true;
                     self.had_string_argument = true;
                 }
@@ -633,6 +647,7 @@ impl EnvAppData {
                     b,
                     "-vvS",
                     &mut all_args,
+                    &mut self.split_strings,
                     Some(original_args),
                     true,
                     false,
@@ -657,12 +672,11 @@ impl EnvAppData {
                     }
 
                     let native_next_arg = NCvt::convert(next_arg);
-                    let arg_strings = parse_args_from_str(native_next_arg.as_ref())?;
-                    all_args.extend(
-                        arg_strings
-                            .into_iter()
-                            .map(from_native_int_representation_owned),
-                    );
+                    split_string_arg(
+                        native_next_arg.as_ref(),
+                        &mut all_args,
+                        &mut self.split_strings,
+                    )?;
                     self.had_string_argument = true;
                     expecting_arg = false;
                     consumed_split_payload_arg = Some(n + 1);
@@ -703,20 +717,6 @@ impl EnvAppData {
     ) -> Result<ParsedArguments, Box<dyn UError>> {
         let original_args: Vec<OsString> = original_args.collect();
         let args = self.process_all_string_arguments(&original_args)?;
-        #[cfg(unix)]
-        let mut signal_apply_all = BTreeSet::new();
-
-        #[cfg(unix)]
-        for arg in &args {
-            if arg == "--ignore-signal" {
-                signal_apply_all.insert(options::IGNORE_SIGNAL);
-            } else if arg == "--default-signal" {
-                signal_apply_all.insert(options::DEFAULT_SIGNAL);
-            } else if arg == "--block-signal" {
-                signal_apply_all.insert(options::BLOCK_SIGNAL);
-            }
-        }
-
         let app = uu_app();
         let matches = match app.try_get_matches_from(args) {
             Ok(matches) => matches,
@@ -741,8 +741,6 @@ impl EnvAppData {
         Ok(ParsedArguments {
             original_args,
             matches,
-            #[cfg(unix)]
-            signal_apply_all,
         })
     }
 
@@ -750,8 +748,6 @@ impl EnvAppData {
         let ParsedArguments {
             original_args,
             matches,
-            #[cfg(unix)]
-            signal_apply_all,
         } = self.parse_arguments(original_args)?;
 
         self.do_debug_printing = self.do_debug_printing || (0 != matches.get_count("debug"));
@@ -762,12 +758,11 @@ impl EnvAppData {
             debug_print_args(&original_args);
             self.do_input_debug_printing = Some(false);
         }
+        if self.do_debug_printing {
+            debug_print_split_strings(&self.split_strings);
+        }
 
-        let mut opts = make_options(
-            &matches,
-            #[cfg(unix)]
-            &signal_apply_all,
-        )?;
+        let mut opts = make_options(&matches)?;
 
         // NOTE: we manually set and unset the env vars below rather than using Command::env() to more
         //       easily handle the case where no command is given
@@ -781,41 +776,31 @@ impl EnvAppData {
 
         apply_specified_env_vars(&opts);
 
+        // GNU env tests this behavior
+        if opts.program.is_empty() && opts.running_directory.is_some() {
+            return Err(UUsageError::new(
+                125,
+                translate!("env-error-must-specify-command-with-chdir"),
+            ));
+        }
+
         #[cfg(unix)]
         {
-            let mut signal_action_log = SignalActionLog::default();
-            apply_signal_action(
-                &opts.default_signal,
-                &mut signal_action_log,
-                SignalActionKind::Default,
-                reset_signal,
-            )?;
-            apply_signal_action(
-                &opts.ignore_signal,
-                &mut signal_action_log,
-                SignalActionKind::Ignore,
-                ignore_signal,
-            )?;
-            apply_signal_action(
-                &opts.block_signal,
-                &mut signal_action_log,
-                SignalActionKind::Block,
-                block_signal,
-            )?;
+            apply_signal_actions(&opts.signals, self.do_debug_printing)?;
+            apply_signal_mask(&opts.signals, self.do_debug_printing)?;
             if opts.list_signal_handling {
-                list_signal_handling(&signal_action_log);
+                list_signal_handling()?;
             }
         }
 
-        apply_change_directory(&opts)?;
         if opts.program.is_empty() {
             // no program provided, so just dump all env vars to stdout
             print_all_env_vars(opts.line_ending)?;
-        } else {
-            return self.run_program(&opts, self.do_debug_printing);
+            return Ok(());
         }
 
-        Ok(())
+        apply_change_directory(&opts, self.do_debug_printing)?;
+        self.run_program(&opts, self.do_debug_printing)
     }
 
     /// Run the program specified by the options.
@@ -931,6 +916,9 @@ impl EnvAppData {
             cmd.args(args);
 
             match cmd.status() {
+                #[cfg(windows)]
+                Ok(exit) if !exit.success() => Err(uucore::spawn::exit_code(exit).into()),
+                #[cfg(not(windows))]
                 Ok(exit) if !exit.success() => Err(exit.code().unwrap_or(1).into()),
                 Err(ref err) => match err.kind() {
                     io::ErrorKind::NotFound | io::ErrorKind::InvalidInput => {
@@ -968,11 +956,7 @@ fn apply_removal_of_all_env_vars(opts: &Options<'_>) {
     }
 }
 
-#[cfg_attr(not(unix), allow(clippy::elidable_lifetime_names))]
-fn make_options<'a>(
-    matches: &'a clap::ArgMatches,
-    #[cfg(unix)] signal_apply_all: &BTreeSet<&'static str>,
-) -> UResult<Options<'a>> {
+fn make_options(matches: &clap::ArgMatches) -> UResult<Options<'_>> {
     let ignore_env = matches.get_flag("ignore-environment");
     let line_ending = LineEnding::from_zero_flag(matches.get_flag("null"));
     let running_directory = matches
@@ -991,11 +975,7 @@ fn make_options<'a>(
         .map(OsString::as_os_str);
 
     #[cfg(unix)]
-    let ignore_signal = build_signal_request(matches, options::IGNORE_SIGNAL, signal_apply_all)?;
-    #[cfg(unix)]
-    let default_signal = build_signal_request(matches, options::DEFAULT_SIGNAL, signal_apply_all)?;
-    #[cfg(unix)]
-    let block_signal = build_signal_request(matches, options::BLOCK_SIGNAL, signal_apply_all)?;
+    let signals = SignalRequests::from_matches(matches)?;
     #[cfg(unix)]
     let list_signal_handling = matches.get_flag(options::LIST_SIGNAL_HANDLING);
 
@@ -1009,11 +989,7 @@ fn make_options<'a>(
         program: vec![],
         argv0,
         #[cfg(unix)]
-        ignore_signal,
-        #[cfg(unix)]
-        default_signal,
-        #[cfg(unix)]
-        block_signal,
+        signals,
         #[cfg(unix)]
         list_signal_handling,
     };
@@ -1061,25 +1037,20 @@ fn apply_unset_env_vars(opts: &Options<'_>) -> Result<(), Box<dyn UError>> {
     Ok(())
 }
 
-fn apply_change_directory(opts: &Options<'_>) -> Result<(), Box<dyn UError>> {
-    // GNU env tests this behavior
-    if opts.program.is_empty() && opts.running_directory.is_some() {
-        return Err(UUsageError::new(
-            125,
-            translate!("env-error-must-specify-command-with-chdir"),
-        ));
-    }
-
+fn apply_change_directory(
+    opts: &Options<'_>,
+    do_debug_printing: bool,
+) -> Result<(), Box<dyn UError>> {
     if let Some(d) = opts.running_directory {
-        match env::set_current_dir(d) {
-            Ok(()) => d,
-            Err(error) => {
-                return Err(USimpleError::new(
-                    125,
-                    translate!("env-error-cannot-change-directory", "directory" => d.quote(), "error" => error),
-                ));
-            }
-        };
+        if do_debug_printing {
+            let _ = writeln!(stderr(), "chdir:    {}", d.quote());
+        }
+        if let Err(error) = env::set_current_dir(d) {
+            return Err(USimpleError::new(
+                125,
+                translate!("env-error-cannot-change-directory", "directory" => d.quote(), "error" => strip_errno(&error)),
+            ));
+        }
     }
     Ok(())
 }
@@ -1123,136 +1094,128 @@ fn apply_specified_env_vars(opts: &Options<'_>) {
 }
 
 #[cfg(unix)]
-fn apply_signal_action<F>(
-    request: &SignalRequest,
-    log: &mut SignalActionLog,
-    action_kind: SignalActionKind,
-    signal_fn: F,
-) -> UResult<()>
-where
-    F: Fn(usize) -> UResult<()>,
-{
-    request.for_each_signal(|sig_value, explicit| {
-        // On some platforms ALL_SIGNALS may contain values that are not valid in libc.
-        // Skip those invalid ones and continue (GNU env also ignores undefined signals).
-        if !signal_is_valid(sig_value) {
-            return Ok(());
-        }
+fn signal_display_name(sig: usize) -> String {
+    signal_name_by_value(sig).unwrap_or_else(|| sig.to_string())
+}
 
-        signal_fn(sig_value)?;
-        log.record(sig_value, action_kind, explicit);
+#[cfg(unix)]
+fn apply_signal_actions(requests: &SignalRequests, do_debug_printing: bool) -> UResult<()> {
+    for (&sig, request) in &requests.actions {
+        let (handler, action_name) = match request.action {
+            SignalAction::Default => (libc::SIG_DFL, "DEFAULT"),
+            SignalAction::Ignore => (libc::SIG_IGN, "IGNORE"),
+        };
+        // nix::sys::signal::Signal does not cover real-time signals, so we need to call
+        // libc::signal directly.
+        let result =
+            nix::errno::Errno::result(unsafe { libc::signal(sig as libc::c_int, handler) });
+        if let Err(err) = result {
+            // Signals that cannot be caught, like SIGKILL, are only an error when named.
+            if request.explicit {
+                return Err(USimpleError::new(
+                    125,
+                    translate!("env-error-failed-set-signal-action", "signal" => (sig as i32), "error" => err.desc()),
+                ));
+            }
+        }
+        if do_debug_printing {
+            let failure = if result.is_err() {
+                " (failure ignored)"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                stderr(),
+                "Reset signal {} ({sig}) to {action_name}{failure}",
+                signal_display_name(sig)
+            );
+        }
 
         // Set environment variable to communicate to Rust child processes
         // that SIGPIPE should be default (not ignored)
-        if matches!(action_kind, SignalActionKind::Default) && sig_value == libc::SIGPIPE as usize {
+        if request.action == SignalAction::Default && sig == libc::SIGPIPE as usize {
             unsafe {
                 env::set_var("RUST_SIGPIPE", "default");
             }
         }
-
-        Ok(())
-    })
-}
-
-#[cfg(unix)]
-fn ignore_signal(sig: usize) -> UResult<()> {
-    // SAFETY: This is safe because we write the handler for each signal only once, and therefore "the current handler is the default", as the documentation requires it.
-    // nix::sys::signal::Signal does not cover real-time signals, so we need to call
-    // libc::signal directly.
-    let result = unsafe {
-        let res = libc::signal(sig as libc::c_int, libc::SIG_IGN);
-        nix::errno::Errno::result(res)
-    };
-    if let Err(err) = result {
-        return Err(USimpleError::new(
-            125,
-            translate!("env-error-failed-set-signal-action", "signal" => (sig as i32), "error" => err.desc()),
-        ));
     }
     Ok(())
 }
 
 #[cfg(unix)]
-fn reset_signal(sig: usize) -> UResult<()> {
-    // nix::sys::signal::Signal does not cover real-time signals, so we need to call
-    // libc::signal directly.
-    let result = unsafe {
-        let res = libc::signal(sig as libc::c_int, libc::SIG_DFL);
-        nix::errno::Errno::result(res)
-    };
-    if let Err(err) = result {
-        return Err(USimpleError::new(
+fn current_signal_mask() -> UResult<SigSet> {
+    let mut mask = SigSet::empty();
+    sigprocmask(SigmaskHow::SIG_BLOCK, None, Some(&mut mask)).map_err(|err| {
+        USimpleError::new(
             125,
-            translate!("env-error-failed-set-signal-action", "signal" => (sig as i32), "error" => err.desc()),
-        ));
-    }
-    Ok(())
+            translate!("env-error-failed-get-signal-mask", "error" => err.desc()),
+        )
+    })?;
+    Ok(mask)
 }
 
 #[cfg(unix)]
-fn sigset_from_signal_value(sig: usize) -> UResult<SigSet> {
-    // nix::sys::signal::Signal does not cover real time signals, so we need to build
-    // sigset_t manually using libc.
-    let mut sigset: libc::sigset_t = unsafe { zeroed() };
-
-    if let Err(err) = unsafe { nix::errno::Errno::result(libc::sigemptyset(&raw mut sigset)) } {
-        return Err(USimpleError::new(
-            125,
-            translate!(
-                "env-error-failed-set-signal-action",
-                "signal" => (sig as i32),
-                "error" => err.desc()
-            ),
-        ));
+fn apply_signal_mask(requests: &SignalRequests, do_debug_printing: bool) -> UResult<()> {
+    if requests.mask.is_empty() {
+        return Ok(());
     }
 
-    if let Err(err) =
-        unsafe { nix::errno::Errno::result(libc::sigaddset(&raw mut sigset, sig as libc::c_int)) }
-    {
-        return Err(USimpleError::new(
-            125,
-            translate!(
-                "env-error-failed-set-signal-action",
-                "signal" => (sig as i32),
-                "error" => err.desc()
-            ),
-        ));
+    // nix::sys::signal::SigSet does not cover real time signals, so we need to
+    // update the sigset_t manually using libc.
+    let mut mask: libc::sigset_t = *current_signal_mask()?.as_ref();
+    for (&sig, &block) in &requests.mask {
+        unsafe {
+            if block {
+                libc::sigaddset(&raw mut mask, sig as libc::c_int);
+            } else {
+                libc::sigdelset(&raw mut mask, sig as libc::c_int);
+            }
+        }
+        if do_debug_printing {
+            let _ = writeln!(
+                stderr(),
+                "signal {} ({sig}) mask set to {}",
+                signal_display_name(sig),
+                if block { "BLOCK" } else { "UNBLOCK" }
+            );
+        }
     }
 
-    Ok(unsafe { SigSet::from_sigset_t_unchecked(sigset) })
-}
-
-#[cfg(unix)]
-fn block_signal(sig: usize) -> UResult<()> {
-    let set = sigset_from_signal_value(sig)?;
-
-    if let Err(err) = sigprocmask(SigmaskHow::SIG_BLOCK, Some(&set), None) {
-        return Err(USimpleError::new(
+    let mask = unsafe { SigSet::from_sigset_t_unchecked(mask) };
+    sigprocmask(SigmaskHow::SIG_SETMASK, Some(&mask), None).map_err(|err| {
+        USimpleError::new(
             125,
-            translate!(
-                "env-error-failed-set-signal-action",
-                "signal" => (sig as i32),
-                "error" => err.desc()
-            ),
-        ));
-    }
+            translate!("env-error-failed-set-signal-mask", "error" => err.desc()),
+        )
+    })?;
     Ok(())
 }
 
+/// Print every signal that is blocked or ignored, the way GNU env does.
 #[cfg(unix)]
-fn list_signal_handling(log: &SignalActionLog) {
-    for (&sig_value, record) in &log.records {
-        if !record.explicit {
+fn list_signal_handling() -> UResult<()> {
+    let mask = current_signal_mask()?;
+    let mut error = stderr().lock();
+    for sig in (1..=signal_number_upper_bound()).filter(|&sig| signal_is_valid(sig)) {
+        let mut action: libc::sigaction = unsafe { zeroed() };
+        if unsafe { libc::sigaction(sig as libc::c_int, ptr::null(), &raw mut action) } != 0 {
             continue;
         }
-        let action = match record.kind {
-            SignalActionKind::Default => "DEFAULT",
-            SignalActionKind::Ignore => "IGNORE",
-            SignalActionKind::Block => "BLOCK",
+        let blocked = unsafe { libc::sigismember(mask.as_ref(), sig as libc::c_int) } == 1;
+        let ignored = action.sa_sigaction == libc::SIG_IGN;
+        let state = match (blocked, ignored) {
+            (true, true) => "BLOCK,IGNORE",
+            (true, false) => "BLOCK",
+            (false, true) => "IGNORE",
+            (false, false) => continue,
         };
-        let signal_name = signal_name_by_value(sig_value).unwrap_or("?".to_string());
-        eprintln!("{signal_name:<10} ({}): {action}", sig_value as i32);
+        let _ = writeln!(
+            error,
+            "{:<10} ({sig:>2}): {state}",
+            signal_display_name(sig)
+        );
     }
+    Ok(())
 }
 
 #[uucore::main]
@@ -1313,7 +1276,7 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err().to_string(),
-            "no terminating quote in -S string at position 13 for quote '\"'"
+            "invalid backslash at end of string in -S at position 13 in context DoubleQuoted"
         );
 
         // Test EnvInvalidSequenceBackslashXInMinusS
@@ -165,7 +165,7 @@ impl<'a> SplitIterator<'a> {
                 self.get_parser().get_peek_position(),
                 "Delimiter".into(),
             )),
-            Some('_' | NEW_LINE) => {
+            Some('_') => {
                 self.skip_one()?;
                 Ok(())
             }
@@ -219,10 +219,6 @@ impl<'a> SplitIterator<'a> {
                 self.get_parser().get_peek_position(),
                 "Unquoted".into(),
             )),
-            Some(NEW_LINE) => {
-                self.skip_one()?;
-                Ok(())
-            }
             Some('_') => {
                 self.skip_one()?;
                 self.push_word_to_words();
@@ -271,10 +267,6 @@ impl<'a> SplitIterator<'a> {
                 self.get_parser().get_peek_position(),
                 '\'',
             )),
-            Some(NEW_LINE) => {
-                self.skip_one()?;
-                Ok(())
-            }
             Some(SINGLE_QUOTES | BACKSLASH) => {
                 self.take_one()?;
                 Ok(())
@@ -315,15 +307,11 @@ impl<'a> SplitIterator<'a> {
 
     fn state_double_quoted_backslash(&mut self) -> Result<(), EnvError> {
         match self.get_current_char() {
-            None => Err(EnvError::EnvMissingClosingQuote(
+            None => Err(EnvError::EnvInvalidBackslashAtEndOfStringInMinusS(
                 self.get_parser().get_peek_position(),
-                '"',
+                "DoubleQuoted".into(),
             )),
-            Some(NEW_LINE) => {
-                self.skip_one()?;
-                Ok(())
-            }
-            Some(DOUBLE_QUOTES | DOLLAR | BACKSLASH) => {
+            Some(DOUBLE_QUOTES | SINGLE_QUOTES | DOLLAR | BACKSLASH) => {
                 self.take_one()?;
                 Ok(())
             }
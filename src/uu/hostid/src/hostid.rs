@@ -6,6 +6,7 @@
 // spell-checker:ignore (ToDO) gethostid
 
 use clap::Command;
+#[cfg(unix)]
 use libc::{c_long, gethostid};
 use std::io::{Write, stdout};
 use uucore::{error::UResult, format_usage};
@@ -15,6 +16,14 @@ use uucore::translate;
 #[uucore::main(no_signals)]
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     uucore::clap_localization::handle_clap_result(uu_app(), args)?;
+    let result = hostid()?;
+    writeln!(stdout().lock(), "{result:0>8x}")?;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps, reason = "the Windows version can fail")]
+fn hostid() -> std::io::Result<c_long> {
     /*
      * POSIX says gethostid returns a "32-bit identifier" but is silent
      * whether it's sign-extended.  Turn off any sign-extension.  This
@@ -27,8 +36,48 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let mask = 0xffff_ffff;
 
     result &= mask;
-    writeln!(stdout().lock(), "{result:0>8x}")?;
-    Ok(())
+    Ok(result)
+}
+
+/// Windows has no `gethostid`, so the identifier is taken from the
+/// `MachineGuid` that is generated when Windows is installed. Its first
+/// group is 8 hex digits long.
+#[cfg(windows)]
+fn hostid() -> std::io::Result<u32> {
+    use std::io;
+    use std::ptr;
+    use windows_sys::Win32::Foundation::ERROR_SUCCESS;
+    use windows_sys::Win32::System::Registry::{
+        HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ, RRF_SUBKEY_WOW6464KEY, RegGetValueW,
+    };
+
+    let key: Vec<u16> = "SOFTWARE\\Microsoft\\Cryptography\0"
+        .encode_utf16()
+        .collect();
+    let value: Vec<u16> = "MachineGuid\0".encode_utf16().collect();
+    let mut buffer = [0_u16; 64];
+    let mut size = u32::try_from(size_of_val(&buffer)).unwrap();
+    // SAFETY: `size` is the size of `buffer` in bytes, and both names are NUL-terminated.
+    // The 64-bit view is read even from a 32-bit process, as only it has the value.
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            key.as_ptr(),
+            value.as_ptr(),
+            RRF_RT_REG_SZ | RRF_SUBKEY_WOW6464KEY,
+            ptr::null_mut(),
+            buffer.as_mut_ptr().cast(),
+            &raw mut size,
+        )
+    };
+    if status != ERROR_SUCCESS {
+        return Err(io::Error::from_raw_os_error(status as i32));
+    }
+
+    let guid = String::from_utf16_lossy(&buffer[..size as usize / 2]);
+    guid.get(..8)
+        .and_then(|group| u32::from_str_radix(group, 16).ok())
+        .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
 }
 
 pub fn uu_app() -> Command {
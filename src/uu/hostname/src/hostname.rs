@@ -14,6 +14,7 @@ use std::{collections::hash_set::HashSet, ffi::OsString};
 use clap::builder::ValueParser;
 use clap::{Arg, ArgAction, ArgMatches, Command};
 
+use dns_lookup::AddrInfoHints;
 #[cfg(any(target_os = "freebsd", target_os = "openbsd"))]
 use dns_lookup::lookup_host;
 use uucore::translate;
@@ -29,6 +30,11 @@ static OPT_FQDN: &str = "fqdn";
 static OPT_SHORT: &str = "short";
 static OPT_HOST: &str = "host";
 
+#[cfg(unix)]
+const AI_CANONNAME: i32 = libc::AI_CANONNAME;
+#[cfg(windows)]
+const AI_CANONNAME: i32 = windows_sys::Win32::Networking::WinSock::AI_CANONNAME as i32;
+
 #[cfg(windows)]
 mod wsa {
     use std::io;
@@ -166,22 +172,44 @@ fn display_hostname(matches: &ArgMatches) -> UResult<()> {
 
         Ok(())
     } else {
-        if matches.get_flag(OPT_SHORT) || matches.get_flag(OPT_DOMAIN) {
-            let mut it = hostname.char_indices().filter(|&ci| ci.1 == '.');
-            if let Some(ci) = it.next() {
-                if matches.get_flag(OPT_SHORT) {
-                    writeln!(stdout(), "{}", &hostname[0..ci.0])?;
-                } else {
-                    writeln!(stdout(), "{}", &hostname[ci.0 + 1..])?;
-                }
-            } else if matches.get_flag(OPT_SHORT) {
-                writeln!(stdout(), "{hostname}")?;
+        if matches.get_flag(OPT_SHORT) {
+            let short = hostname
+                .split_once('.')
+                .map_or(hostname.as_str(), |(short, _)| short);
+            writeln!(stdout(), "{short}")?;
+            return Ok(());
+        }
+
+        if matches.get_flag(OPT_DOMAIN) {
+            // The domain is the part of the fully qualified name after the host name.
+            if let Some((_, domain)) = fqdn(&hostname).split_once('.') {
+                writeln!(stdout(), "{domain}")?;
             }
             return Ok(());
         }
 
+        if matches.get_flag(OPT_FQDN) {
+            writeln!(stdout(), "{}", fqdn(&hostname))?;
+            return Ok(());
+        }
+
         writeln!(stdout(), "{hostname}")?;
 
         Ok(())
     }
 }
+
+/// The canonical name the resolver gives for `hostname`, or `hostname`
+/// itself if it can't be resolved.
+fn fqdn(hostname: &str) -> String {
+    let hints = AddrInfoHints {
+        flags: AI_CANONNAME,
+        ..AddrInfoHints::default()
+    };
+    dns_lookup::getaddrinfo(Some(hostname), None, Some(hints))
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .and_then(Result::ok)
+        .and_then(|info| info.canonname)
+        .unwrap_or_else(|| hostname.to_owned())
+}
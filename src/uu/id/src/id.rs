@@ -712,10 +712,7 @@ fn id_print(state: &State, groups: &[u32]) -> io::Result<()> {
     )?;
 
     #[cfg(all(feature = "selinux", any(target_os = "linux", target_os = "android")))]
-    if state.selinux_supported
-        && !state.user_specified
-        && std::env::var_os("POSIXLY_CORRECT").is_none()
-    {
+    if state.selinux_supported && !state.user_specified && !uucore::posix::posixly_correct() {
         // print SElinux context (does not depend on "-Z")
         if let Ok(context) = selinux::SecurityContext::current(false) {
             let bytes = context.as_bytes();
@@ -724,10 +721,7 @@ fn id_print(state: &State, groups: &[u32]) -> io::Result<()> {
     }
 
     #[cfg(all(feature = "smack", target_os = "linux"))]
-    if state.smack_supported
-        && !state.user_specified
-        && std::env::var_os("POSIXLY_CORRECT").is_none()
-    {
+    if state.smack_supported && !state.user_specified && !uucore::posix::posixly_correct() {
         // print SMACK label (does not depend on "-Z")
         if let Ok(label) = uucore::smack::get_smack_label_for_self() {
             write!(lock, " context={label}")?;
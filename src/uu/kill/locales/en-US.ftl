@@ -2,8 +2,8 @@ kill-about = Send signal to processes or list information about signals.
 kill-usage = kill [OPTIONS]... PID...
 
 # Help messages
-kill-help-list = Lists signals
-kill-help-table = Lists table of signals
+kill-help-list = Lists signal names, or converts signal names to/from numbers
+kill-help-table = Like -l, but prints a table of signal numbers, names and descriptions
 kill-help-signal = Sends given signal instead of SIGTERM
 
 # Error messages
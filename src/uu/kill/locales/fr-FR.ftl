@@ -2,8 +2,8 @@ kill-about = Envoyer un signal aux processus ou lister les informations sur les
 kill-usage = kill [OPTIONS]... PID...
 
 # Messages d'aide
-kill-help-list = Liste les signaux
-kill-help-table = Liste le tableau des signaux
+kill-help-list = Liste les noms des signaux, ou convertit les noms de signaux en numéros et inversement
+kill-help-table = Comme -l, mais affiche un tableau des numéros, noms et descriptions des signaux
 kill-help-signal = Envoie le signal donné au lieu de SIGTERM
 
 # Messages d'erreur
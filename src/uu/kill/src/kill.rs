@@ -18,8 +18,8 @@ use uucore::error::{FromIo, UError, UResult, USimpleError, strip_errno};
 use uucore::translate;
 
 use uucore::signals::{
-    signal_by_name_or_value, signal_list_name_by_value, signal_list_value_by_name_or_number,
-    signal_number_upper_bound,
+    signal_by_name_or_value, signal_description, signal_list_name_by_value,
+    signal_list_value_by_name_or_number, signal_number_upper_bound,
 };
 use uucore::{format_usage, show};
 
@@ -87,7 +87,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
             kill(sig, &pids);
         }
-        Mode::Table => table()?,
+        Mode::Table => table(&pids_or_signals)?,
         Mode::List => list(&pids_or_signals)?,
     }
 
@@ -168,14 +168,49 @@ fn handle_obsolete(args: &mut Vec<String>) -> UResult<Option<usize>> {
     Ok(None)
 }
 
-fn table() -> UResult<()> {
+/// Print the number, name and description of the given signals, or of all
+/// signals, with the columns aligned for the whole table like GNU.
+fn table(signals: &[String]) -> UResult<()> {
+    let upper_bound = signal_number_upper_bound();
+    let num_width = upper_bound.to_string().len();
+    let name_width = (0..=upper_bound)
+        .filter_map(signal_list_name_by_value)
+        .map(|name| name.len())
+        .max()
+        .unwrap_or_default();
+
+    let operands: Vec<Result<usize, &String>> = if signals.is_empty() {
+        (0..=upper_bound)
+            .filter(|&value| signal_list_name_by_value(value).is_some())
+            .map(Ok)
+            .collect()
+    } else {
+        signals
+            .iter()
+            .map(|signal| parse_list_operand(signal).ok_or(signal))
+            .collect()
+    };
+
     // Buffer the listing so a failed write surfaces as one clean error at flush
     // rather than the runtime's implicit-flush message on top of ours.
     let mut out = BufWriter::new(io::stdout().lock());
-    for signal_value in 0..=signal_number_upper_bound() {
-        if let Some(signal_name) = signal_list_name_by_value(signal_value) {
-            writeln!(out, "{signal_value: >#2} {signal_name}").map_err(KillError::Write)?;
+    for operand in operands {
+        let value = match operand {
+            Ok(value) => value,
+            Err(signal) => {
+                out.flush().map_err(KillError::Write)?;
+                show!(invalid_signal(signal));
+                continue;
+            }
+        };
+        let name = signal_list_name_by_value(value).unwrap_or_else(|| value.to_string());
+        match signal_description(value) {
+            Some(description) => {
+                writeln!(out, "{value:>num_width$} {name:<name_width$} {description}")
+            }
+            None => writeln!(out, "{value:>num_width$} {name}"),
         }
+        .map_err(KillError::Write)?;
     }
     out.flush().map_err(KillError::Write)?;
     Ok(())
@@ -194,24 +229,35 @@ fn normalize_list_signal_value(signal_value: usize) -> Option<usize> {
         .filter(|value| *value <= signal_number_upper_bound())
 }
 
+/// Resolve a signal given to `-l` or `-t`, by name or by number.
+///
+/// GNU kill accepts plain signal numbers, values masked to the low 8 bits,
+/// and exit statuses that encode `128 + signal`.
+fn parse_list_operand(signal_name_or_value: &str) -> Option<usize> {
+    match signal_name_or_value.parse::<usize>() {
+        Ok(value) => normalize_list_signal_value(value),
+        Err(_) => signal_list_value_by_name_or_number(signal_name_or_value),
+    }
+}
+
+fn invalid_signal(signal: &str) -> Box<dyn UError> {
+    USimpleError::new(
+        1,
+        translate!("kill-error-invalid-signal", "signal" => signal.quote()),
+    )
+}
+
 fn print_signal(signal_name_or_value: &str) -> UResult<()> {
     // Resolve the signal to the text kill would print, so the write path is the
-    // same for every branch (a single buffered write + flush).
-    let output = if let Some(signal_value) = signal_name_or_value
-        .parse::<usize>()
-        .ok()
-        .and_then(normalize_list_signal_value)
-    {
-        // GNU kill accepts plain signal numbers, values masked to the low 8 bits,
-        // and exit statuses that encode `128 + signal`.
+    // same for every branch (a single buffered write + flush). Numbers are
+    // converted to names, and names to numbers.
+    let Some(signal_value) = parse_list_operand(signal_name_or_value) else {
+        return Err(invalid_signal(signal_name_or_value));
+    };
+    let output = if signal_name_or_value.parse::<usize>().is_ok() {
         signal_list_name_by_value(signal_value).unwrap_or_else(|| signal_value.to_string())
-    } else if let Some(signal_value) = signal_list_value_by_name_or_number(signal_name_or_value) {
-        signal_value.to_string()
     } else {
-        return Err(USimpleError::new(
-            1,
-            translate!("kill-error-invalid-signal", "signal" => signal_name_or_value.quote()),
-        ));
+        signal_value.to_string()
     };
 
     let mut out = BufWriter::new(io::stdout().lock());
@@ -6,11 +6,13 @@
 // spell-checker:ignore (ToDO) getlogin userlogin
 
 use clap::Command;
+#[cfg(unix)]
 use std::ffi::CStr;
 use std::io::{Write, stdout};
 use uucore::translate;
 use uucore::{error::UResult, show_error};
 
+#[cfg(unix)]
 fn get_userlogin() -> Option<String> {
     let login_ptr = unsafe { libc::getlogin() };
     if login_ptr.is_null() {
@@ -20,6 +22,41 @@ fn get_userlogin() -> Option<String> {
     }
 }
 
+/// The user of the current logon session, which differs from the user of the
+/// process token when the process was started with `runas`.
+#[cfg(windows)]
+fn get_userlogin() -> Option<String> {
+    use std::ptr;
+    use windows_sys::Win32::System::RemoteDesktop::{
+        WTS_CURRENT_SERVER_HANDLE, WTS_CURRENT_SESSION, WTSFreeMemory, WTSQuerySessionInformationW,
+        WTSUserName,
+    };
+
+    let mut buffer: *mut u16 = ptr::null_mut();
+    let mut bytes_returned = 0;
+    // SAFETY: WTS_CURRENT_SERVER_HANDLE and WTS_CURRENT_SESSION are always valid
+    let result = unsafe {
+        WTSQuerySessionInformationW(
+            WTS_CURRENT_SERVER_HANDLE,
+            WTS_CURRENT_SESSION,
+            WTSUserName,
+            &raw mut buffer,
+            &raw mut bytes_returned,
+        )
+    };
+    if result == 0 || buffer.is_null() {
+        return None;
+    }
+
+    // SAFETY: the buffer holds a NUL-terminated UTF-16 string of at most `bytes_returned` bytes
+    let wide = unsafe { std::slice::from_raw_parts(buffer, bytes_returned as usize / 2) };
+    let len = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+    let login = (len > 0).then(|| String::from_utf16_lossy(&wide[..len]));
+    // SAFETY: the buffer was allocated by WTSQuerySessionInformationW
+    unsafe { WTSFreeMemory(buffer.cast()) };
+    login
+}
+
 #[uucore::main(no_signals)]
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let _ = uucore::clap_localization::handle_clap_result(uu_app(), args)?;
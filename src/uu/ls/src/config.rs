@@ -202,6 +202,8 @@ pub struct Config {
     pub(crate) dereference: Dereference,
     pub(crate) ignore_patterns: Vec<Pattern>,
     pub(crate) size_format: SizeFormat,
+    /// Whether sizes in bytes or blocks are printed with thousands separators.
+    pub(crate) grouping: bool,
     pub(crate) directory: bool,
     pub(crate) time: MetadataTimeField,
     #[cfg(unix)]
@@ -762,7 +764,9 @@ impl Config {
                 (DEFAULT_FILE_SIZE_BLOCK_SIZE, 1000)
             } else if opt_hr {
                 (DEFAULT_FILE_SIZE_BLOCK_SIZE, DEFAULT_BLOCK_SIZE)
-            } else if let Ok(size) = parse_size_non_zero_u64(opt_block_size) {
+            } else if let Ok(size) =
+                parse_size_non_zero_u64(parse_block_size::split_grouping(opt_block_size).0)
+            {
                 // --block-size overrides -k
                 (size, size)
             } else {
@@ -777,6 +781,18 @@ impl Config {
         } else {
             (DEFAULT_FILE_SIZE_BLOCK_SIZE, DEFAULT_BLOCK_SIZE)
         };
+        let grouping = match opt_block_size {
+            Some(opt_block_size) => parse_block_size::split_grouping(opt_block_size).1,
+            None => {
+                !opt_si
+                    && !opt_hr
+                    && parse_block_size::grouping_from_env(&[
+                        "LS_BLOCK_SIZE",
+                        "BLOCK_SIZE",
+                        "BLOCKSIZE",
+                    ])
+            }
+        };
 
         let long = {
             let author = options.get_flag(options::AUTHOR);
@@ -987,6 +1003,7 @@ impl Config {
             dereference,
             ignore_patterns,
             size_format,
+            grouping,
             directory: options.get_flag(options::DIRECTORY),
             time,
             color,
@@ -39,10 +39,10 @@ use uucore::fsxattr::has_acl;
 use uucore::libc::{dev_t, major, minor};
 use uucore::{
     error::UResult,
-    format::human::human_readable,
+    format::human::{SizeFormat, human_readable},
     fs::display_permissions,
     fsext::metadata_get_time,
-    i18n::{UEncoding, get_ctype_encoding},
+    i18n::{UEncoding, decimal::apply_grouping, get_ctype_encoding},
     os_str_as_bytes_lossy,
     quoting_style::{QuotingStyle, locale_aware_escape_dir_name, locale_aware_escape_name},
     show,
@@ -718,7 +718,10 @@ fn display_len_or_rdev(metadata: &Metadata, config: &Config) -> SizeOrDeviceId {
 }
 
 pub fn display_size(size: u64, config: &Config) -> String {
-    human_readable(size, config.size_format)
+    match config.size_format {
+        SizeFormat::Bytes if config.grouping => apply_grouping(&size.to_string()),
+        size_format => human_readable(size, size_format),
+    }
 }
 
 /// Takes a [`PathData`] struct and returns a cell with a name ready for displaying.
@@ -5,7 +5,7 @@ mknod-after-help = Mandatory arguments to long options are mandatory for short o
 
   Both MAJOR and MINOR must be specified when TYPE is b, c, or u, and they
   must be omitted when TYPE is p. If MAJOR or MINOR begins with 0x or 0X,
-  it is interpreted as hexadecimal; otherwise, if it begins with 0, as octal;
+  it is interpreted as hexadecimal; if it begins with 0o or 0, as octal;
   otherwise, as decimal. TYPE may be:
 
   - b create a block (buffered) special file
@@ -32,3 +32,5 @@ mknod-error-invalid-mode = invalid mode ({ $error })
 mknod-error-mode-permission-bits-only = mode must specify only file permission bits
 mknod-error-missing-device-type = missing device type
 mknod-error-invalid-device-type = invalid device type { $type }
+mknod-error-invalid-device-number = invalid device number { $number }
+mknod-error-invalid-device = invalid device { $major } { $minor }
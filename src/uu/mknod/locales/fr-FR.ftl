@@ -5,7 +5,7 @@ mknod-after-help = Les arguments obligatoires pour les options longues le sont a
 
   MAJEUR et MINEUR doivent tous deux être spécifiés quand TYPE est b, c, ou u, et ils
   doivent être omis quand TYPE est p. Si MAJEUR ou MINEUR commence par 0x ou 0X,
-  il est interprété comme hexadécimal ; sinon, s'il commence par 0, comme octal ;
+  il est interprété comme hexadécimal ; s'il commence par 0o ou 0, comme octal ;
   sinon, comme décimal. TYPE peut être :
 
   - b créer un fichier spécial bloc (mis en mémoire tampon)
@@ -32,3 +32,5 @@ mknod-error-invalid-mode = mode invalide ({ $error })
 mknod-error-mode-permission-bits-only = le mode ne doit spécifier que les bits de permission de fichier
 mknod-error-missing-device-type = type de périphérique manquant
 mknod-error-invalid-device-type = type de périphérique invalide { $type }
+mknod-error-invalid-device-number = numéro de périphérique invalide { $number }
+mknod-error-invalid-device = périphérique invalide { $major } { $minor }
@@ -13,7 +13,7 @@ use std::io::{self, Write as _};
 use uucore::display::Quotable;
 use uucore::error::{UResult, USimpleError, UUsageError, set_exit_code};
 use uucore::format_usage;
-use uucore::fs::makedev;
+use uucore::fs::{major, makedev, minor};
 use uucore::translate;
 
 #[allow(clippy::unnecessary_cast)]
@@ -176,7 +176,17 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                 translate!("mknod-error-fifo-no-major-minor"),
             ));
         }
-        (_, Some(&major), Some(&minor)) => makedev(major as _, minor as _) as u64,
+        (_, Some(&maj), Some(&min)) => {
+            let dev = makedev(maj as _, min as _);
+            // Reject numbers the platform's dev_t encoding cannot round-trip.
+            if major(dev) as u32 != maj || minor(dev) as u32 != min {
+                return Err(USimpleError::new(
+                    1,
+                    translate!("mknod-error-invalid-device", "major" => maj, "minor" => min),
+                ));
+            }
+            dev as u64
+        }
         _ => {
             return Err(UUsageError::new(
                 1,
@@ -240,13 +250,13 @@ pub fn uu_app() -> Command {
             Arg::new(options::MAJOR)
                 .value_name(options::MAJOR)
                 .help(translate!("mknod-help-major"))
-                .value_parser(value_parser!(u32)),
+                .value_parser(parse_device_number),
         )
         .arg(
             Arg::new(options::MINOR)
                 .value_name(options::MINOR)
                 .help(translate!("mknod-help-minor"))
-                .value_parser(value_parser!(u32)),
+                .value_parser(parse_device_number),
         )
         .arg(
             Arg::new(options::SECURITY_CONTEXT)
@@ -283,6 +293,36 @@ fn parse_mode(str_mode: &str) -> Result<u32, String> {
         })
 }
 
+/// Parse a major or minor device number.
+///
+/// Like GNU mknod, a leading `0x`/`0X` selects hexadecimal and a leading `0`
+/// selects octal; `0o`/`0O` is accepted as an explicit octal prefix too.
+fn parse_device_number(s: &str) -> Result<u32, String> {
+    // Like GNU, a single leading '+' is allowed before any radix prefix.
+    let unsigned = s.strip_prefix('+').unwrap_or(s);
+    let (digits, radix) = if let Some(hex) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+    {
+        (hex, 16)
+    } else if let Some(oct) = unsigned
+        .strip_prefix("0o")
+        .or_else(|| unsigned.strip_prefix("0O"))
+    {
+        (oct, 8)
+    } else if unsigned.len() > 1 && unsigned.starts_with('0') {
+        (&unsigned[1..], 8)
+    } else {
+        (unsigned, 10)
+    };
+    // from_str_radix would also accept a second '+', so only allow digits here.
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(translate!("mknod-error-invalid-device-number", "number" => s.quote()));
+    }
+    u32::from_str_radix(digits, radix)
+        .map_err(|_| translate!("mknod-error-invalid-device-number", "number" => s.quote()))
+}
+
 fn parse_type(tpe: &str) -> Result<FileType, String> {
     // Only check the first character, to allow mnemonic usage like
     // 'mknod /dev/rst0 character 18 0'.
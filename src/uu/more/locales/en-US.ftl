@@ -8,6 +8,7 @@ more-error-cannot-open-io-error = cannot open {$path}: {$error}
 more-error-bad-usage = bad usage
 more-error-cannot-seek-to-line = Cannot seek to line number {$line}
 more-error-pattern-not-found = Pattern not found
+more-error-invalid-pattern = Invalid pattern
 more-error-unknown-key = Unknown key: '{$key}'. Press 'h' for instructions. (unimplemented)
 
 # Help messages
@@ -8,6 +8,7 @@ more-error-cannot-open-io-error = impossible d'ouvrir {$path} : {$error}
 more-error-bad-usage = mauvaise utilisation
 more-error-cannot-seek-to-line = Impossible d'atteindre la ligne numéro {$line}
 more-error-pattern-not-found = Motif non trouvé
+more-error-invalid-pattern = Motif invalide
 more-error-unknown-key = Touche inconnue : '{$key}'. Appuyez sur 'h' pour les instructions. (non implémenté)
 
 # Messages d'aide
@@ -22,6 +22,7 @@ use crossterm::{
     terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
     tty::IsTty,
 };
+use regex::{Captures, Regex};
 
 use uucore::error::{UResult, USimpleError, UUsageError};
 use uucore::format_usage;
@@ -472,7 +473,8 @@ struct Pager<'a> {
     content_rows: usize,
     /// Count of blank lines that have been condensed in the current view
     lines_squeezed: usize,
-    pattern: Option<String>,
+    /// Pattern of the last search
+    pattern: Option<Regex>,
     file_name: Option<&'a str>,
     next_file: Option<&'a str>,
     eof_reached: bool,
@@ -499,7 +501,11 @@ impl<'a> Pager<'a> {
             upper_mark: options.from_line,
             content_rows,
             lines_squeezed: 0,
-            pattern: options.pattern.clone(),
+            // The pattern given on the command line is a plain string.
+            pattern: options
+                .pattern
+                .as_deref()
+                .and_then(|pattern| Regex::new(&regex::escape(pattern)).ok()),
             file_name,
             next_file,
             eof_reached: false,
@@ -604,12 +610,86 @@ impl<'a> Pager<'a> {
         Ok(())
     }
 
+    /// Search forward for the pattern, starting at the line after the top of the screen.
+    fn search_next(&mut self) -> UResult<()> {
+        if self.pattern.is_none() {
+            return Ok(());
+        }
+        let current = self.upper_mark;
+        self.upper_mark = current.saturating_add(1);
+        if let Some(line) = self.search_pattern_in_file() {
+            self.upper_mark = line;
+        } else {
+            self.upper_mark = current;
+            let msg = format!(
+                "{} ({})",
+                translate!("more-error-pattern-not-found"),
+                translate!("more-press-return"),
+            );
+            write!(self.stdout, "\r{}", self.highlight_text(&msg))?;
+            self.stdout.flush()?;
+            self.wait_for_enter_key()?;
+        }
+        Ok(())
+    }
+
+    /// Use the regular expression `pattern` for the next searches. An invalid
+    /// one is reported and the previous pattern is kept; returns whether it was valid.
+    fn set_search_pattern(&mut self, pattern: &str) -> UResult<bool> {
+        let Ok(regex) = Regex::new(pattern) else {
+            let msg = format!(
+                "{} ({})",
+                translate!("more-error-invalid-pattern"),
+                translate!("more-press-return"),
+            );
+            write!(self.stdout, "\r{}", self.highlight_text(&msg))?;
+            self.stdout.flush()?;
+            self.wait_for_enter_key()?;
+            return Ok(false);
+        };
+        self.pattern = Some(regex);
+        Ok(true)
+    }
+
+    /// Read a search pattern on the prompt line. Returns `None` if the input
+    /// is cancelled with Escape or by erasing past the start of the line.
+    fn read_pattern(&mut self) -> UResult<Option<String>> {
+        let mut pattern = String::new();
+        self.clear_line()?;
+        write!(self.stdout, "\r/")?;
+        self.stdout.flush()?;
+        loop {
+            let Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read()?
+            else {
+                continue;
+            };
+            match code {
+                KeyCode::Enter => return Ok(Some(pattern)),
+                KeyCode::Esc => return Ok(None),
+                KeyCode::Backspace => {
+                    if pattern.pop().is_none() {
+                        return Ok(None);
+                    }
+                }
+                KeyCode::Char(c) => pattern.push(c),
+                _ => continue,
+            }
+            self.clear_line()?;
+            write!(self.stdout, "\r/{pattern}")?;
+            self.stdout.flush()?;
+        }
+    }
+
     fn search_pattern_in_file(&mut self) -> Option<usize> {
         let pattern = self.pattern.clone().expect("pattern should be set");
         let mut line_num = self.upper_mark;
         loop {
             match self.get_line(line_num) {
-                Some(line) if line.contains(&pattern) => return Some(line_num),
+                Some(line) if pattern.is_match(line) => return Some(line_num),
                 Some(_) => line_num += 1,
                 None => return None,
             }
@@ -666,6 +746,13 @@ impl<'a> Pager<'a> {
             }
             let mut wrong_key = None;
             match event::read()? {
+                // --- Skip key release events ---
+                // Only Windows reports these, and they must not repeat the command of the key press.
+                Event::Key(KeyEvent {
+                    kind: KeyEventKind::Release,
+                    ..
+                }) => continue,
+
                 // --- Quit commands ---
                 Event::Key(
                     KeyEvent {
@@ -723,17 +810,35 @@ impl<'a> Pager<'a> {
                     self.prev_line();
                 }
 
+                // --- Search ---
+                Event::Key(KeyEvent {
+                    code: KeyCode::Char('/'),
+                    modifiers: KeyModifiers::NONE,
+                    kind: KeyEventKind::Press,
+                    ..
+                }) => {
+                    // An empty pattern repeats the previous search.
+                    // Searching backward with `?` is not implemented.
+                    if let Some(pattern) = self.read_pattern()?
+                        && (pattern.is_empty() || self.set_search_pattern(&pattern)?)
+                    {
+                        self.search_next()?;
+                    }
+                }
+                Event::Key(KeyEvent {
+                    code: KeyCode::Char('n'),
+                    modifiers: KeyModifiers::NONE,
+                    kind: KeyEventKind::Press,
+                    ..
+                }) => {
+                    self.search_next()?;
+                }
+
                 // --- Terminal events ---
                 Event::Resize(col, row) => {
                     self.resize_page(col, row, options.lines);
                 }
 
-                // --- Skip key release events ---
-                Event::Key(KeyEvent {
-                    kind: KeyEventKind::Release,
-                    ..
-                }) => continue,
-
                 // --- Handle unknown keys ---
                 Event::Key(KeyEvent {
                     code: KeyCode::Char(k),
@@ -820,8 +925,9 @@ impl<'a> Pager<'a> {
             // Display the line
             let mut line = self.lines[index].clone();
             if let Some(pattern) = &self.pattern {
-                let highlighted = self.highlight_text(pattern);
-                line = line.replace(pattern, &highlighted);
+                line = pattern
+                    .replace_all(&line, |caps: &Captures| self.highlight_text(&caps[0]))
+                    .into_owned();
             }
             self.stdout.write_all(format!("\r{line}\n").as_bytes())?;
             lines_printed += 1;
@@ -1185,10 +1291,41 @@ mod tests {
         assert!(pager.handle_pattern_search().is_ok());
         let stdout = String::from_utf8_lossy(&pager.stdout);
         assert!(stdout.contains(&translate!("more-error-pattern-not-found")));
-        assert_eq!(pager.pattern, None);
+        assert!(pager.pattern.is_none());
         assert_eq!(pager.upper_mark, 0);
     }
 
+    #[test]
+    fn test_search_next() {
+        let content = "foo\nbar\nbaz\nbar\n";
+        let mut pager = TestPagerBuilder::new(content).pattern("bar").build();
+        pager.search_next().unwrap();
+        assert_eq!(pager.upper_mark, 1);
+        pager.search_next().unwrap();
+        assert_eq!(pager.upper_mark, 3);
+
+        // The position is kept when there is no further match
+        pager.search_next().unwrap();
+        assert_eq!(pager.upper_mark, 3);
+        let stdout = String::from_utf8_lossy(&pager.stdout);
+        assert!(stdout.contains(&translate!("more-error-pattern-not-found")));
+    }
+
+    #[test]
+    fn test_search_regex() {
+        let content = "foo\nbar\nbaz\nbar\n";
+        let mut pager = TestPagerBuilder::new(content).build();
+        assert!(pager.set_search_pattern("^ba[^r]$").unwrap());
+        pager.search_next().unwrap();
+        assert_eq!(pager.upper_mark, 2);
+
+        // An invalid pattern keeps the previous one
+        assert!(!pager.set_search_pattern("ba(").unwrap());
+        let stdout = String::from_utf8_lossy(&pager.stdout);
+        assert!(stdout.contains(&translate!("more-error-invalid-pattern")));
+        assert_eq!(pager.pattern.as_ref().unwrap().as_str(), "^ba[^r]$");
+    }
+
     #[test]
     fn test_wrong_key() {
         let mut pager = TestPagerBuilder::default().silent().build();
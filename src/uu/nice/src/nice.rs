@@ -9,7 +9,6 @@ use clap::{Arg, ArgAction, Command};
 use std::ffi::OsString;
 use std::io::{ErrorKind, Write, stdout};
 use std::num::IntErrorKind;
-use std::os::unix::process::CommandExt;
 use std::process;
 
 use uucore::translate;
@@ -99,8 +98,8 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let matches =
         uucore::clap_localization::handle_clap_result_with_exit_code(uu_app(), args, 125)?;
 
-    let current_niceness = rustix::process::getpriority_process(None)
-        .map_err(|e| USimpleError::new(125, format!("getpriority: {e}")))?;
+    let current_niceness = platform::get_niceness()
+        .map_err(|e| USimpleError::new(125, format!("getpriority: {}", strip_errno(&e))))?;
 
     let Some(mut cmd_iter) = matches.get_many::<String>(options::COMMAND) else {
         if matches.contains_id(options::ADJUSTMENT) {
@@ -129,13 +128,18 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         },
     };
 
+    let cmd = cmd_iter.next().unwrap();
+    let args: Vec<&String> = cmd_iter.collect();
+    let mut command = process::Command::new(cmd);
+    command.args(args);
+
     let new_niceness = current_niceness.saturating_add(adjustment);
     // We can't use `show_warning` because that will panic if stderr
     // isn't writable. The GNU test suite checks specifically that the
     // exit code when failing to write the advisory is 125, but Rust
     // will produce an exit code of 101 when it panics.
-    if let Err(e) = rustix::process::setpriority_process(None, new_niceness) {
-        let warning_msg = translate!("nice-warning-setpriority", "util_name" => "nice", "error" => strip_errno(&e.into()) );
+    if let Err(e) = platform::set_niceness(&mut command, new_niceness) {
+        let warning_msg = translate!("nice-warning-setpriority", "util_name" => "nice", "error" => strip_errno(&e) );
 
         if writeln!(std::io::stderr(), "{warning_msg}").is_err() {
             set_exit_code(125);
@@ -143,10 +147,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         }
     }
 
-    let cmd = cmd_iter.next().unwrap();
-    let args: Vec<&String> = cmd_iter.collect();
-
-    let err = process::Command::new(cmd).args(args).exec();
+    let err = platform::run(&mut command);
 
     show_error!("{cmd}: {err}");
 
@@ -159,6 +160,96 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+mod platform {
+    use std::io;
+    use std::os::unix::process::CommandExt;
+    use std::process::Command;
+
+    pub fn get_niceness() -> io::Result<i32> {
+        Ok(rustix::process::getpriority_process(None)?)
+    }
+
+    /// The niceness is set on nice itself and kept by the exec'ed command.
+    pub fn set_niceness(_command: &mut Command, niceness: i32) -> io::Result<()> {
+        Ok(rustix::process::setpriority_process(None, niceness)?)
+    }
+
+    /// Replace nice with `command`, only returning if that failed.
+    pub fn run(command: &mut Command) -> io::Error {
+        command.exec()
+    }
+}
+
+/// Windows has no niceness, but a priority class per process. Niceness values
+/// are mapped onto the classes by ranges, and a class is reported as the
+/// niceness that is representative of its range:
+///
+/// | niceness   | priority class | reported |
+/// |------------|----------------|----------|
+/// | 15 to 19   | idle           | 19       |
+/// | 5 to 14    | below normal   | 10       |
+/// | -4 to 4    | normal         | 0        |
+/// | -14 to -5  | above normal   | -10      |
+/// | -20 to -15 | high           | -20      |
+///
+/// The realtime class is reported as -20 too, but never requested, as it can
+/// starve the rest of the system.
+#[cfg(windows)]
+mod platform {
+    use std::io;
+    use std::os::windows::process::CommandExt;
+    use std::process::{self, Command};
+    use windows_sys::Win32::System::Threading::{
+        ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, GetCurrentProcess,
+        GetPriorityClass, HIGH_PRIORITY_CLASS, IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS,
+        REALTIME_PRIORITY_CLASS, SetPriorityClass,
+    };
+
+    pub fn get_niceness() -> io::Result<i32> {
+        // SAFETY: `GetCurrentProcess` returns a pseudo handle that is always
+        // valid.
+        match unsafe { GetPriorityClass(GetCurrentProcess()) } {
+            0 => Err(io::Error::last_os_error()),
+            IDLE_PRIORITY_CLASS => Ok(19),
+            BELOW_NORMAL_PRIORITY_CLASS => Ok(10),
+            ABOVE_NORMAL_PRIORITY_CLASS => Ok(-10),
+            HIGH_PRIORITY_CLASS | REALTIME_PRIORITY_CLASS => Ok(-20),
+            _ => Ok(0),
+        }
+    }
+
+    /// Only the idle and below normal classes are inherited by child
+    /// processes, so the class is requested when creating the command. It is
+    /// set on nice itself too, which reports whether the class may be used at
+    /// all.
+    pub fn set_niceness(command: &mut Command, niceness: i32) -> io::Result<()> {
+        let priority_class = match niceness {
+            15.. => IDLE_PRIORITY_CLASS,
+            5..=14 => BELOW_NORMAL_PRIORITY_CLASS,
+            -4..=4 => NORMAL_PRIORITY_CLASS,
+            -14..=-5 => ABOVE_NORMAL_PRIORITY_CLASS,
+            ..=-15 => HIGH_PRIORITY_CLASS,
+        };
+        command.creation_flags(priority_class);
+        // SAFETY: `GetCurrentProcess` returns a pseudo handle that is always
+        // valid.
+        if unsafe { SetPriorityClass(GetCurrentProcess(), priority_class) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Run `command` and exit with its status, only returning if it couldn't
+    /// be started.
+    pub fn run(command: &mut Command) -> io::Error {
+        match command.status() {
+            Ok(status) => process::exit(uucore::spawn::exit_code(status)),
+            Err(err) => err,
+        }
+    }
+}
+
 pub fn uu_app() -> Command {
     Command::new("nice")
         .about(translate!("nice-about"))
@@ -3,19 +3,18 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
-// spell-checker:ignore (ToDO) SIGHUP cproc vprocmgr homeout
-#[cfg(not(unix))]
+// spell-checker:ignore (ToDO) SIGHUP cproc vprocmgr homeout USERPROFILE
+#[cfg(not(any(unix, windows)))]
 compile_error!("nohup is not supported on the target");
 
 use clap::{Arg, ArgAction, Command};
-use rustix::stdio::{dup2_stderr, dup2_stdin, dup2_stdout, stdout};
 use std::env;
+use std::ffi::OsString;
 use std::fs::{File, OpenOptions};
-use std::io::{Error, ErrorKind, IsTerminal};
+use std::io::{Error, ErrorKind};
+#[cfg(unix)]
 use std::os::unix::fs::OpenOptionsExt;
-use std::os::unix::process::CommandExt;
-use std::path::{Path, PathBuf};
-use std::process;
+use std::path::PathBuf;
 use std::sync::LazyLock;
 use thiserror::Error;
 use uucore::display::Quotable;
@@ -76,32 +75,25 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         *FAILURE_CODE,
     )?;
 
-    replace_fds()?;
-
-    unsafe { libc::signal(libc::SIGHUP, libc::SIG_IGN) };
-
-    #[cfg(target_vendor = "apple")]
-    if unsafe { !_vprocmgr_detach_from_console(0).is_null() } {
-        return Err(NohupError::CannotDetach.into());
-    }
     #[allow(clippy::unwrap_used, reason = "set as required by clap")]
     let mut cmd_iter = matches.get_many::<String>(options::CMD).unwrap();
     #[allow(clippy::unwrap_used, reason = "set as required by clap")]
     let cmd = cmd_iter.next().unwrap();
     let args: Vec<&String> = cmd_iter.collect();
 
-    let err = process::Command::new(cmd).args(args).exec();
+    platform::run(cmd, &args)
+}
 
+fn report_failed_to_run(cmd: &str, err: &Error) {
     show_error!(
         "{}",
-        translate!("nohup-error-failed-to-run-command", "command" => cmd.quote(), "error" => strip_errno(&err))
+        translate!("nohup-error-failed-to-run-command", "command" => cmd.quote(), "error" => strip_errno(err))
     );
 
     match err.kind() {
         ErrorKind::NotFound => set_exit_code(EXIT_ENOENT),
         _ => set_exit_code(EXIT_CANNOT_INVOKE),
     }
-    Ok(())
 }
 
 pub fn uu_app() -> Command {
@@ -122,29 +114,9 @@ pub fn uu_app() -> Command {
         .infer_long_args(true)
 }
 
-fn replace_fds() -> UResult<()> {
-    if std::io::stdin().is_terminal() {
-        let new_stdin = File::open(Path::new("/dev/null"))
-            .map_err(|e| NohupError::CannotReplace("STDIN", e))?;
-        dup2_stdin(&new_stdin).map_err(|e| NohupError::CannotReplace("STDIN", Error::from(e)))?;
-    }
-
-    if std::io::stdout().is_terminal() {
-        let new_stdout = find_stdout()?;
-
-        dup2_stdout(&new_stdout)
-            .map_err(|e| NohupError::CannotReplace("STDOUT", Error::from(e)))?;
-    }
-
-    if std::io::stderr().is_terminal() {
-        dup2_stderr(stdout()).map_err(|e| NohupError::CannotReplace("STDERR", Error::from(e)))?;
-    }
-    Ok(())
-}
-
 fn find_stdout() -> UResult<File> {
     try_open_nohup_file(NOHUP_OUT).or_else(|e1| {
-        let Ok(home) = env::var("HOME") else {
+        let Some(home) = home_dir() else {
             return Err(NohupError::OpenFailed(*FAILURE_CODE, e1).into());
         };
 
@@ -157,16 +129,25 @@ fn find_stdout() -> UResult<File> {
     })
 }
 
+fn home_dir() -> Option<OsString> {
+    let home = env::var_os("HOME");
+    // Windows has no HOME unless a POSIX-like shell sets it.
+    #[cfg(windows)]
+    let home = home.or_else(|| env::var_os("USERPROFILE"));
+    home
+}
+
 fn try_open_nohup_file(path: &str) -> std::io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
     // POSIX nohup creates the output file with mode 0600 so that other
     // users on a shared host can't read whatever the detached job logs.
     // Setting `.mode()` here only affects newly-created files; if the
-    // file already exists its permissions are left alone.
-    let file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .mode(0o600)
-        .open(path)?;
+    // file already exists its permissions are left alone. On Windows a new
+    // file inherits the ACL of its directory instead.
+    #[cfg(unix)]
+    options.mode(0o600);
+    let file = options.open(path)?;
 
     show_error!(
         "{}",
@@ -176,7 +157,167 @@ fn try_open_nohup_file(path: &str) -> std::io::Result<File> {
     Ok(file)
 }
 
-#[cfg(target_vendor = "apple")]
-unsafe extern "C" {
-    fn _vprocmgr_detach_from_console(flags: u32) -> *const libc::c_int;
+#[cfg(unix)]
+mod platform {
+    use super::{NohupError, find_stdout, report_failed_to_run};
+    use rustix::stdio::{dup2_stderr, dup2_stdin, dup2_stdout, stdout};
+    use std::fs::File;
+    use std::io::{Error, IsTerminal};
+    use std::os::unix::process::CommandExt;
+    use std::path::Path;
+    use std::process;
+    use uucore::error::UResult;
+
+    pub fn run(cmd: &str, args: &[&String]) -> UResult<()> {
+        replace_fds()?;
+
+        unsafe { libc::signal(libc::SIGHUP, libc::SIG_IGN) };
+
+        #[cfg(target_vendor = "apple")]
+        if unsafe { !_vprocmgr_detach_from_console(0).is_null() } {
+            return Err(NohupError::CannotDetach.into());
+        }
+
+        let err = process::Command::new(cmd).args(args).exec();
+        report_failed_to_run(cmd, &err);
+        Ok(())
+    }
+
+    fn replace_fds() -> UResult<()> {
+        if std::io::stdin().is_terminal() {
+            let new_stdin = File::open(Path::new("/dev/null"))
+                .map_err(|e| NohupError::CannotReplace("STDIN", e))?;
+            dup2_stdin(&new_stdin)
+                .map_err(|e| NohupError::CannotReplace("STDIN", Error::from(e)))?;
+        }
+
+        if std::io::stdout().is_terminal() {
+            let new_stdout = find_stdout()?;
+
+            dup2_stdout(&new_stdout)
+                .map_err(|e| NohupError::CannotReplace("STDOUT", Error::from(e)))?;
+        }
+
+        if std::io::stderr().is_terminal() {
+            dup2_stderr(stdout())
+                .map_err(|e| NohupError::CannotReplace("STDERR", Error::from(e)))?;
+        }
+        Ok(())
+    }
+
+    #[cfg(target_vendor = "apple")]
+    unsafe extern "C" {
+        fn _vprocmgr_detach_from_console(flags: u32) -> *const libc::c_int;
+    }
+}
+
+/// Windows has no SIGHUP: closing a console terminates every process attached
+/// to it, and terminals may also kill the job object they put their processes
+/// in. So instead of exec'ing, nohup starts COMMAND without a console, in its
+/// own process group and outside of the current job when that is allowed, and
+/// then detaches itself from the console while waiting for its exit status.
+#[cfg(windows)]
+mod platform {
+    use super::{NohupError, find_stdout, report_failed_to_run};
+    use std::fs::File;
+    use std::io::IsTerminal;
+    use std::mem::zeroed;
+    use std::os::windows::io::AsHandle;
+    use std::os::windows::process::CommandExt;
+    use std::process;
+    use std::ptr;
+    use uucore::display::Quotable;
+    use uucore::error::{FromIo, UResult, set_exit_code};
+    use windows_sys::Win32::System::Console::{FreeConsole, SetConsoleCtrlHandler};
+    use windows_sys::Win32::System::JobObjects::{
+        IsProcessInJob, JOB_OBJECT_LIMIT_BREAKAWAY_OK, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JobObjectExtendedLimitInformation, QueryInformationJobObject,
+    };
+    use windows_sys::Win32::System::Threading::{
+        CREATE_BREAKAWAY_FROM_JOB, CREATE_NEW_PROCESS_GROUP, DETACHED_PROCESS, GetCurrentProcess,
+    };
+
+    pub fn run(cmd: &str, args: &[&String]) -> UResult<()> {
+        let mut command = process::Command::new(cmd);
+        command.args(args);
+
+        if std::io::stdin().is_terminal() {
+            let null = File::open("NUL").map_err(|e| NohupError::CannotReplace("STDIN", e))?;
+            command.stdin(null);
+        }
+
+        if std::io::stdout().is_terminal() {
+            let new_stdout = find_stdout()?;
+            if std::io::stderr().is_terminal() {
+                let new_stderr = new_stdout
+                    .try_clone()
+                    .map_err(|e| NohupError::CannotReplace("STDERR", e))?;
+                command.stderr(new_stderr);
+            }
+            command.stdout(new_stdout);
+        } else if std::io::stderr().is_terminal() {
+            let new_stderr = std::io::stdout()
+                .as_handle()
+                .try_clone_to_owned()
+                .map_err(|e| NohupError::CannotReplace("STDERR", e))?;
+            command.stderr(new_stderr);
+        }
+
+        // A detached process has no console, so it can't be killed by the
+        // console closing, and a new process group does not receive CTRL+C.
+        let mut flags = DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP;
+        if may_break_away_from_job() {
+            flags |= CREATE_BREAKAWAY_FROM_JOB;
+        }
+        command.creation_flags(flags);
+
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(err) => {
+                report_failed_to_run(cmd, &err);
+                return Ok(());
+            }
+        };
+
+        // SAFETY: passing no handler with TRUE makes this process ignore
+        // CTRL+C, and FreeConsole only detaches it from its console.
+        unsafe {
+            SetConsoleCtrlHandler(None, 1);
+            FreeConsole();
+        }
+
+        let status = child.wait().map_err_context(|| cmd.quote().to_string())?;
+        set_exit_code(uucore::spawn::exit_code(status));
+        Ok(())
+    }
+
+    /// Whether nohup runs in a job object that lets its children leave it.
+    ///
+    /// Creating a process with `CREATE_BREAKAWAY_FROM_JOB` fails if the job
+    /// does not allow it, so only ask for it when it is permitted.
+    fn may_break_away_from_job() -> bool {
+        let mut in_job = 0;
+        // SAFETY: `GetCurrentProcess` returns a pseudo handle that is always
+        // valid, and a null job handle checks for any job.
+        if unsafe { IsProcessInJob(GetCurrentProcess(), ptr::null_mut(), &raw mut in_job) } == 0
+            || in_job == 0
+        {
+            return false;
+        }
+
+        // SAFETY: the buffer is a zeroed JOBOBJECT_EXTENDED_LIMIT_INFORMATION
+        // and its size is passed along, and a null job handle queries the job
+        // of the current process.
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { zeroed() };
+        let ok = unsafe {
+            QueryInformationJobObject(
+                ptr::null_mut(),
+                JobObjectExtendedLimitInformation,
+                (&raw mut info).cast(),
+                size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                ptr::null_mut(),
+            )
+        };
+        ok != 0 && info.BasicLimitInformation.LimitFlags & JOB_OBJECT_LIMIT_BREAKAWAY_OK != 0
+    }
 }
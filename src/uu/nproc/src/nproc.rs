@@ -94,25 +94,106 @@ fn num_cpus_all() -> usize {
     // So fallback to available_parallelism at here is not useful
     #[cfg(unix)]
     return unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) } as usize;
-    // not sure what we can do for non-unix...
-    #[cfg(not(unix))]
+    #[cfg(windows)]
+    return windows::active_processor_count().unwrap_or_else(available_parallelism);
+    // not sure what we can do for other platforms...
+    #[cfg(not(any(unix, windows)))]
     available_parallelism()
 }
 
-// We cannot use std::thread::available_parallelism to mimic GNU's rounding...
+/// The CPU quota of the cgroups of this process, as a number of CPUs.
+///
+/// Any ancestor of a cgroup may be limited too, so this is the lowest quota
+/// found walking up the hierarchy: `cpu.max` for cgroup v2 like GNU, and
+/// `cpu.cfs_quota_us` for the `cpu` controller of cgroup v1. CPU sets need no
+/// handling here, as they already restrict the affinity mask.
 #[cfg(any(target_os = "linux", target_os = "android"))]
-fn cgroups2_quota() -> Option<usize> {
+fn cgroup_quota() -> Option<usize> {
     use std::fs::read_to_string;
+    use std::path::Path;
+
     let cgroups = read_to_string("/proc/self/cgroup").ok()?;
-    let path = cgroups.lines().next()?.split(':').nth(2)?;
-    let pair = read_to_string(format!("/sys/fs/cgroup{path}/cpu.max")).ok()?;
+    let mounts = read_to_string("/proc/self/mounts").unwrap_or_default();
+    // Find where a cgroup hierarchy is mounted, from its type and options.
+    let mount_point = |is_hierarchy: &dyn Fn(&str, &str) -> bool| {
+        mounts.lines().find_map(|line| {
+            let mut fields = line.split_whitespace().skip(1);
+            let (dir, fs_type, options) = (fields.next()?, fields.next()?, fields.next()?);
+            is_hierarchy(fs_type, options).then(|| Path::new(dir).to_path_buf())
+        })
+    };
+
+    let mut quota = None;
+    for line in cgroups.lines() {
+        // hierarchy-ID:controller-list:cgroup-path
+        let mut fields = line.splitn(3, ':');
+        let (Some(id), Some(controllers), Some(path)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let found = if id == "0" && controllers.is_empty() {
+            let default = Path::new("/sys/fs/cgroup");
+            let mount = if default.join("cgroup.controllers").exists() {
+                Some(default.to_path_buf())
+            } else {
+                mount_point(&|fs_type, _| fs_type == "cgroup2")
+            };
+            mount.and_then(|mount| lowest_quota(&mount, path, cgroup2_quota))
+        } else if controllers.split(',').any(|c| c == "cpu") {
+            mount_point(&|fs_type, options| {
+                fs_type == "cgroup" && options.split(',').any(|o| o == "cpu")
+            })
+            .and_then(|mount| lowest_quota(&mount, path, cgroup1_quota))
+        } else {
+            None
+        };
+        quota = quota.into_iter().chain(found).min();
+    }
+    quota
+}
+
+/// The lowest quota of the cgroup at `path` in the hierarchy mounted at
+/// `mount` and of its ancestors, as read by `read_quota`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn lowest_quota(
+    mount: &std::path::Path,
+    path: &str,
+    read_quota: fn(&std::path::Path) -> Option<usize>,
+) -> Option<usize> {
+    std::path::Path::new(path)
+        .ancestors()
+        .filter_map(|cgroup| read_quota(&mount.join(cgroup.strip_prefix("/").ok()?)))
+        .min()
+}
+
+/// The quota in `cpu.max`, which is "max" without any quota.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn cgroup2_quota(cgroup: &std::path::Path) -> Option<usize> {
+    let pair = std::fs::read_to_string(cgroup.join("cpu.max")).ok()?;
     let mut pair = pair.split_whitespace();
-    // map the string "max" to None as we unwrap_or(usize::MAX) later
     let quota = pair.next()?.parse::<usize>().ok()?;
+    let period = pair.next()?.parse::<usize>().ok()?;
+    quota_in_cpus(quota, period)
+}
+
+/// The quota in `cpu.cfs_quota_us`, which is -1 without any quota.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn cgroup1_quota(cgroup: &std::path::Path) -> Option<usize> {
+    let read = |name| std::fs::read_to_string(cgroup.join(name)).ok();
+    let quota = read("cpu.cfs_quota_us")?.trim().parse::<usize>().ok()?;
+    let period = read("cpu.cfs_period_us")?.trim().parse::<usize>().ok()?;
+    quota_in_cpus(quota, period)
+}
+
+// We cannot use std::thread::available_parallelism to mimic GNU's rounding...
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn quota_in_cpus(quota: usize, period: usize) -> Option<usize> {
     // kernel does not provide 0 period. But it seems GNU cares about it
-    let period = pair.next()?.parse::<usize>().ok().filter(|&p| p > 0)?;
-    // mimic GNU's rounding
-    Some(quota.saturating_add(period / 2) / period)
+    (period > 0).then(|| {
+        // mimic GNU's rounding
+        quota.saturating_add(period / 2) / period
+    })
 }
 
 fn available_parallelism() -> usize {
@@ -127,8 +208,84 @@ fn available_parallelism() -> usize {
     match unsafe { libc::sched_getscheduler(0) } {
         libc::SCHED_FIFO | libc::SCHED_RR | libc::SCHED_DEADLINE => affinity,
         // GNU has no quota if /proc is masked
-        _ => affinity.min(cgroups2_quota().unwrap_or(usize::MAX)),
+        _ => affinity.min(cgroup_quota().unwrap_or(usize::MAX)),
+    }
+    #[cfg(windows)]
+    if let Some(count) = windows::affinity_count().or_else(windows::active_processor_count) {
+        return count;
     }
     #[cfg(not(any(target_os = "linux", target_os = "android")))]
     std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
 }
+
+/// Windows splits machines with more than 64 logical processors into
+/// processor groups, and the usual APIs (and the standard library) only count
+/// the processors of the group of the current process.
+#[cfg(windows)]
+mod windows {
+    use std::ptr;
+    use windows_sys::Win32::System::SystemInformation::{
+        GetLogicalProcessorInformationEx, PROCESSOR_GROUP_INFO, RelationGroup,
+        SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+    };
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, GetProcessAffinityMask};
+
+    /// The number of active logical processors in all processor groups.
+    pub fn active_processor_count() -> Option<usize> {
+        let mut length = 0;
+        // SAFETY: a null buffer only queries the length that is needed.
+        unsafe {
+            GetLogicalProcessorInformationEx(RelationGroup, ptr::null_mut(), &raw mut length)
+        };
+        if length == 0 {
+            return None;
+        }
+        // Use u64 elements to get a suitably aligned buffer.
+        let mut buffer = vec![0_u64; (length as usize).div_ceil(size_of::<u64>())];
+        let info = buffer
+            .as_mut_ptr()
+            .cast::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>();
+        // SAFETY: the buffer is aligned and at least `length` bytes long.
+        if unsafe { GetLogicalProcessorInformationEx(RelationGroup, info, &raw mut length) } == 0 {
+            return None;
+        }
+
+        // SAFETY: with RelationGroup, the buffer holds a single entry whose
+        // GroupInfo array has an element for each active group. The pointers
+        // are derived from the buffer, so they may go past the one element
+        // that array is declared with.
+        let groups = unsafe {
+            let group = &raw const (*info).Anonymous.Group;
+            std::slice::from_raw_parts(
+                (&raw const (*group).GroupInfo).cast::<PROCESSOR_GROUP_INFO>(),
+                usize::from((*group).ActiveGroupCount),
+            )
+        };
+        Some(
+            groups
+                .iter()
+                .map(|group| usize::from(group.ActiveProcessorCount))
+                .sum(),
+        )
+    }
+
+    /// The number of processors the process is restricted to, if its
+    /// affinity was set to a subset of the processors of its group.
+    ///
+    /// Processes whose threads run in several groups have no affinity mask.
+    pub fn affinity_count() -> Option<usize> {
+        let mut process_mask = 0;
+        let mut system_mask = 0;
+        // SAFETY: `GetCurrentProcess` returns a pseudo handle that is always
+        // valid, and the masks are valid for writes.
+        let ok = unsafe {
+            GetProcessAffinityMask(
+                GetCurrentProcess(),
+                &raw mut process_mask,
+                &raw mut system_mask,
+            )
+        };
+        (ok != 0 && process_mask != 0 && process_mask != system_mask)
+            .then(|| process_mask.count_ones() as usize)
+    }
+}
@@ -6,7 +6,7 @@
 // spell-checker:ignore powf seps replacen
 
 use uucore::display::Quotable;
-use uucore::i18n::decimal::{locale_decimal_separator, locale_grouping_separator};
+use uucore::i18n::decimal::{apply_grouping, locale_decimal_separator};
 use uucore::translate;
 
 use crate::numeric::ParsedNumber;
@@ -242,54 +242,6 @@ fn parse_suffix(
     Ok((number, suffix))
 }
 
-fn apply_grouping(s: &str) -> String {
-    let grouping_separator = locale_grouping_separator();
-    if grouping_separator.is_empty() {
-        return s.to_string();
-    }
-
-    let (sign, rest) = if let Some(rest) = s.strip_prefix('-') {
-        ("-", rest)
-    } else {
-        ("", s)
-    };
-    let dec_sep = locale_decimal_separator();
-    let (integer, fraction) = rest.split_once(dec_sep).map_or((rest, ""), |(i, f)| (i, f));
-    if integer.len() < 4 {
-        return s.to_string();
-    }
-
-    let sep_len = grouping_separator.len();
-    let num_seps = (integer.len() - 1) / 3;
-    let mut grouped = String::with_capacity(
-        sign.len()
-            + integer.len()
-            + num_seps * sep_len
-            + if fraction.is_empty() {
-                0
-            } else {
-                1 + fraction.len()
-            },
-    );
-    grouped.push_str(sign);
-
-    let first_group = integer.len() % 3;
-    let first_group = if first_group == 0 { 3 } else { first_group };
-    grouped.push_str(&integer[..first_group]);
-    for chunk in integer.as_bytes()[first_group..].chunks(3) {
-        grouped.push_str(grouping_separator);
-        // SAFETY: integer is known to be valid UTF-8 ASCII digits
-        grouped.push_str(std::str::from_utf8(chunk).unwrap());
-    }
-
-    if !fraction.is_empty() {
-        grouped.push_str(dec_sep);
-        grouped.push_str(fraction);
-    }
-
-    grouped
-}
-
 fn split_next_field(s: &str) -> (&str, &str, &str) {
     let prefix_len = s.find(|c: char| !c.is_whitespace()).unwrap_or(s.len());
     let field_end = s[prefix_len..]
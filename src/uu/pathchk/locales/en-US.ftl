@@ -5,14 +5,15 @@ pathchk-usage = pathchk [OPTION]... NAME...
 pathchk-help-posix = check for most POSIX systems
 pathchk-help-posix-special = check for empty names and leading "-"
 pathchk-help-portability = check for all POSIX systems (equivalent to -p -P)
+pathchk-help-windows = also check for names that are invalid on Windows
 
 # Error messages
 pathchk-error-missing-operand = missing operand
 pathchk-error-empty-file-name = empty file name
-pathchk-error-posix-path-length-exceeded = limit { $limit } exceeded by length { $length } of file name { $path }
-pathchk-error-posix-name-length-exceeded = limit { $limit } exceeded by length { $length } of file name component { $component }
-pathchk-error-leading-hyphen = leading hyphen in file name component { $component }
+pathchk-error-leading-hyphen = leading '-' in a component of file name { $path }
 pathchk-error-path-length-exceeded = limit { $limit } exceeded by length { $length } of file name { $path }
 pathchk-error-name-length-exceeded = limit { $limit } exceeded by length { $length } of file name component { $component }
-pathchk-error-empty-path-not-found = pathchk: '': No such file or directory
-pathchk-error-nonportable-character = nonportable character '{ $character }' in file name component { $component }
+pathchk-error-nonportable-character = nonportable character { $character } in file name { $path }
+pathchk-error-windows-invalid-character = character { $character } is not allowed on Windows in file name { $path }
+pathchk-error-windows-trailing-dot-or-space = trailing dot or space in component { $component } of file name { $path } is not allowed on Windows
+pathchk-error-windows-reserved-name = component { $component } of file name { $path } is a reserved device name on Windows
@@ -5,14 +5,15 @@ pathchk-usage = pathchk [OPTION]... NOM...
 pathchk-help-posix = vérifier pour la plupart des systèmes POSIX
 pathchk-help-posix-special = vérifier les noms vides et les "-" en début
 pathchk-help-portability = vérifier pour tous les systèmes POSIX (équivalent à -p -P)
+pathchk-help-windows = vérifier aussi les noms invalides sous Windows
 
 # Messages d'erreur
 pathchk-error-missing-operand = opérande manquant
 pathchk-error-empty-file-name = nom de fichier vide
-pathchk-error-posix-path-length-exceeded = limite { $limit } dépassée par la longueur { $length } du nom de fichier { $path }
-pathchk-error-posix-name-length-exceeded = limite { $limit } dépassée par la longueur { $length } du composant de nom de fichier { $component }
-pathchk-error-leading-hyphen = tiret en début dans le composant de nom de fichier { $component }
+pathchk-error-leading-hyphen = '-' en début d'un composant du nom de fichier { $path }
 pathchk-error-path-length-exceeded = limite { $limit } dépassée par la longueur { $length } du nom de fichier { $path }
 pathchk-error-name-length-exceeded = limite { $limit } dépassée par la longueur { $length } du composant de nom de fichier { $component }
-pathchk-error-empty-path-not-found = pathchk: '' : Aucun fichier ou répertoire de ce type
-pathchk-error-nonportable-character = caractère non portable '{ $character }' dans le composant de nom de fichier { $component }
+pathchk-error-nonportable-character = caractère non portable { $character } dans le nom de fichier { $path }
+pathchk-error-windows-invalid-character = le caractère { $character } n'est pas autorisé sous Windows dans le nom de fichier { $path }
+pathchk-error-windows-trailing-dot-or-space = le point ou l'espace final du composant { $component } du nom de fichier { $path } n'est pas autorisé sous Windows
+pathchk-error-windows-reserved-name = le composant { $component } du nom de fichier { $path } est un nom de périphérique réservé sous Windows
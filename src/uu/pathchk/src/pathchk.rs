@@ -2,30 +2,22 @@
 //
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
-#![allow(unused_must_use)] // because we of writeln!
 
-// spell-checker:ignore (ToDO) lstat
+// spell-checker:ignore (ToDO) lstat pathconf
 use clap::{Arg, ArgAction, Command};
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fs;
-use std::io::{ErrorKind, Write};
+use std::io::ErrorKind;
 use uucore::display::Quotable;
-use uucore::error::{UResult, UUsageError, set_exit_code};
-use uucore::format_usage;
+use uucore::error::{UResult, UUsageError, set_exit_code, strip_errno};
 use uucore::translate;
-
-// operating mode
-enum Mode {
-    Default, // use filesystem to determine information and limits
-    Basic,   // check basic compatibility with POSIX
-    Extra,   // check for leading dashes and empty names
-    Both,    // a combination of `Basic` and `Extra`
-}
+use uucore::{format_usage, show_error};
 
 mod options {
     pub const POSIX: &str = "posix";
     pub const POSIX_SPECIAL: &str = "posix-special";
     pub const PORTABILITY: &str = "portability";
+    pub const WINDOWS: &str = "windows";
     pub const PATH: &str = "path";
 }
 
@@ -35,56 +27,53 @@ const POSIX_NAME_MAX: usize = 14;
 
 #[cfg(all(unix, not(target_os = "redox")))]
 const PATH_MAX: usize = libc::PATH_MAX as usize;
-#[cfg(all(unix, not(target_os = "redox")))]
-const FILENAME_MAX: usize = libc::FILENAME_MAX as usize;
 #[cfg(target_os = "redox")]
 const PATH_MAX: usize = 4096;
-#[cfg(target_os = "redox")]
-const FILENAME_MAX: usize = 255;
 // for Windows. But don't deny wasm
 #[cfg(not(unix))]
 const PATH_MAX: usize = 260;
-#[cfg(not(unix))]
-const FILENAME_MAX: usize = 255;
+/// Component length limit used when the file system cannot be asked.
+const NAME_MAX: usize = 255;
+
+/// Device names that Windows reserves in every directory, with any extension.
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+const WINDOWS_INVALID_CHARS: &[u8] = b"<>:\"\\|?*";
+
+/// Which checks to run in addition to the file system ones.
+#[derive(Clone, Copy)]
+struct Checks {
+    /// `-p`: POSIX limits and the portable character set, without the file system.
+    basic: bool,
+    /// `-P`: empty names and leading hyphens.
+    extra: bool,
+    /// `--windows`: names Windows cannot represent.
+    windows: bool,
+}
 
 #[uucore::main(no_signals)]
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let matches = uucore::clap_localization::handle_clap_result(uu_app(), args)?;
 
-    // set working mode
-    let is_posix = matches.get_flag(options::POSIX);
-    let is_posix_special = matches.get_flag(options::POSIX_SPECIAL);
     let is_portability = matches.get_flag(options::PORTABILITY);
-
-    let mode = if (is_posix && is_posix_special) || is_portability {
-        Mode::Both
-    } else if is_posix {
-        Mode::Basic
-    } else if is_posix_special {
-        Mode::Extra
-    } else {
-        Mode::Default
+    let checks = Checks {
+        basic: matches.get_flag(options::POSIX) || is_portability,
+        extra: matches.get_flag(options::POSIX_SPECIAL) || is_portability,
+        windows: matches.get_flag(options::WINDOWS),
     };
 
-    // take necessary actions
-    let paths = matches.get_many::<OsString>(options::PATH);
-    if paths.is_none() {
+    let Some(paths) = matches.get_many::<OsString>(options::PATH) else {
         return Err(UUsageError::new(
             1,
             translate!("pathchk-error-missing-operand"),
         ));
-    }
+    };
 
-    // free strings are path operands
-    // FIXME: TCS, seems inefficient and overly verbose (?)
     let mut res = true;
-    for p in paths.unwrap() {
-        let path_str = p.to_string_lossy();
-        let mut path = Vec::new();
-        for path_segment in path_str.split('/') {
-            path.push(path_segment.to_string());
-        }
-        res &= check_path(&mode, &path);
+    for p in paths {
+        res &= validate_file_name(p, checks);
     }
 
     // determine error code
@@ -119,6 +108,12 @@ pub fn uu_app() -> Command {
                 .help(translate!("pathchk-help-portability"))
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::WINDOWS)
+                .long(options::WINDOWS)
+                .help(translate!("pathchk-help-windows"))
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(options::PATH)
                 .hide(true)
@@ -128,150 +123,191 @@ pub fn uu_app() -> Command {
         )
 }
 
-/// check a path, given as a slice of it's components and an operating mode
-fn check_path(mode: &Mode, path: &[String]) -> bool {
-    match *mode {
-        Mode::Basic => check_basic(path),
-        Mode::Extra => check_default(path) && check_extra(path),
-        Mode::Both => check_basic(path) && check_extra(path),
-        Mode::Default => check_default(path),
+/// Iterate over the non-empty, slash-separated components of `file`, along
+/// with the offset at which each starts.
+fn components(file: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    file.split(|&b| b == b'/')
+        .scan(0, |offset, component| {
+            let start = *offset;
+            *offset += component.len() + 1;
+            Some((start, component))
+        })
+        .filter(|(_, component)| !component.is_empty())
+}
+
+/// Quote raw file name bytes for an error message.
+fn quoted(bytes: &[u8]) -> String {
+    match uucore::os_str_from_bytes(bytes) {
+        Ok(s) => s.quote().to_string(),
+        Err(_) => String::from_utf8_lossy(bytes).quote().to_string(),
     }
 }
 
-/// check a path in basic compatibility mode
-fn check_basic(path: &[String]) -> bool {
-    let joined_path = path.join("/");
-    let total_len = joined_path.len();
-    // path length
-    if total_len > POSIX_PATH_MAX {
-        writeln!(
-            std::io::stderr(),
+/// Check `file` and print a diagnostic for the first problem found, the way
+/// GNU pathchk does.
+fn validate_file_name(file: &OsStr, checks: Checks) -> bool {
+    let bytes = uucore::os_str_as_bytes_lossy(file);
+    let bytes = bytes.as_ref();
+    let len = bytes.len();
+    let mut file_exists = false;
+
+    if checks.extra && components(bytes).any(|(_, c)| c.starts_with(b"-")) {
+        show_error!(
             "{}",
-            translate!("pathchk-error-posix-path-length-exceeded", "limit" => POSIX_PATH_MAX, "length" => total_len, "path" => joined_path)
+            translate!("pathchk-error-leading-hyphen", "path" => file.quote())
         );
         return false;
-    } else if total_len == 0 {
-        writeln!(
-            std::io::stderr(),
-            "{}",
-            translate!("pathchk-error-empty-file-name")
-        );
+    }
+
+    if (checks.basic || checks.extra) && len == 0 {
+        show_error!("{}", translate!("pathchk-error-empty-file-name"));
         return false;
     }
-    // components: character portability and length
-    for p in path {
-        let component_len = p.len();
-        if component_len > POSIX_NAME_MAX {
-            writeln!(
-                std::io::stderr(),
-                "{}",
-                translate!("pathchk-error-posix-name-length-exceeded", "limit" => POSIX_NAME_MAX, "length" => component_len, "component" => p.quote())
-            );
+
+    if checks.basic {
+        if !check_portable_chars(file, bytes) {
             return false;
         }
-        if !check_portable_chars(p) {
-            return false;
+    } else {
+        // Check whether a file name component is in a directory that is not
+        // searchable, or has some other serious problem. We use lstat, just
+        // like the original implementation.
+        match fs::symlink_metadata(file) {
+            Ok(_) => file_exists = true,
+            Err(e) if e.kind() == ErrorKind::NotFound && len != 0 => {}
+            Err(e) => {
+                show_error!("{}: {}", file.maybe_quote(), strip_errno(&e));
+                return false;
+            }
         }
     }
-    // permission checks
-    check_searchable(&joined_path)
-}
 
-/// check a path in extra compatibility mode
-fn check_extra(path: &[String]) -> bool {
-    // components: leading hyphens
-    for p in path {
-        if p.starts_with('-') {
-            writeln!(
-                std::io::stderr(),
+    // Lengths below the POSIX minimums are valid everywhere, so the (possibly
+    // expensive) system limits are only queried beyond them.
+    if checks.basic || (!file_exists && POSIX_PATH_MAX <= len) {
+        let max_size = if checks.basic {
+            POSIX_PATH_MAX
+        } else {
+            PATH_MAX
+        };
+        if max_size <= len {
+            show_error!(
                 "{}",
-                translate!("pathchk-error-leading-hyphen", "component" => p.quote())
+                translate!("pathchk-error-path-length-exceeded", "limit" => max_size - 1, "length" => len, "path" => file.quote())
             );
             return false;
         }
     }
-    // path length
-    if path.join("/").is_empty() {
-        writeln!(
-            std::io::stderr(),
-            "{}",
-            translate!("pathchk-error-empty-file-name")
-        );
-        return false;
+
+    let check_component_lengths =
+        checks.basic || (!file_exists && components(bytes).any(|(_, c)| POSIX_NAME_MAX < c.len()));
+    if check_component_lengths {
+        for (start, component) in components(bytes) {
+            let name_max = if checks.basic {
+                POSIX_NAME_MAX
+            } else {
+                name_max(&bytes[..start])
+            };
+            if name_max < component.len() {
+                show_error!(
+                    "{}",
+                    translate!("pathchk-error-name-length-exceeded", "limit" => name_max, "length" => component.len(), "component" => quoted(component))
+                );
+                return false;
+            }
+        }
     }
-    true
+
+    !checks.windows || check_windows_name(file, bytes)
 }
 
-/// check a path in default mode (using the file system)
-fn check_default(path: &[String]) -> bool {
-    let joined_path = path.join("/");
-    let total_len = joined_path.len();
-    // path length
-    if total_len > PATH_MAX {
-        writeln!(
-            std::io::stderr(),
-            "{}",
-            translate!("pathchk-error-path-length-exceeded", "limit" => PATH_MAX, "length" => total_len, "path" => joined_path.quote())
-        );
-        return false;
+/// The longest file name component allowed in `dir`.
+#[cfg(unix)]
+fn name_max(dir: &[u8]) -> usize {
+    let dir = if dir.is_empty() { b"." } else { dir };
+    let Ok(dir) = std::ffi::CString::new(dir) else {
+        return NAME_MAX;
+    };
+    // SAFETY: `dir` is a valid NUL-terminated string.
+    let limit = unsafe { libc::pathconf(dir.as_ptr(), libc::_PC_NAME_MAX) };
+    // pathconf fails for directories that do not exist yet; fall back to the
+    // usual limit rather than accepting any length.
+    if limit < 0 {
+        NAME_MAX
+    } else {
+        usize::try_from(limit).unwrap_or(usize::MAX)
+    }
+}
+
+#[cfg(not(unix))]
+fn name_max(_dir: &[u8]) -> usize {
+    NAME_MAX
+}
+
+/// Check that `bytes` only contains characters from the POSIX portable file
+/// name character set (plus `/`).
+fn check_portable_chars(file: &OsStr, bytes: &[u8]) -> bool {
+    const VALID_CHARS: &[u8] =
+        b"/ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789._-";
+    let Some(i) = bytes.iter().position(|b| !VALID_CHARS.contains(b)) else {
+        return true;
+    };
+    show_error!(
+        "{}",
+        translate!("pathchk-error-nonportable-character", "character" => quote_char(&bytes[i..]), "path" => file.quote())
+    );
+    false
+}
+
+/// Quote the character starting `bytes`: as is when printable, as an octal
+/// escape of its first byte otherwise.
+fn quote_char(bytes: &[u8]) -> String {
+    let width = match bytes[0] {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    };
+    match std::str::from_utf8(&bytes[..width.min(bytes.len())]) {
+        Ok(c) if !c.chars().any(char::is_control) => format!("'{c}'"),
+        _ => format!("'\\{:03o}'", bytes[0]),
     }
-    if total_len == 0 {
-        // Check whether a file name component is in a directory that is not searchable,
-        // or has some other serious problem. POSIX does not allow "" as a file name,
-        // but some non-POSIX hosts do (as an alias for "."),
-        // so allow "" if `symlink_metadata` (corresponds to `lstat`) does.
-        if fs::symlink_metadata(&joined_path).is_err() {
-            writeln!(
-                std::io::stderr(),
+}
+
+/// Check that every component of `bytes` can be created on Windows: no
+/// reserved device names, no trailing dots or spaces, and no characters
+/// Windows forbids.
+fn check_windows_name(file: &OsStr, bytes: &[u8]) -> bool {
+    for (_, component) in components(bytes) {
+        if component == b"." || component == b".." {
+            continue;
+        }
+        if let Some(&c) = component
+            .iter()
+            .find(|&&b| b < 0x20 || WINDOWS_INVALID_CHARS.contains(&b))
+        {
+            show_error!(
                 "{}",
-                translate!("pathchk-error-empty-path-not-found")
+                translate!("pathchk-error-windows-invalid-character", "character" => quote_char(&[c]), "path" => file.quote())
             );
             return false;
         }
-    }
-
-    // components: length
-    for p in path {
-        let component_len = p.len();
-        if component_len > FILENAME_MAX {
-            writeln!(
-                std::io::stderr(),
+        if component.ends_with(b".") || component.ends_with(b" ") {
+            show_error!(
                 "{}",
-                translate!("pathchk-error-name-length-exceeded", "limit" => FILENAME_MAX, "length" => component_len, "component" => p.quote())
+                translate!("pathchk-error-windows-trailing-dot-or-space", "component" => quoted(component), "path" => file.quote())
             );
             return false;
         }
-    }
-    // permission checks
-    check_searchable(&joined_path)
-}
-
-/// check whether a path is or if other problems arise
-fn check_searchable(path: &str) -> bool {
-    // we use lstat, just like the original implementation
-    match fs::symlink_metadata(path) {
-        Ok(_) => true,
-        Err(e) => {
-            if e.kind() == ErrorKind::NotFound {
-                true
-            } else {
-                writeln!(std::io::stderr(), "{e}");
-                false
-            }
-        }
-    }
-}
-
-/// check whether a path segment contains only valid (read: portable) characters
-fn check_portable_chars(path_segment: &str) -> bool {
-    const VALID_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789._-";
-    for (i, ch) in path_segment.as_bytes().iter().enumerate() {
-        if !VALID_CHARS.contains(ch) {
-            let invalid = path_segment[i..].chars().next().unwrap();
-            writeln!(
-                std::io::stderr(),
+        let stem = component.split(|&b| b == b'.').next().unwrap_or_default();
+        let stem = stem.trim_ascii_end();
+        if WINDOWS_RESERVED_NAMES
+            .iter()
+            .any(|name| stem.eq_ignore_ascii_case(name.as_bytes()))
+        {
+            show_error!(
                 "{}",
-                translate!("pathchk-error-nonportable-character", "character" => invalid, "component" => path_segment.quote())
+                translate!("pathchk-error-windows-reserved-name", "component" => quoted(component), "path" => file.quote())
             );
             return false;
         }
@@ -541,7 +541,7 @@ fn get_date_format(matches: &ArgMatches) -> String {
         Some(format) => format,
         None => {
             // Replicate behavior from GNU manual.
-            if std::env::var("POSIXLY_CORRECT").is_ok()
+            if uucore::posix::posixly_correct()
                 // TODO: This needs to be moved to uucore and handled by icu?
                 && (std::env::var_os("LC_TIME").as_deref() == Some(OsStr::new("POSIX"))
                     || std::env::var_os("LC_ALL").as_deref() == Some(OsStr::new("POSIX")))
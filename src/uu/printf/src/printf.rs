@@ -8,7 +8,9 @@ use std::io::stdout;
 use std::ops::ControlFlow;
 use uucore::display::Quotable;
 use uucore::error::{UResult, UUsageError};
-use uucore::format::{FormatArgument, FormatArguments, FormatItem, parse_spec_and_escape};
+use uucore::format::{
+    FormatArgument, FormatArguments, FormatError, FormatItem, parse_spec_and_escape,
+};
 use uucore::translate;
 use uucore::{format_usage, os_str_as_bytes, show_warning};
 
@@ -43,7 +45,10 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         if let Ok(FormatItem::Spec(_)) = item {
             format_seen = true;
         }
-        match item?.write(stdout(), &mut args)? {
+        match item
+            .map_err(FormatError::with_spec_context)?
+            .write(stdout(), &mut args)?
+        {
             ControlFlow::Continue(()) => {}
             ControlFlow::Break(()) => return Ok(()),
         }
@@ -70,7 +75,10 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
     while !args.is_exhausted() {
         for item in parse_spec_and_escape(format) {
-            match item?.write(stdout(), &mut args)? {
+            match item
+                .map_err(FormatError::with_spec_context)?
+                .write(stdout(), &mut args)?
+            {
                 ControlFlow::Continue(()) => {}
                 ControlFlow::Break(()) => return Ok(()),
             }
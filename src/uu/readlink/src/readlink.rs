@@ -6,16 +6,16 @@
 // spell-checker:ignore (ToDO) errno
 
 use clap::{Arg, ArgAction, Command};
-use std::env;
 use std::ffi::OsString;
 use std::fs;
 use std::io::{Write, stdout};
 use std::path::{Path, PathBuf};
 use uucore::display::Quotable;
-use uucore::error::{FromIo, UResult, UUsageError};
+use uucore::error::{FromIo, UResult, UUsageError, set_exit_code};
 use uucore::fs::{MissingHandling, ResolveMode, canonicalize};
 use uucore::libc::EINVAL;
 use uucore::line_ending::LineEnding;
+use uucore::posix::posixly_correct;
 use uucore::translate;
 use uucore::{format_usage, show_error};
 
@@ -30,13 +30,20 @@ const OPT_ZERO: &str = "zero";
 
 const ARG_FILES: &str = "files";
 
+/// `-f`, `-e` and `-m` override each other; the last one given wins.
+const CANONICALIZE_MODES: [&str; 3] = [
+    OPT_CANONICALIZE,
+    OPT_CANONICALIZE_EXISTING,
+    OPT_CANONICALIZE_MISSING,
+];
+
 #[uucore::main(no_signals)]
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let matches = uucore::clap_localization::handle_clap_result(uu_app(), args)?;
 
     let mut no_trailing_delimiter = matches.get_flag(OPT_NO_NEWLINE);
     let use_zero = matches.get_flag(OPT_ZERO);
-    let verbose = matches.get_flag(OPT_VERBOSE) || env::var("POSIXLY_CORRECT").is_ok();
+    let verbose = matches.get_flag(OPT_VERBOSE) || posixly_correct();
 
     // GNU readlink -f/-e/-m follows symlinks first and then applies `..` (physical resolution).
     // ResolveMode::Logical collapses `..` before following links, which yields the opposite order,
@@ -81,6 +88,8 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         Some(LineEnding::from_zero_flag(use_zero))
     };
 
+    // Like GNU, a failing operand does not stop the others from being
+    // printed; it only makes the final exit status 1.
     for p in &files {
         let path_result = if res_mode == ResolveMode::None {
             fs::read_link(p)
@@ -93,8 +102,9 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                 show(&path, line_ending).map_err_context(String::new)?;
             }
             Err(err) => {
+                set_exit_code(1);
                 if !verbose {
-                    return Err(1.into());
+                    continue;
                 }
 
                 let message = if err.raw_os_error() == Some(EINVAL) {
@@ -104,7 +114,6 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                         .to_string()
                 };
                 show_error!("{message}");
-                return Err(1.into());
             }
         }
     }
@@ -123,6 +132,7 @@ pub fn uu_app() -> Command {
                 .short('f')
                 .long(OPT_CANONICALIZE)
                 .help(translate!("readlink-help-canonicalize"))
+                .overrides_with_all(CANONICALIZE_MODES)
                 .action(ArgAction::SetTrue),
         )
         .arg(
@@ -130,6 +140,7 @@ pub fn uu_app() -> Command {
                 .short('e')
                 .long("canonicalize-existing")
                 .help(translate!("readlink-help-canonicalize-existing"))
+                .overrides_with_all(CANONICALIZE_MODES)
                 .action(ArgAction::SetTrue),
         )
         .arg(
@@ -137,6 +148,7 @@ pub fn uu_app() -> Command {
                 .short('m')
                 .long(OPT_CANONICALIZE_MISSING)
                 .help(translate!("readlink-help-canonicalize-missing"))
+                .overrides_with_all(CANONICALIZE_MODES)
                 .action(ArgAction::SetTrue),
         )
         .arg(
@@ -12,7 +12,7 @@ use clap::{
 use std::{
     ffi::{OsStr, OsString},
     io::{Write, stdout},
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
 };
 use uucore::fs::make_path_relative_to;
 use uucore::translate;
@@ -266,13 +266,67 @@ fn canonicalize_relative(
     can_mode: MissingHandling,
     resolve: ResolveMode,
 ) -> std::io::Result<PathBuf> {
-    let abs = canonicalize(r, can_mode, resolve)?;
+    let abs = canonicalize_path(r, can_mode, resolve)?;
     if can_mode == MissingHandling::Existing && !abs.is_dir() {
         abs.read_dir()?; // raise not a directory error
     }
     Ok(abs)
 }
 
+/// Canonicalize `p` like [`canonicalize`], additionally applying the
+/// existence requirements of `-e` and `-E` to the components that `-L`
+/// removes textually.
+///
+/// With `-L`, `dangling/..` collapses to `.` before any symlink is followed,
+/// but like GNU, every component written before a `..` must still exist
+/// unless `-m` is given.
+fn canonicalize_path(
+    p: &Path,
+    can_mode: MissingHandling,
+    resolve: ResolveMode,
+) -> std::io::Result<PathBuf> {
+    if resolve == ResolveMode::Logical && can_mode != MissingHandling::Missing {
+        check_logical_components(p, can_mode)?;
+    }
+    canonicalize(p, can_mode, resolve)
+}
+
+/// Check that each prefix of `p`, as it is built up component by component
+/// with `..` removing the previous one, exists.
+///
+/// With [`MissingHandling::Normal`] the last component may be missing.
+fn check_logical_components(p: &Path, can_mode: MissingHandling) -> std::io::Result<()> {
+    let components: Vec<Component> = p.components().collect();
+    let mut prefix = PathBuf::new();
+    for (i, component) in components.iter().enumerate() {
+        match component {
+            Component::CurDir => continue,
+            Component::ParentDir
+                if matches!(prefix.components().next_back(), Some(Component::Normal(_))) =>
+            {
+                prefix.pop();
+                continue;
+            }
+            Component::ParentDir
+            | Component::Prefix(_)
+            | Component::RootDir
+            | Component::Normal(_) => {
+                prefix.push(component);
+            }
+        }
+        if let Err(e) = prefix.metadata() {
+            let is_last = i + 1 == components.len();
+            if can_mode == MissingHandling::Existing
+                || !is_last
+                || e.kind() != std::io::ErrorKind::NotFound
+            {
+                return Err(e);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Resolve a path to an absolute form and print it.
 ///
 /// If `relative_to` and/or `relative_base` is given
@@ -294,7 +348,7 @@ fn resolve_path(
     relative_to: Option<&Path>,
     relative_base: Option<&Path>,
 ) -> std::io::Result<()> {
-    let abs = canonicalize(p, can_mode, resolve)?;
+    let abs = canonicalize_path(p, can_mode, resolve)?;
     if can_mode == MissingHandling::Normal {
         let path_bytes = p.as_os_str().as_encoded_bytes();
         if path_bytes.ends_with(b"/.") || path_bytes.ends_with(b"/./") {
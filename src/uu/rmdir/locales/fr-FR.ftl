@@ -9,6 +9,7 @@ rmdir-help-verbose = afficher un diagnostic pour chaque répertoire traité
 # Messages d'erreur
 rmdir-error-symbolic-link-not-followed = échec de la suppression de { $path } : Lien symbolique non suivi
 rmdir-error-failed-to-remove = échec de la suppression de { $path } : { $err }
+rmdir-error-failed-to-remove-directory = échec de la suppression du répertoire { $path } : { $err }
 
 # Sortie détaillée
 rmdir-verbose-removing-directory = { $util_name } : suppression du répertoire, { $path }
@@ -39,7 +39,11 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         .map(Path::new)
     {
         if let Err(error) = remove(path, opts) {
-            let Error { error, path } = error;
+            let RemoveError {
+                error,
+                path,
+                is_parent,
+            } = error;
 
             if opts.ignore && dir_not_empty(&error, path) {
                 continue;
@@ -47,6 +51,16 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
             set_exit_code(1);
 
+            // A parent removed by -p is known to be a directory, so like GNU
+            // say so unless the error claims otherwise.
+            if is_parent && error.kind() != io::ErrorKind::NotADirectory {
+                show_error!(
+                    "{}",
+                    translate!("rmdir-error-failed-to-remove-directory", "path" => path.quote(), "err" => strip_errno(&error))
+                );
+                continue;
+            }
+
             // If `foo` is a symlink to a directory then `rmdir foo/` may give
             // a "not a directory" error. This is confusing as `rm foo/` says
             // "is a directory".
@@ -58,7 +72,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             // - It's a symlink
             // - It either points to a directory or dangles
             #[cfg(unix)]
-            {
+            if !is_parent {
                 use std::ffi::OsStr;
                 use std::os::unix::ffi::OsStrExt;
 
@@ -91,33 +105,44 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     Ok(())
 }
 
-struct Error<'a> {
+struct RemoveError<'a> {
     error: io::Error,
     path: &'a Path,
+    /// Whether `path` is an ancestor being removed because of `-p`.
+    is_parent: bool,
 }
 
-fn remove(mut path: &Path, opts: Opts) -> Result<(), Error<'_>> {
-    remove_single(path, opts)?;
+fn remove(mut path: &Path, opts: Opts) -> Result<(), RemoveError<'_>> {
+    remove_single(path, opts).map_err(|error| RemoveError {
+        error,
+        path,
+        is_parent: false,
+    })?;
     if opts.parents {
+        // Stop at the first ancestor that cannot be removed.
         while let Some(new) = path.parent() {
             path = new;
             if path.as_os_str().is_empty() {
                 break;
             }
-            remove_single(path, opts)?;
+            remove_single(path, opts).map_err(|error| RemoveError {
+                error,
+                path,
+                is_parent: true,
+            })?;
         }
     }
     Ok(())
 }
 
-fn remove_single(path: &Path, opts: Opts) -> Result<(), Error<'_>> {
+fn remove_single(path: &Path, opts: Opts) -> io::Result<()> {
     if opts.verbose {
         println!(
             "{}",
             translate!("rmdir-verbose-removing-directory", "util_name" => "rmdir", "path" => path.quote())
         );
     }
-    remove_dir(path).map_err(|error| Error { error, path })
+    remove_dir(path)
 }
 
 #[cfg(unix)]
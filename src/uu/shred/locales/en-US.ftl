@@ -73,4 +73,5 @@ shred-failed-to-clone-file-handle = failed to clone file handle
 shred-failed-to-seek-file = failed to seek in file
 shred-failed-to-read-seed-bytes = failed to read seed bytes from file
 shred-failed-to-get-metadata = failed to get file metadata
+shred-failed-to-get-device-size = {$file}: failed to get device size
 shred-failed-to-set-permissions = failed to set file permissions
@@ -3,7 +3,7 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
-// spell-checker:ignore (words) wipesync prefill couldnt fillpattern
+// spell-checker:ignore (words) wipesync prefill couldnt fillpattern BLKGETSIZE DKIOCGETBLOCKCOUNT DKIOCGETBLOCKSIZE
 
 use clap::{Arg, ArgAction, Command};
 #[cfg(unix)]
@@ -14,6 +14,8 @@ use std::ffi::OsString;
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Seek, SeekFrom, Write};
 #[cfg(unix)]
+use std::os::unix::fs::FileTypeExt;
+#[cfg(unix)]
 use std::os::unix::prelude::PermissionsExt;
 use std::path::{Path, PathBuf};
 use uucore::display::Quotable;
@@ -55,6 +57,15 @@ const PATTERN_BUFFER_SIZE: usize = BLOCK_SIZE + PATTERN_LENGTH - 1;
 /// example, `std::os::unix::fs::MetadataExt::blksize()`.
 const OPTIMAL_IO_BLOCK_SIZE: usize = 1 << 12;
 
+/// Number of [`BLOCK_SIZE`] writes between two flushes when wiping a device (64 MiB), so that a
+/// pass over a large device neither piles up dirty pages nor reports a failing device only at
+/// the very end.
+const DEVICE_SYNC_BLOCKS: u64 = 1 << 10;
+
+/// A block buffer aligned for `O_DIRECT` writes, whatever the logical sector size of the device.
+#[repr(C, align(4096))]
+struct AlignedBlock([u8; BLOCK_SIZE]);
+
 /// Patterns that appear in order for the passes
 ///
 /// A single-byte pattern is equivalent to a multi-byte pattern of that byte three times.
@@ -633,16 +644,19 @@ fn wipe_file(
             translate!("shred-no-such-file-or-directory", "file" => path.maybe_quote()),
         ));
     }
-    if !path.is_file() {
+    let metadata =
+        fs::metadata(path).map_err_context(|| translate!("shred-failed-to-get-metadata"))?;
+    #[cfg(unix)]
+    let is_device = metadata.file_type().is_block_device();
+    #[cfg(not(unix))]
+    let is_device = false;
+    if !metadata.is_file() && !is_device {
         return Err(USimpleError::new(
             1,
             translate!("shred-not-a-file", "file" => path.maybe_quote()),
         ));
     }
 
-    let metadata =
-        fs::metadata(path).map_err_context(|| translate!("shred-failed-to-get-metadata"))?;
-
     // If force is true, set file permissions to not-readonly.
     if force {
         let mut perms = metadata.permissions();
@@ -663,9 +677,26 @@ fn wipe_file(
             .map_err_context(|| translate!("shred-failed-to-set-permissions"))?;
     }
 
+    let mut file = OpenOptions::new()
+        .write(true)
+        .truncate(false)
+        .open(path)
+        .map_err_context(
+            || translate!("shred-failed-to-open-for-writing", "file" => path.maybe_quote()),
+        )?;
+
+    // The length of a device node is 0; ask the device instead.
+    let file_size = if is_device {
+        block_device_size(&file).map_err_context(
+            || translate!("shred-failed-to-get-device-size", "file" => path.maybe_quote()),
+        )?
+    } else {
+        metadata.len()
+    };
+
     // Fill up our pass sequence
     let mut pass_sequence = Vec::new();
-    if metadata.len() != 0 {
+    if file_size != 0 {
         // Only add passes if the file is non-empty
 
         if n_passes <= 3 {
@@ -689,18 +720,9 @@ fn wipe_file(
     }
 
     let total_passes = pass_sequence.len();
-    let mut file = OpenOptions::new()
-        .write(true)
-        .truncate(false)
-        .open(path)
-        .map_err_context(
-            || translate!("shred-failed-to-open-for-writing", "file" => path.maybe_quote()),
-        )?;
-
-    let size = match size {
-        Some(size) => size,
-        None => metadata.len(),
-    };
+    let size = size.unwrap_or(file_size);
+    // Rounding up to a whole block would write past the end of a device.
+    let exact = exact || is_device;
 
     for (i, pass_type) in pass_sequence.into_iter().enumerate() {
         if verbose {
@@ -712,7 +734,7 @@ fn wipe_file(
             );
         }
         // size is an optional argument for exactly how many bytes we want to shred
-        do_pass(&mut file, &pass_type, exact, random_source, size).map_err_context(
+        do_pass(&mut file, &pass_type, exact, is_device, random_source, size).map_err_context(
             || translate!("shred-file-write-pass-failed", "file" => path.maybe_quote()),
         )?;
     }
@@ -746,6 +768,7 @@ fn do_pass(
     file: &mut File,
     pass_type: &PassType,
     exact: bool,
+    is_device: bool,
     random_source: Option<&RefCell<File>>,
     file_size: u64,
 ) -> Result<(), io::Error> {
@@ -754,22 +777,149 @@ fn do_pass(
 
     let mut writer = BytesWriter::from_pass_type(pass_type, random_source)?;
     let (number_of_blocks, bytes_left) = split_on_blocks(file_size, exact);
+    let mut direct = is_device.then(|| DirectWriter::new(file));
 
     // We start by writing BLOCK_SIZE times as many time as possible.
-    for _ in 0..number_of_blocks {
+    for i in 1..=number_of_blocks {
         let block = writer.bytes_for_pass(BLOCK_SIZE)?;
-        file.write_all(block)?;
+        match &mut direct {
+            Some(direct) => direct.write_all(file, block)?,
+            None => file.write_all(block)?,
+        }
+        if is_device && i % DEVICE_SYNC_BLOCKS == 0 {
+            file.sync_data()?;
+        }
     }
 
     // Then we write remaining data which is smaller than the BLOCK_SIZE
     let block = writer.bytes_for_pass(bytes_left as usize)?;
-    file.write_all(block)?;
+    match &mut direct {
+        Some(direct) => direct.write_all(file, block)?,
+        None => file.write_all(block)?,
+    }
+    if let Some(direct) = &mut direct {
+        direct.finish(file);
+    }
 
     file.sync_data()?;
 
     Ok(())
 }
 
+/// Writes to a device with `O_DIRECT`, so that wiping a whole device does not go through (and
+/// evict everything else from) the page cache. Blocks are copied to an aligned buffer first, as
+/// direct I/O requires. Like GNU, direct I/O is given up for the rest of the pass as soon as the
+/// device rejects a write with `EINVAL`, e.g. for a tail that is not a whole sector.
+struct DirectWriter {
+    enabled: bool,
+    buffer: Box<AlignedBlock>,
+}
+
+impl DirectWriter {
+    fn new(file: &File) -> Self {
+        Self {
+            enabled: set_direct_io(file, true),
+            buffer: Box::new(AlignedBlock([0; BLOCK_SIZE])),
+        }
+    }
+
+    fn write_all(&mut self, file: &mut File, block: &[u8]) -> io::Result<()> {
+        if self.enabled {
+            let aligned = &mut self.buffer.0[..block.len()];
+            aligned.copy_from_slice(block);
+            match file.write(aligned) {
+                Ok(n) if n == block.len() => return Ok(()),
+                Ok(n) => {
+                    self.finish(file);
+                    return file.write_all(&block[n..]);
+                }
+                Err(e) if e.raw_os_error() == Some(libc::EINVAL) => self.finish(file),
+                Err(e) => return Err(e),
+            }
+        }
+        file.write_all(block)
+    }
+
+    fn finish(&mut self, file: &File) {
+        if self.enabled {
+            set_direct_io(file, false);
+            self.enabled = false;
+        }
+    }
+}
+
+/// Turn `O_DIRECT` on or off for `file`. Returns whether it is on afterwards.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn set_direct_io(file: &File, on: bool) -> bool {
+    use rustix::fs::{OFlags, fcntl_getfl, fcntl_setfl};
+
+    let Ok(mut flags) = fcntl_getfl(file) else {
+        return false;
+    };
+    flags.set(OFlags::DIRECT, on);
+    fcntl_setfl(file, flags).is_ok() && on
+}
+
+/// Direct I/O is only used where `O_DIRECT` can be toggled on an open descriptor.
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn set_direct_io(_file: &File, _on: bool) -> bool {
+    false
+}
+
+/// Size in bytes of the block device open as `file`, using `BLKGETSIZE64`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn block_device_size(file: &File) -> io::Result<u64> {
+    use rustix::ioctl::{Getter, Opcode, ioctl, opcode};
+
+    // The kernel defines BLKGETSIZE64 with a `size_t` argument size but fills in a u64.
+    const BLKGETSIZE64: Opcode = opcode::read::<usize>(0x12, 114);
+    // SAFETY: BLKGETSIZE64 is a getter that writes a u64.
+    match unsafe { ioctl(file, Getter::<BLKGETSIZE64, u64>::new()) } {
+        Ok(size) => Ok(size),
+        Err(_) => size_by_seeking(file),
+    }
+}
+
+/// Size in bytes of the block device open as `file`, using `DKIOCGETBLOCKCOUNT` and
+/// `DKIOCGETBLOCKSIZE`.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+fn block_device_size(file: &File) -> io::Result<u64> {
+    use rustix::ioctl::{Getter, Opcode, ioctl, opcode};
+
+    const DKIOCGETBLOCKSIZE: Opcode = opcode::read::<u32>(b'd', 24);
+    const DKIOCGETBLOCKCOUNT: Opcode = opcode::read::<u64>(b'd', 25);
+    // SAFETY: both are getters writing a value of the type they are declared with.
+    let sizes = unsafe {
+        ioctl(file, Getter::<DKIOCGETBLOCKSIZE, u32>::new()).and_then(|block_size| {
+            ioctl(file, Getter::<DKIOCGETBLOCKCOUNT, u64>::new())
+                .map(|block_count| u64::from(block_size) * block_count)
+        })
+    };
+    match sizes {
+        Ok(size) => Ok(size),
+        Err(_) => size_by_seeking(file),
+    }
+}
+
+/// Size in bytes of the block device open as `file`, by seeking to its end.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios"
+)))]
+fn block_device_size(file: &File) -> io::Result<u64> {
+    size_by_seeking(file)
+}
+
+/// Size in bytes of `file`, found by seeking to its end. Used where the platform has no size
+/// ioctl or the ioctl fails. Leaves the file positioned at its start.
+fn size_by_seeking(mut file: &File) -> io::Result<u64> {
+    let size = file.seek(SeekFrom::End(0))?;
+    file.rewind()?;
+    Ok(size)
+}
+
 /// Repeatedly renames the file with strings of decreasing length (most likely all 0s)
 /// Return the path of the file after its last renaming or None in case of an error
 fn wipe_name(orig_path: &Path, verbose: bool, remove_method: RemoveMethod) -> PathBuf {
@@ -852,7 +1002,11 @@ fn do_remove(path: &Path, verbose: bool, remove_method: RemoveMethod) -> Result<
 #[cfg(test)]
 mod tests {
 
-    use crate::{BLOCK_SIZE, OPTIMAL_IO_BLOCK_SIZE, split_on_blocks};
+    use crate::{
+        AlignedBlock, BLOCK_SIZE, DirectWriter, OPTIMAL_IO_BLOCK_SIZE, PassType, Pattern, do_pass,
+        size_by_seeking, split_on_blocks,
+    };
+    use std::io::{Read, Seek, SeekFrom, Write};
 
     #[test]
     fn test_align_non_exact_control_values() {
@@ -884,4 +1038,56 @@ mod tests {
             assert_eq!(test_size, size);
         }
     }
+
+    #[test]
+    fn test_aligned_block_is_sector_aligned() {
+        let block = Box::new(AlignedBlock([0; BLOCK_SIZE]));
+        assert_eq!(block.0.as_ptr() as usize % OPTIMAL_IO_BLOCK_SIZE, 0);
+    }
+
+    #[test]
+    fn test_size_by_seeking_regular_file() {
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&[b'x'; 12345]).unwrap();
+        file.seek(SeekFrom::Start(7)).unwrap();
+
+        assert_eq!(size_by_seeking(&file).unwrap(), 12345);
+        assert_eq!(file.stream_position().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_direct_writer_non_aligned_tail() {
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = DirectWriter::new(&file);
+        writer.write_all(&mut file, &[b'a'; BLOCK_SIZE]).unwrap();
+        // A tail that is not a whole sector makes direct I/O fail with EINVAL, which must fall
+        // back to a regular write instead of losing the data.
+        writer.write_all(&mut file, &[b'b'; 1000]).unwrap();
+        writer.finish(&file);
+        assert!(!writer.enabled);
+
+        let mut content = Vec::new();
+        file.rewind().unwrap();
+        file.read_to_end(&mut content).unwrap();
+        assert_eq!(content.len(), BLOCK_SIZE + 1000);
+        assert!(content[..BLOCK_SIZE].iter().all(|&b| b == b'a'));
+        assert!(content[BLOCK_SIZE..].iter().all(|&b| b == b'b'));
+    }
+
+    #[test]
+    fn test_do_pass_device_writes_exact_size() {
+        let size = 3 * BLOCK_SIZE as u64 + 123;
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&vec![b'x'; size as usize + 10]).unwrap();
+
+        let pass = PassType::Pattern(Pattern::Single(0x55));
+        do_pass(&mut file, &pass, true, true, None, size).unwrap();
+
+        let mut content = Vec::new();
+        file.rewind().unwrap();
+        file.read_to_end(&mut content).unwrap();
+        assert_eq!(content.len(), size as usize + 10);
+        assert!(content[..size as usize].iter().all(|&b| b == 0x55));
+        assert!(content[size as usize..].iter().all(|&b| b == b'x'));
+    }
 }
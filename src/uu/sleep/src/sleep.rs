@@ -70,6 +70,55 @@ fn sleep(args: &[&str]) -> UResult<()> {
     if arg_error {
         return Err(UUsageError::new(1, ""));
     }
-    thread::sleep(sleep_dur);
+    sleep_for(sleep_dur);
     Ok(())
 }
+
+/// Sleep until `dur` has elapsed on the monotonic clock.
+///
+/// The deadline is absolute, so resuming after an interruption (`EINTR`) neither
+/// shortens the sleep nor accumulates the rounding of repeated relative sleeps, and
+/// wall clock changes do not affect it.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn sleep_for(dur: Duration) {
+    use rustix::io::Errno;
+    use rustix::thread::{ClockId, Timespec, clock_nanosleep_absolute};
+    use rustix::time::clock_gettime;
+
+    let monotonic_now = || {
+        let now = clock_gettime(ClockId::Monotonic);
+        Duration::new(now.tv_sec as u64, now.tv_nsec as u32)
+    };
+    let deadline = monotonic_now().checked_add(dur);
+    let timespec = deadline.and_then(|deadline| {
+        Some(Timespec {
+            tv_sec: deadline.as_secs().try_into().ok()?,
+            tv_nsec: deadline.subsec_nanos().into(),
+        })
+    });
+    let (Some(deadline), Some(timespec)) = (deadline, timespec) else {
+        // Too far in the future to represent, e.g. "infinity".
+        loop {
+            thread::sleep(Duration::MAX);
+        }
+    };
+
+    loop {
+        match clock_nanosleep_absolute(ClockId::Monotonic, &timespec) {
+            Err(Errno::INTR) => {}
+            Ok(()) => return,
+            Err(_) => {
+                // Not expected for a valid deadline, but never return early: sleep
+                // for what is left, as the sleep may have been interrupted before.
+                thread::sleep(deadline.saturating_sub(monotonic_now()));
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn sleep_for(dur: Duration) {
+    // The standard library restarts the sleep with the remaining time after EINTR.
+    thread::sleep(dur);
+}
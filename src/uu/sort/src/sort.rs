@@ -27,6 +27,7 @@ use custom_str_cmp::custom_str_cmp;
 use ext_sort::ext_sort;
 use foldhash::fast::FoldHasher;
 use foldhash::{HashMap, SharedSeed};
+use memchr::memchr_iter;
 use numeric_str_cmp::{NumInfo, NumInfoParseSettings, human_numeric_str_cmp, numeric_str_cmp};
 use rand::{RngExt as _, rng};
 #[cfg(not(target_os = "wasi"))]
@@ -56,7 +57,7 @@ use uucore::line_ending::LineEnding;
 use uucore::parser::num_parser::{ExtendedParser, ExtendedParserError};
 use uucore::parser::parse_size::{ParseSizeError, Parser};
 use uucore::parser::shortcut_value_parser::ShortcutValueParser;
-use uucore::posix::{MODERN, TRADITIONAL};
+use uucore::posix::{allows_traditional_usage, posixly_correct};
 use uucore::show_error;
 use uucore::translate;
 use uucore::version_cmp::version_cmp;
@@ -927,12 +928,8 @@ fn is_blank_thousands_sep(line: &[u8], idx: usize, allow_unit_after_blank: bool)
 /// Split between separators. These separators are not included in fields.
 /// The result is stored into `token_buffer`.
 fn tokenize_with_separator(line: &[u8], separator: u8, token_buffer: &mut Vec<Field>) {
-    let separator_indices = line
-        .iter()
-        .enumerate()
-        .filter_map(|(i, &c)| if c == separator { Some(i) } else { None });
     let mut start = 0;
-    for sep_idx in separator_indices {
+    for sep_idx in memchr_iter(separator, line) {
         token_buffer.push(start..sep_idx);
         start = sep_idx + 1;
     }
@@ -1437,12 +1434,6 @@ pub(crate) fn current_open_fd_count() -> Option<usize> {
 
 const STDIN_FILE: &str = "-";
 
-/// Legacy `+POS1 [-POS2]` syntax is permitted unless `_POSIX2_VERSION` is in
-/// the [TRADITIONAL, MODERN) range (matches GNU behaviour).
-fn allows_traditional_usage() -> bool {
-    !matches!(uucore::posix::posix_version(), Some(ver) if (TRADITIONAL..MODERN).contains(&ver))
-}
-
 #[derive(Debug, Clone)]
 struct LegacyKeyPart {
     field: usize,
@@ -1601,9 +1592,10 @@ where
     I: IntoIterator,
     I::Item: Into<OsString>,
 {
-    if !allows_traditional_usage() {
-        return (args.into_iter().map(Into::into).collect(), Vec::new());
-    }
+    // Like GNU, `+POS1 -POS2` is still a key outside of the traditional usage,
+    // unless POSIXLY_CORRECT is set.
+    let traditional_usage = allows_traditional_usage();
+    let minus_pos_usage = !posixly_correct();
 
     let mut processed = Vec::new();
     let mut legacy_warnings = Vec::new();
@@ -1623,12 +1615,17 @@ where
             let mut to_part = None;
 
             let next_candidate = iter.peek().map(|next| next.to_string_lossy().to_string());
-
-            if let Some(next_str) = next_candidate
-                && let Some(stripped) = next_str
+            let next_pos = next_candidate.as_deref().and_then(|next_str| {
+                next_str
                     .strip_prefix('-')
                     .filter(|s| s.starts_with(|c: char| c.is_ascii_digit()))
-            {
+            });
+            if !(traditional_usage || (minus_pos_usage && next_pos.is_some())) {
+                processed.push(arg);
+                continue;
+            }
+
+            if let Some(stripped) = next_pos {
                 let next_arg = iter.next().unwrap();
                 if let Some(parsed) = parse_legacy_part(stripped) {
                     to_part = Some(parsed);
@@ -15,6 +15,8 @@ stdbuf-after-help = If MODE is 'L' the corresponding stream will be line buffere
 
   NOTE: If COMMAND adjusts the buffering of its standard streams (tee does for e.g.) then that will override corresponding settings changed by stdbuf.
   Also some filters (like dd and cat etc.) don't use streams for I/O, and are thus unaffected by stdbuf settings.
+  Statically linked programs and programs using another C library are unaffected as well, and stdbuf warns about them.
+  On macOS, programs protected by System Integrity Protection (like those in /bin and /usr/bin) and set-user-ID or set-group-ID programs ignore the preloaded library, so their buffering, and that of the commands they run, is unaffected.
 
 stdbuf-help-input = adjust standard input stream buffering
 stdbuf-help-output = adjust standard output stream buffering
@@ -29,3 +31,7 @@ stdbuf-error-permission-denied = failed to execute process: Permission denied
 stdbuf-error-no-such-file = failed to execute process: No such file or directory
 stdbuf-error-failed-to-execute = failed to execute process: {$error}
 stdbuf-error-killed-by-signal = process killed by signal {$signal}
+
+stdbuf-warning-statically-linked = {$command} is statically linked; its buffering will not be changed
+stdbuf-warning-other-libc = {$command} uses another C library ({$interpreter}); its buffering will not be changed
+stdbuf-warning-restricted-command = {$command} is protected by System Integrity Protection or is set-user-ID; its buffering will not be changed
@@ -14,6 +14,8 @@ stdbuf-after-help = Si MODE est 'L', le flux correspondant sera mis en mémoire
 
   NOTE : Si COMMANDE ajuste la mise en mémoire tampon de ses flux standards (tee le fait par exemple), cela remplacera les paramètres correspondants modifiés par stdbuf.
   De plus, certains filtres (comme dd et cat etc.) n'utilisent pas de flux pour les E/S, et ne sont donc pas affectés par les paramètres stdbuf.
+  Les programmes liés statiquement et ceux qui utilisent une autre bibliothèque C ne sont pas non plus affectés, et stdbuf le signale.
+  Sous macOS, les programmes protégés par la Protection de l'intégrité du système (comme ceux de /bin et /usr/bin) et les programmes set-user-ID ou set-group-ID ignorent la bibliothèque préchargée : leur mise en mémoire tampon, et celle des commandes qu'ils lancent, n'est pas modifiée.
 
 stdbuf-help-input = ajuster la mise en mémoire tampon du flux d'entrée standard
 stdbuf-help-output = ajuster la mise en mémoire tampon du flux de sortie standard
@@ -28,3 +30,7 @@ stdbuf-error-permission-denied = échec de l'exécution du processus : Permissio
 stdbuf-error-no-such-file = échec de l'exécution du processus : Aucun fichier ou répertoire de ce type
 stdbuf-error-failed-to-execute = échec de l'exécution du processus : {$error}
 stdbuf-error-killed-by-signal = processus tué par le signal {$signal}
+
+stdbuf-warning-statically-linked = {$command} est lié statiquement ; sa mise en mémoire tampon ne sera pas modifiée
+stdbuf-warning-other-libc = {$command} utilise une autre bibliothèque C ({$interpreter}) ; sa mise en mémoire tampon ne sera pas modifiée
+stdbuf-warning-restricted-command = {$command} est protégé par la Protection de l'intégrité du système ou est set-user-ID ; sa mise en mémoire tampon ne sera pas modifiée
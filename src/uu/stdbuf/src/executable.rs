@@ -0,0 +1,213 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore dylinker musl phdr phnum phoff phentsize filesz
+
+//! Inspection of the executable run by stdbuf, to tell the user when the
+//! preloaded libstdbuf can't have any effect on it.
+
+use std::env;
+use std::ffi::OsStr;
+use std::fs::File;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::FileExt;
+use std::path::PathBuf;
+
+/// Why libstdbuf won't be loaded into an executable.
+pub(crate) enum Unsupported {
+    /// The executable has no dynamic loader to preload libstdbuf.
+    StaticallyLinked,
+    /// The executable is linked against another C library than libstdbuf,
+    /// identified by its dynamic loader.
+    OtherLibc(String),
+    /// dyld drops the `DYLD_*` variables for the executable.
+    #[cfg(target_os = "macos")]
+    Restricted,
+}
+
+/// Check whether the libstdbuf preload will be honored by `command`.
+///
+/// Only executables that can be read and are recognized are checked; scripts
+/// or anything unexpected are assumed to be fine.
+pub(crate) fn check(command: &OsStr) -> Option<Unsupported> {
+    let path = resolve(command)?;
+    let file = File::open(&path).ok()?;
+
+    #[cfg(target_os = "macos")]
+    if is_restricted(&file) {
+        return Some(Unsupported::Restricted);
+    }
+
+    let mut magic = [0; 4];
+    file.read_exact_at(&mut magic, 0).ok()?;
+    match magic {
+        [0x7f, b'E', b'L', b'F'] => check_elf(&file),
+        [0xce | 0xcf, 0xfa, 0xed, 0xfe] => check_macho(&file, magic[0] == 0xcf),
+        _ => None,
+    }
+}
+
+/// Find the file that `execvp` would run for `command`.
+fn resolve(command: &OsStr) -> Option<PathBuf> {
+    if command.as_bytes().contains(&b'/') {
+        return Some(PathBuf::from(command));
+    }
+    let paths = env::var_os("PATH")?;
+    env::split_paths(&paths)
+        .map(|dir| dir.join(command))
+        .find(|path| path.is_file())
+}
+
+/// System Integrity Protection marks the executables shipped with macOS (e.g.
+/// in `/bin` and `/usr/bin`) as restricted, and set-user-ID or set-group-ID
+/// programs are restricted as well. Such programs, and everything they start
+/// (like the commands run by `/bin/sh`), can't have libstdbuf injected.
+#[cfg(target_os = "macos")]
+fn is_restricted(file: &File) -> bool {
+    use std::os::macos::fs::MetadataExt;
+
+    // from <sys/stat.h>
+    const SF_RESTRICTED: u32 = 0x0008_0000;
+
+    file.metadata()
+        .is_ok_and(|meta| meta.st_flags() & SF_RESTRICTED != 0 || meta.st_mode() & 0o6000 != 0)
+}
+
+/// Read `N` bytes at `offset`, to be decoded with the file's byte order.
+fn read_at<const N: usize>(file: &File, offset: u64) -> Option<[u8; N]> {
+    let mut buf = [0; N];
+    file.read_exact_at(&mut buf, offset).ok()?;
+    Some(buf)
+}
+
+struct Reader<'a> {
+    file: &'a File,
+    big_endian: bool,
+}
+
+impl Reader<'_> {
+    fn u16(&self, offset: u64) -> Option<u16> {
+        let buf = read_at(self.file, offset)?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(buf)
+        } else {
+            u16::from_le_bytes(buf)
+        })
+    }
+
+    fn u32(&self, offset: u64) -> Option<u32> {
+        let buf = read_at(self.file, offset)?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(buf)
+        } else {
+            u32::from_le_bytes(buf)
+        })
+    }
+
+    fn u64(&self, offset: u64) -> Option<u64> {
+        let buf = read_at(self.file, offset)?;
+        Some(if self.big_endian {
+            u64::from_be_bytes(buf)
+        } else {
+            u64::from_le_bytes(buf)
+        })
+    }
+}
+
+/// An ELF executable without a `PT_INTERP` program header is started directly
+/// by the kernel, so nothing handles `LD_PRELOAD`.
+fn check_elf(file: &File) -> Option<Unsupported> {
+    const PT_INTERP: u32 = 3;
+    // from <linux/limits.h>, the interpreter is a path
+    const PATH_MAX: u64 = 4096;
+
+    let [class, data] = read_at(file, 4)?;
+    let is_64 = match class {
+        1 => false,
+        2 => true,
+        _ => return None,
+    };
+    let reader = Reader {
+        file,
+        big_endian: data == 2,
+    };
+
+    let (phoff, phentsize, phnum) = if is_64 {
+        (reader.u64(32)?, reader.u16(54)?, reader.u16(56)?)
+    } else {
+        (u64::from(reader.u32(28)?), reader.u16(42)?, reader.u16(44)?)
+    };
+
+    for index in 0..u64::from(phnum) {
+        let phdr = phoff + index * u64::from(phentsize);
+        if reader.u32(phdr)? != PT_INTERP {
+            continue;
+        }
+        let (offset, size) = if is_64 {
+            (reader.u64(phdr + 8)?, reader.u64(phdr + 32)?)
+        } else {
+            (
+                u64::from(reader.u32(phdr + 4)?),
+                u64::from(reader.u32(phdr + 16)?),
+            )
+        };
+        if size > PATH_MAX {
+            return None;
+        }
+        let mut interpreter = vec![0; usize::try_from(size).ok()?];
+        file.read_exact_at(&mut interpreter, offset).ok()?;
+        let interpreter = String::from_utf8_lossy(&interpreter)
+            .trim_end_matches('\0')
+            .to_string();
+        return check_interpreter(interpreter);
+    }
+
+    Some(Unsupported::StaticallyLinked)
+}
+
+/// libstdbuf is built against the same C library as stdbuf, and loading it
+/// into a program using another one (e.g. glibc and musl) fails.
+#[cfg(target_os = "linux")]
+fn check_interpreter(interpreter: String) -> Option<Unsupported> {
+    let name = interpreter.rsplit('/').next().unwrap_or_default();
+    let is_musl = name.starts_with("ld-musl");
+    let is_glibc = name.starts_with("ld-linux") || name.starts_with("ld64.so");
+    let other_libc = if cfg!(target_env = "musl") {
+        is_glibc
+    } else {
+        is_musl
+    };
+    other_libc.then_some(Unsupported::OtherLibc(interpreter))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn check_interpreter(_interpreter: String) -> Option<Unsupported> {
+    None
+}
+
+/// A Mach-O executable without a `LC_LOAD_DYLINKER` load command isn't
+/// started by dyld.
+fn check_macho(file: &File, is_64: bool) -> Option<Unsupported> {
+    const MH_EXECUTE: u32 = 0x2;
+    const LC_LOAD_DYLINKER: u32 = 0xe;
+
+    let reader = Reader {
+        file,
+        big_endian: false,
+    };
+    if reader.u32(12)? != MH_EXECUTE {
+        return None;
+    }
+    let ncmds = reader.u32(16)?;
+    let mut command = if is_64 { 32 } else { 28 };
+    for _ in 0..ncmds {
+        if reader.u32(command)? == LC_LOAD_DYLINKER {
+            return None;
+        }
+        command += u64::from(reader.u32(command + 4)?);
+    }
+
+    Some(Unsupported::StaticallyLinked)
+}
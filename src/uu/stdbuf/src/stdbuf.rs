@@ -5,7 +5,13 @@
 
 // spell-checker:ignore (ToDO) tempdir dyld dylib optgrps libstdbuf
 
+#[cfg(unix)]
+mod executable;
+
 use clap::{Arg, ArgAction, ArgMatches, Command};
+use std::env;
+#[cfg(unix)]
+use std::ffi::OsStr;
 use std::ffi::OsString;
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
@@ -14,11 +20,18 @@ use std::process;
 use tempfile::TempDir;
 use tempfile::tempdir;
 use thiserror::Error;
+#[cfg(unix)]
+use uucore::display::Quotable;
 use uucore::error::{UResult, USimpleError, UUsageError};
 use uucore::format_usage;
 use uucore::parser::parse_size::parse_size_u64;
+#[cfg(unix)]
+use uucore::show_warning;
 use uucore::translate;
 
+#[cfg(unix)]
+use crate::executable::Unsupported;
+
 mod options {
     pub const INPUT: &str = "input";
     pub const INPUT_SHORT: char = 'i';
@@ -87,7 +100,7 @@ fn preload_strings() -> (&'static str, &'static str) {
 
 #[cfg(target_vendor = "apple")]
 fn preload_strings() -> (&'static str, &'static str) {
-    ("DYLD_LIBRARY_PATH", "dylib")
+    ("DYLD_INSERT_LIBRARIES", "dylib")
 }
 
 #[cfg(any(target_os = "cygwin", windows))]
@@ -130,6 +143,39 @@ fn set_command_env(command: &mut process::Command, buffer_name: &str, buffer_typ
     }
 }
 
+/// Add libstdbuf to the libraries that are already preloaded, like GNU stdbuf.
+fn preload_value(preload_env: &str, libstdbuf: PathBuf) -> OsString {
+    match env::var_os(preload_env) {
+        Some(mut libs) if !libs.is_empty() => {
+            libs.push(":");
+            libs.push(libstdbuf);
+            libs
+        }
+        _ => libstdbuf.into_os_string(),
+    }
+}
+
+/// Tell the user when the buffering of `command` won't be changed.
+#[cfg(unix)]
+fn warn_if_unsupported(command: &OsStr) {
+    let message = match executable::check(command) {
+        None => return,
+        Some(Unsupported::StaticallyLinked) => {
+            translate!("stdbuf-warning-statically-linked", "command" => command.quote())
+        }
+        Some(Unsupported::OtherLibc(interpreter)) => translate!(
+            "stdbuf-warning-other-libc",
+            "command" => command.quote(),
+            "interpreter" => interpreter
+        ),
+        #[cfg(target_os = "macos")]
+        Some(Unsupported::Restricted) => {
+            translate!("stdbuf-warning-restricted-command", "command" => command.quote())
+        }
+    };
+    show_warning!("{message}");
+}
+
 #[cfg(not(feature = "feat_external_libstdbuf"))]
 fn get_preload_env(tmp_dir: &TempDir) -> UResult<(String, PathBuf)> {
     use std::fs::File;
@@ -158,7 +204,7 @@ fn get_preload_env(_tmp_dir: &TempDir) -> UResult<(String, PathBuf)> {
     let mut search_paths: Vec<PathBuf> = Vec::with_capacity(2);
 
     // First, try to get the directory where stdbuf is running from
-    if let Ok(exe_path) = std::env::current_exe()
+    if let Ok(exe_path) = env::current_exe()
         && let Some(exe_dir) = exe_path.parent()
     {
         search_paths.push(exe_dir.to_path_buf());
@@ -205,7 +251,9 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let tmp_dir = tempdir()
         .map_err(|e| UUsageError::new(125, format!("failed to create temp directory: {e}")))?;
     let (preload_env, libstdbuf) = get_preload_env(&tmp_dir)?;
-    command.env(preload_env, libstdbuf);
+    command.env(&preload_env, preload_value(&preload_env, libstdbuf));
+    #[cfg(unix)]
+    warn_if_unsupported(first_command);
     set_command_env(&mut command, "_STDBUF_I", &options.stdin);
     set_command_env(&mut command, "_STDBUF_O", &options.stdout);
     set_command_env(&mut command, "_STDBUF_E", &options.stderr);
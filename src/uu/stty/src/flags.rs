@@ -5,7 +5,7 @@
 
 // spell-checker:ignore parenb parodd cmspar hupcl cstopb cread clocal crtscts CSIZE
 // spell-checker:ignore ignbrk brkint ignpar parmrk inpck istrip inlcr igncr icrnl ixoff ixon iuclc ixany imaxbel iutf
-// spell-checker:ignore opost olcuc ocrnl onlcr onocr onlret ofdel nldly crdly tabdly bsdly vtdly ffdly
+// spell-checker:ignore opost olcuc ocrnl onlcr onocr onlret ofill ofdel nldly crdly tabdly bsdly vtdly ffdly
 // spell-checker:ignore isig icanon iexten echoe crterase echok echonl noflsh xcase tostop echoprt prterase echoctl ctlecho echoke crtkill flusho extproc
 // spell-checker:ignore lnext rprnt susp swtch vdiscard veof veol verase vintr vkill vlnext vquit vreprint vstart vstop vsusp vswtc vwerase werase
 // spell-checker:ignore sigquit sigtstp
@@ -42,39 +42,41 @@ pub enum AllFlags<'a> {
 }
 
 pub const CONTROL_FLAGS: &[Flag<C>] = &[
-    Flag::new("parenb", C::PARENB),
-    Flag::new("parodd", C::PARODD),
+    Flag::new("parenb", C::PARENB).unaffected_by_sane(),
+    Flag::new("parodd", C::PARODD).unaffected_by_sane(),
     #[cfg(any(
         target_os = "android",
         all(target_os = "linux", not(target_arch = "mips"))
     ))]
-    Flag::new("cmspar", C::CMSPAR),
-    Flag::new_grouped("cs5", C::CS5, C::CSIZE),
-    Flag::new_grouped("cs6", C::CS6, C::CSIZE),
-    Flag::new_grouped("cs7", C::CS7, C::CSIZE),
+    Flag::new("cmspar", C::CMSPAR).unaffected_by_sane(),
+    Flag::new_grouped("cs5", C::CS5, C::CSIZE).unaffected_by_sane(),
+    Flag::new_grouped("cs6", C::CS6, C::CSIZE).unaffected_by_sane(),
+    Flag::new_grouped("cs7", C::CS7, C::CSIZE).unaffected_by_sane(),
     Flag::new_grouped("cs8", C::CS8, C::CSIZE).sane(),
-    Flag::new("hupcl", C::HUPCL),
-    Flag::new("cstopb", C::CSTOPB),
+    Flag::new("hupcl", C::HUPCL).unaffected_by_sane(),
+    Flag::new("hup", C::HUPCL).unaffected_by_sane().hidden(),
+    Flag::new("cstopb", C::CSTOPB).unaffected_by_sane(),
     Flag::new("cread", C::CREAD).sane(),
-    Flag::new("clocal", C::CLOCAL),
-    Flag::new("crtscts", C::CRTSCTS),
+    Flag::new("clocal", C::CLOCAL).unaffected_by_sane(),
+    Flag::new("crtscts", C::CRTSCTS).unaffected_by_sane(),
 ];
 
 pub const INPUT_FLAGS: &[Flag<I>] = &[
     Flag::new("ignbrk", I::IGNBRK),
     Flag::new("brkint", I::BRKINT).sane(),
-    Flag::new("ignpar", I::IGNPAR),
-    Flag::new("parmrk", I::PARMRK),
-    Flag::new("inpck", I::INPCK),
-    Flag::new("istrip", I::ISTRIP),
+    Flag::new("ignpar", I::IGNPAR).unaffected_by_sane(),
+    Flag::new("parmrk", I::PARMRK).unaffected_by_sane(),
+    Flag::new("inpck", I::INPCK).unaffected_by_sane(),
+    Flag::new("istrip", I::ISTRIP).unaffected_by_sane(),
     Flag::new("inlcr", I::INLCR),
     Flag::new("igncr", I::IGNCR),
     Flag::new("icrnl", I::ICRNL).sane(),
+    Flag::new("ixon", I::IXON).unaffected_by_sane(),
     Flag::new("ixoff", I::IXOFF),
-    Flag::new("tandem", I::IXOFF),
-    Flag::new("ixon", I::IXON),
+    Flag::new("tandem", I::IXOFF).hidden(),
     // not supported by nix
-    // Flag::new("iuclc", I::IUCLC),
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Flag::new("iuclc", I::from_bits_retain(nix::libc::IUCLC)),
     Flag::new("ixany", I::IXANY),
     Flag::new("imaxbel", I::IMAXBEL).sane(),
     #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
@@ -94,6 +96,9 @@ pub const OUTPUT_FLAGS: &[Flag<O>] = &[
     Flag::new("onlcr", O::ONLCR).sane(),
     Flag::new("onocr", O::ONOCR),
     Flag::new("onlret", O::ONLRET),
+    // not supported by nix
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Flag::new("ofill", O::from_bits_retain(nix::libc::OFILL)),
     #[cfg(any(
         target_os = "android",
         target_os = "haiku",
@@ -225,8 +230,9 @@ pub const LOCAL_FLAGS: &[Flag<L>] = &[
     Flag::new("echok", L::ECHOK).sane(),
     Flag::new("echonl", L::ECHONL),
     Flag::new("noflsh", L::NOFLSH),
-    // Not supported by nix
-    // Flag::new("xcase", L::XCASE),
+    // not supported by nix
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Flag::new("xcase", L::from_bits_retain(nix::libc::XCASE)),
     Flag::new("tostop", L::TOSTOP),
     #[cfg(not(target_os = "cygwin"))]
     Flag::new("echoprt", L::ECHOPRT),
@@ -35,7 +35,7 @@ use nix::sys::termios::{
 use nix::{ioctl_read_bad, ioctl_write_ptr_bad};
 use std::cmp::Ordering;
 use std::fs::File;
-use std::io::{self, Stdin, stdin, stdout};
+use std::io::{Stdin, stdin, stdout};
 use std::num::IntErrorKind;
 use std::os::fd::{AsFd, BorrowedFd};
 use std::os::unix::fs::OpenOptionsExt;
@@ -74,7 +74,8 @@ pub struct Flag<T> {
     #[expect(clippy::struct_field_names)]
     flag: T,
     show: bool,
-    sane: bool,
+    /// The value `stty sane` gives the flag, if it changes it at all.
+    sane: Option<bool>,
     group: Option<T>,
 }
 
@@ -84,7 +85,7 @@ impl<T> Flag<T> {
             name,
             flag,
             show: true,
-            sane: false,
+            sane: Some(false),
             group: None,
         }
     }
@@ -94,7 +95,7 @@ impl<T> Flag<T> {
             name,
             flag,
             show: true,
-            sane: false,
+            sane: Some(false),
             group: Some(group),
         }
     }
@@ -105,7 +106,13 @@ impl<T> Flag<T> {
     }
 
     pub const fn sane(mut self) -> Self {
-        self.sane = true;
+        self.sane = Some(true);
+        self
+    }
+
+    /// The flag is left alone by `stty sane`, and is thus only shown with `-a`.
+    pub const fn unaffected_by_sane(mut self) -> Self {
+        self.sane = None;
         self
     }
 }
@@ -184,7 +191,7 @@ impl AsRawFd for Device {
 }
 
 impl<'a> Options<'a> {
-    fn from(matches: &'a ArgMatches) -> io::Result<Self> {
+    fn from(matches: &'a ArgMatches) -> UResult<Self> {
         let (file, device_name) = match matches.get_one::<String>(options::FILE) {
             // Two notes here:
             // 1. O_NONBLOCK is needed because according to GNU docs, a
@@ -200,7 +207,8 @@ impl<'a> Options<'a> {
                     std::fs::OpenOptions::new()
                         .read(true)
                         .custom_flags(O_NONBLOCK)
-                        .open(f)?,
+                        .open(f)
+                        .map_err_context(|| f.clone())?,
                 ),
                 f.clone(),
             ),
@@ -869,6 +877,13 @@ fn print_control_chars(
                 ));
             }
         }
+        // min and time are only relevant in noncanonical mode
+        if !termios.local_flags.contains(LocalFlags::ICANON) {
+            printer.print(&translate!("stty-output-min-time",
+                "min" => termios.control_chars[S::VMIN as usize],
+                "time" => termios.control_chars[S::VTIME as usize]
+            ));
+        }
         printer.flush();
         return Ok(());
     }
@@ -957,10 +972,10 @@ fn print_flags<T: TermiosFlag>(
         }
         let val = flag.is_in(termios, group);
         if group.is_some() {
-            if val && (!sane || opts.all) {
+            if val && (opts.all || sane == Some(false)) {
                 printer.print(name);
             }
-        } else if opts.all || val != sane {
+        } else if opts.all || sane == Some(!val) {
             if !val {
                 printer.print(&format!("-{name}"));
                 continue;
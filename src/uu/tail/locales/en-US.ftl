@@ -10,7 +10,8 @@ tail-help-bytes = Number of bytes to print
 tail-help-debug = indicate which --follow implementation is used
 tail-help-follow = Print the file as it grows
 tail-help-lines = Number of lines to print
-tail-help-pid = With -f, terminate after process ID, PID dies
+tail-help-pid = With -f, terminate after process ID, PID dies;
+  can be repeated to watch multiple processes
 tail-help-quiet = Never output headers giving file names
 tail-help-sleep-interval = Number of seconds to sleep between polling the file when running with -f
 tail-help-max-unchanged-stats = Reopen a FILE which has not changed size after N (default 5) iterations to see if it has been unlinked or renamed (this is the usual case of rotated log files); This option is meaningful only when polling (i.e., with --use-polling) and when --follow=name
@@ -9,7 +9,8 @@ tail-usage = tail [OPTION]... [FICHIER]...
 tail-help-bytes = Nombre d'octets à afficher
 tail-help-follow = Afficher le fichier au fur et à mesure de sa croissance
 tail-help-lines = Nombre de lignes à afficher
-tail-help-pid = Avec -f, terminer après que l'ID de processus, PID meure
+tail-help-pid = Avec -f, terminer après que l'ID de processus, PID meure ;
+  peut être répété pour surveiller plusieurs processus
 tail-help-quiet = Ne jamais afficher d'en-têtes donnant les noms de fichiers
 tail-help-sleep-interval = Nombre de secondes à attendre entre les sondages du fichier lors de l'exécution avec -f
 tail-help-max-unchanged-stats = Rouvrir un FICHIER qui n'a pas changé de taille après N (par défaut 5) itérations pour voir s'il a été supprimé ou renommé (c'est le cas habituel des fichiers journaux pivotés) ; Cette option n'a de sens que lors du sondage (c'est-à-dire avec --use-polling) et quand --follow=name
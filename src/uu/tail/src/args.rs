@@ -17,6 +17,7 @@ use uucore::parser::parse_signed_num::{SignPrefix, parse_signed_num_max};
 use uucore::parser::parse_size::ParseSizeError;
 use uucore::parser::parse_time;
 use uucore::parser::shortcut_value_parser::ShortcutValueParser;
+use uucore::posix::allows_traditional_usage;
 use uucore::translate;
 use uucore::{format_usage, show_warning};
 
@@ -134,7 +135,7 @@ pub struct Settings {
     pub follow: Option<FollowMode>,
     pub max_unchanged_stats: u32,
     pub mode: FilterMode,
-    pub pid: platform::Pid,
+    pub pids: Vec<platform::Pid>,
     pub retry: bool,
     pub sleep_sec: Duration,
     pub use_polling: bool,
@@ -152,7 +153,7 @@ impl Default for Settings {
             sleep_sec: Duration::from_secs_f32(1.0),
             follow: Option::default(),
             mode: FilterMode::default(),
-            pid: Default::default(),
+            pids: Vec::new(),
             retry: Default::default(),
             use_polling: Default::default(),
             verbose: Default::default(),
@@ -251,7 +252,11 @@ impl Settings {
             }
         }
 
-        if let Some(pid_str) = matches.get_one::<String>(options::PID) {
+        for pid_str in matches
+            .get_many::<String>(options::PID)
+            .into_iter()
+            .flatten()
+        {
             match pid_str.parse() {
                 Ok(pid) => {
                     // NOTE: on unix platform::Pid is i32, on windows platform::Pid is u32
@@ -264,7 +269,9 @@ impl Settings {
                         ));
                     }
 
-                    settings.pid = pid;
+                    if pid != 0 {
+                        settings.pids.push(pid);
+                    }
                 }
                 Err(e) => {
                     return Err(USimpleError::new(
@@ -309,10 +316,14 @@ impl Settings {
             }
         }
 
-        if self.pid != 0 {
+        if !self.pids.is_empty() {
             if self.follow.is_none() {
                 show_warning!("{}", translate!("tail-warning-pid-ignored"));
-            } else if !platform::supports_pid_checks(self.pid) {
+            } else if !self
+                .pids
+                .iter()
+                .all(|&pid| platform::supports_pid_checks(pid))
+            {
                 show_warning!("{}", translate!("tail-warning-pid-not-supported"));
             }
         }
@@ -322,7 +333,7 @@ impl Settings {
         // as `tty` (but no otherwise blocking stdin), then we print a warning that `--follow`
         // cannot be applied under these circumstances and is therefore ineffective.
         if self.follow.is_some() && self.has_stdin() {
-            let blocking_stdin = self.pid == 0
+            let blocking_stdin = self.pids.is_empty()
                 && self.follow == Some(FollowMode::Descriptor)
                 && self.num_inputs() == 1
                 && Handle::stdin().is_ok_and(|handle| {
@@ -363,6 +374,10 @@ impl Settings {
 }
 
 pub fn parse_obsolete(arg: &OsString, input: Option<&OsString>) -> UResult<Option<Settings>> {
+    // Without the traditional usage, `+NUM` is a file name.
+    if arg.as_encoded_bytes().first() == Some(&b'+') && !allows_traditional_usage() {
+        return Ok(None);
+    }
     match parse::parse_obsolete(arg) {
         Some(Ok(args)) => Ok(Some(Settings::from_obsolete_args(&args, input))),
         None => Ok(None),
@@ -488,7 +503,7 @@ pub fn uu_app() -> Command {
                 .long(options::PID)
                 .value_name("PID")
                 .help(translate!("tail-help-pid"))
-                .overrides_with(options::PID),
+                .action(ArgAction::Append),
         )
         .arg(
             Arg::new(options::verbosity::QUIET)
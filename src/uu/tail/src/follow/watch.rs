@@ -99,7 +99,7 @@ pub struct Observer {
     pub orphans: Vec<PathBuf>,
     pub files: FileHandling,
 
-    pub pid: platform::Pid,
+    pub pids: Vec<platform::Pid>,
 }
 
 impl Observer {
@@ -108,13 +108,12 @@ impl Observer {
         follow: Option<FollowMode>,
         use_polling: bool,
         files: FileHandling,
-        pid: platform::Pid,
+        pids: Vec<platform::Pid>,
     ) -> Self {
-        let pid = if platform::supports_pid_checks(pid) {
-            pid
-        } else {
-            0
-        };
+        let pids = pids
+            .into_iter()
+            .filter(|&pid| platform::supports_pid_checks(pid))
+            .collect();
 
         Self {
             retry,
@@ -123,7 +122,7 @@ impl Observer {
             watcher_rx: None,
             orphans: Vec::new(),
             files,
-            pid,
+            pids,
         }
     }
 
@@ -133,7 +132,7 @@ impl Observer {
             settings.follow,
             settings.use_polling,
             FileHandling::from(settings),
-            settings.pid,
+            settings.pids.clone(),
         )
     }
 
@@ -495,7 +494,11 @@ pub fn follow(mut observer: Observer, settings: &Settings) -> UResult<()> {
         return Err(USimpleError::new(1, translate!("tail-no-files-remaining")));
     }
 
-    let process = platform::ProcessChecker::new(observer.pid);
+    let processes: Vec<_> = observer
+        .pids
+        .iter()
+        .map(|&pid| platform::ProcessChecker::new(pid))
+        .collect();
 
     let mut timeout_counter = 0;
 
@@ -503,10 +506,13 @@ pub fn follow(mut observer: Observer, settings: &Settings) -> UResult<()> {
     loop {
         let mut _read_some = false;
 
-        // If `--pid=p`, tail checks whether process p
-        // is alive at least every `--sleep-interval=N` seconds
-        if settings.follow.is_some() && observer.pid != 0 && process.is_dead() {
-            // p is dead, tail will also terminate
+        // If `--pid=p` (possibly repeated), tail checks whether the processes
+        // are alive at least every `--sleep-interval=N` seconds
+        if settings.follow.is_some()
+            && !processes.is_empty()
+            && processes.iter().all(platform::ProcessChecker::is_dead)
+        {
+            // all of them are dead, tail will also terminate
             break;
         }
 
@@ -212,7 +212,7 @@ fn files(a: &OsStr, b: &OsStr, op: &OsStr) -> ParseResult<bool> {
         #[cfg(unix)]
         (Some("-ef"), Ok(f_a), Ok(f_b)) => f_a.ino() == f_b.ino() && f_a.dev() == f_b.dev(),
         #[cfg(not(unix))]
-        (Some("-ef"), Ok(_), Ok(_)) => unimplemented!(),
+        (Some("-ef"), Ok(_), Ok(_)) => same_file::is_same_file(a, b).unwrap_or(false),
         (Some("-nt"), Ok(f_a), Ok(f_b)) => f_a.modified().unwrap() > f_b.modified().unwrap(),
         (Some("-nt"), Ok(_), _) => true,
         (Some("-ot"), Ok(f_a), Ok(f_b)) => f_a.modified().unwrap() < f_b.modified().unwrap(),
@@ -366,7 +366,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(unix)]
     fn test_files_with_ef_op() {
         let a = NamedTempFile::new().unwrap();
         let b = NamedTempFile::new().unwrap();
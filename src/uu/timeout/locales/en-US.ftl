@@ -21,7 +21,7 @@ timeout-after-help = Upon timeout, send the TERM signal to COMMAND, if no other
 
 # Error messages
 timeout-error-invalid-signal = { $signal }: invalid signal
-timeout-error-failed-to-execute-process = failed to execute process: { $error }
+timeout-error-failed-to-run-command = failed to run command { $command }: { $error }
 
 # Verbose messages
 timeout-verbose-sending-signal = sending signal { $signal } to command { $command }
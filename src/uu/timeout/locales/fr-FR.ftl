@@ -21,7 +21,7 @@ timeout-after-help = À l'expiration du délai, le signal TERM est envoyé à CO
 
 # Messages d'erreur
 timeout-error-invalid-signal = { $signal } : signal invalide
-timeout-error-failed-to-execute-process = échec d'exécution du processus : { $error }
+timeout-error-failed-to-run-command = échec de l'exécution de la commande { $command } : { $error }
 
 # Messages détaillés
 timeout-verbose-sending-signal = envoi du signal { $signal } à la commande { $command }
@@ -6,28 +6,41 @@
 // spell-checker:ignore (ToDO) tstr sigstr cmdname setpgid sigchld getpid TTIN TTOU
 
 mod status;
+#[cfg(windows)]
+mod windows;
 
 use crate::status::ExitStatus;
 use clap::{Arg, ArgAction, Command};
-use std::io::{ErrorKind, Write};
+#[cfg(unix)]
+use std::io::ErrorKind;
+use std::io::Write;
+#[cfg(unix)]
 use std::os::unix::process::ExitStatusExt;
+#[cfg(unix)]
 use std::process::{self, Child, Stdio};
+#[cfg(unix)]
 use std::sync::atomic::{self, AtomicBool};
 use std::time::Duration;
 use uucore::display::Quotable;
-use uucore::error::{UResult, USimpleError, UUsageError};
+#[cfg(unix)]
+use uucore::error::USimpleError;
+use uucore::error::{UResult, UUsageError};
 use uucore::parser::parse_time;
+#[cfg(unix)]
 use uucore::process::ChildExt;
+#[cfg(unix)]
 use uucore::signals::install_signal_handler;
 use uucore::translate;
 
+#[cfg(windows)]
+use crate::windows::{signal_by_name_or_value, signal_list_name_by_value};
+#[cfg(unix)]
 use rustix::process::{Pid, Signal, getpid, kill_process, setpgid};
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
-use uucore::{
-    format_usage,
-    signals::{signal_by_name_or_value, signal_list_name_by_value},
-};
+use uucore::format_usage;
+#[cfg(unix)]
+use uucore::signals::{signal_by_name_or_value, signal_list_name_by_value};
 
 pub mod options {
     pub static FOREGROUND: &str = "foreground";
@@ -110,7 +123,18 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         uucore::clap_localization::handle_clap_result_with_exit_code(uu_app(), args, 125)?;
 
     let config = Config::from(&matches)?;
-    timeout(
+    #[cfg(unix)]
+    return timeout(
+        &config.command,
+        config.duration,
+        config.signal,
+        config.kill_after,
+        config.foreground,
+        config.preserve_status,
+        config.verbose,
+    );
+    #[cfg(windows)]
+    return windows::timeout(
         &config.command,
         config.duration,
         config.signal,
@@ -118,7 +142,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         config.foreground,
         config.preserve_status,
         config.verbose,
-    )
+    );
 }
 
 pub fn uu_app() -> Command {
@@ -138,6 +162,7 @@ pub fn uu_app() -> Command {
             Arg::new(options::KILL_AFTER)
                 .long(options::KILL_AFTER)
                 .short('k')
+                .overrides_with(options::KILL_AFTER)
                 .help(translate!("timeout-help-kill-after")),
         )
         .arg(
@@ -151,6 +176,7 @@ pub fn uu_app() -> Command {
             Arg::new(options::SIGNAL)
                 .short('s')
                 .long(options::SIGNAL)
+                .overrides_with(options::SIGNAL)
                 .help(translate!("timeout-help-signal"))
                 .value_name("SIGNAL"),
         )
@@ -178,17 +204,21 @@ pub fn uu_app() -> Command {
         .after_help(translate!("timeout-after-help"))
 }
 
+#[cfg(unix)]
 /// Install SIGCHLD handler to ensure waiting for child works even if parent ignored SIGCHLD.
 fn install_sigchld() {
     extern "C" fn chld(_: libc::c_int) {}
     let _ = install_signal_handler(Signal::as_raw(Signal::CHILD), chld);
 }
 
+#[cfg(unix)]
 /// We should terminate child process when receiving termination signals.
 static SIGNALED: AtomicBool = AtomicBool::new(false);
+#[cfg(unix)]
 /// Track which signal was received (0 = none/timeout expired naturally).
 static RECEIVED_SIGNAL: atomic::AtomicI32 = atomic::AtomicI32::new(0);
 
+#[cfg(unix)]
 /// Install signal handlers for termination signals.
 fn install_signal_handlers(term_signal: usize) {
     extern "C" fn handle_signal(sig: libc::c_int) {
@@ -237,6 +267,7 @@ fn report_if_verbose(signal: usize, cmd: &str, verbose: bool) {
     }
 }
 
+#[cfg(unix)]
 fn signal_from_raw(sig: i32) -> Option<Signal> {
     if sig <= 0 {
         return None;
@@ -258,6 +289,7 @@ fn signal_from_raw(sig: i32) -> Option<Signal> {
     None
 }
 
+#[cfg(unix)]
 fn send_signal(process: &mut Child, signal: usize, foreground: bool) {
     // NOTE: GNU timeout doesn't check for errors of signal.
     // The subprocess might have exited just after the timeout.
@@ -266,14 +298,62 @@ fn send_signal(process: &mut Child, signal: usize, foreground: bool) {
         return;
     }
     let _ = process.send_signal_group(signal);
+    // Also reach the processes that left our group, and the ones `SIGKILL` can't be sent
+    // to as a group since we can't ignore it ourselves.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let _ = uucore::process::signal_descendants(signal);
     let kill_signal = signal_by_name_or_value("KILL").unwrap();
     let continued_signal = signal_by_name_or_value("CONT").unwrap();
     if signal != kill_signal && signal != continued_signal {
         let _ = process.send_signal(continued_signal);
         let _ = process.send_signal_group(continued_signal);
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let _ = uucore::process::signal_descendants(continued_signal);
+    }
+}
+
+/// Wait for the processes the command left behind, and kill them at `deadline`.
+///
+/// When the command exits, its remaining descendants are reparented to us since we
+/// are their subreaper, so nothing outlives `--kill-after`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn kill_orphans(deadline: std::time::Instant) {
+    use rustix::process::{WaitOptions, wait};
+
+    loop {
+        // Reap the ones that already exited.
+        while let Ok(Some(_)) = wait(WaitOptions::NOHANG) {}
+        if uucore::process::descendants(getpid().as_raw_nonzero().get()).is_empty() {
+            return;
+        }
+        if std::time::Instant::now() >= deadline {
+            let _ = uucore::process::signal_descendants(signal_by_name_or_value("KILL").unwrap());
+            while wait(WaitOptions::empty()).is_ok() {}
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[cfg(unix)]
+/// The exit status of `timeout` once the command was signaled because it timed out.
+///
+/// A command that is killed by `SIGKILL` exits with 128 + 9 rather than 124, since
+/// that signal cannot be handled, which is what GNU does too.
+fn timed_out_status(status: process::ExitStatus, preserve_status: bool) -> i32 {
+    let kill_signal = signal_by_name_or_value("KILL").unwrap();
+    match status.signal() {
+        Some(signal) if preserve_status || signal as usize == kill_signal => {
+            ExitStatus::SignalSent(signal as usize).into()
+        }
+        _ if preserve_status => status
+            .code()
+            .unwrap_or_else(|| ExitStatus::TimeoutFailed.into()),
+        _ => ExitStatus::CommandTimedOut.into(),
     }
 }
 
+#[cfg(unix)]
 /// Wait for a child process and send a kill signal if it does not terminate.
 ///
 /// This function waits for the child `process` for the time period
@@ -302,31 +382,25 @@ fn wait_or_kill_process(
     foreground: bool,
     verbose: bool,
 ) -> std::io::Result<i32> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let deadline = std::time::Instant::now() + duration;
     // ignore `SIGTERM` here
-    match process.wait_or_timeout(duration, None) {
-        Ok(Some(status)) => {
-            if preserve_status {
-                let exit_code = status.code().unwrap_or_else(|| {
-                    status.signal().unwrap_or_else(|| {
-                        // Extremely rare: process exited but we have neither exit code nor signal.
-                        // This can happen on some platforms or in unusual termination scenarios.
-                        ExitStatus::TimeoutFailed.into()
-                    })
-                });
-                Ok(exit_code)
-            } else {
-                Ok(ExitStatus::CommandTimedOut.into())
-            }
-        }
+    let status = match process.wait_or_timeout(duration, None) {
+        Ok(Some(status)) => timed_out_status(status, preserve_status),
         Ok(None) => {
             let signal = signal_by_name_or_value("KILL").unwrap();
             report_if_verbose(signal, cmd, verbose);
             send_signal(process, signal, foreground);
             process.wait()?;
-            Ok(ExitStatus::SignalSent(signal).into())
+            ExitStatus::SignalSent(signal).into()
         }
-        Err(_) => Ok(ExitStatus::CommandTimedOut.into()),
+        Err(_) => ExitStatus::CommandTimedOut.into(),
+    };
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if !foreground {
+        kill_orphans(deadline);
     }
+    Ok(status)
 }
 
 #[cfg(unix)]
@@ -349,12 +423,7 @@ fn preserve_signal_info(signal: libc::c_int) -> libc::c_int {
     signal
 }
 
-#[cfg(not(unix))]
-fn preserve_signal_info(signal: libc::c_int) -> libc::c_int {
-    // Do nothing
-    signal
-}
-
+#[cfg(unix)]
 fn timeout(
     cmd: &[String],
     duration: Duration,
@@ -366,6 +435,9 @@ fn timeout(
 ) -> UResult<()> {
     if !foreground {
         let _ = setpgid(Pid::from_raw(0), Pid::from_raw(0));
+        // Adopt the processes orphaned by the command, so they can be killed along with it.
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let _ = rustix::process::set_child_subreaper(Some(getpid()));
     }
 
     let mut cmd_builder = process::Command::new(&cmd[0]);
@@ -413,7 +485,11 @@ fn timeout(
         };
         USimpleError::new(
             status_code,
-            translate!("timeout-error-failed-to-execute-process", "error" => err),
+            translate!(
+                "timeout-error-failed-to-run-command",
+                "command" => cmd[0].quote(),
+                "error" => uucore::error::strip_errno(&err)
+            ),
         )
     })?;
 
@@ -471,18 +547,8 @@ fn timeout(
                 Err(ExitStatus::SignalSent(received_sig as usize).into())
             } else if SIGNALED.load(atomic::Ordering::Relaxed) {
                 Err(ExitStatus::CommandTimedOut.into())
-            } else if preserve_status {
-                Err(status
-                    .code()
-                    .or_else(|| {
-                        status
-                            .signal()
-                            .map(|s| ExitStatus::SignalSent(s as usize).into())
-                    })
-                    .unwrap_or(ExitStatus::CommandTimedOut.into())
-                    .into())
             } else {
-                Err(ExitStatus::CommandTimedOut.into())
+                Err(timed_out_status(status, preserve_status).into())
             }
         }
         Err(_) => {
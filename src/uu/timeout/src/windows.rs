@@ -0,0 +1,181 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore ALRM SEGV
+
+//! Running the command on Windows.
+//!
+//! Windows has no signals. `INT` and `BREAK` are delivered as a CTRL+BREAK
+//! console event to the process group the command runs in, and every other
+//! signal but 0 terminates the command as `SIGKILL` would. The signal names
+//! are still accepted, so that the status of `timeout` is the same as on a
+//! POSIX system.
+
+use crate::report_if_verbose;
+use crate::status::ExitStatus;
+use std::io::ErrorKind;
+use std::os::windows::process::CommandExt;
+use std::process::{self, Child, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use uucore::display::Quotable;
+use uucore::error::{UResult, USimpleError};
+use uucore::translate;
+use windows_sys::Win32::System::Console::{CTRL_BREAK_EVENT, GenerateConsoleCtrlEvent};
+use windows_sys::Win32::System::Threading::CREATE_NEW_PROCESS_GROUP;
+
+/// The names that are accepted for a signal, with their numbers on Linux.
+const SIGNALS: [(&str, usize); 16] = [
+    ("EXIT", 0),
+    ("HUP", 1),
+    ("INT", 2),
+    ("QUIT", 3),
+    ("ILL", 4),
+    ("TRAP", 5),
+    ("ABRT", 6),
+    ("FPE", 8),
+    ("KILL", 9),
+    ("USR1", 10),
+    ("SEGV", 11),
+    ("USR2", 12),
+    ("PIPE", 13),
+    ("ALRM", 14),
+    ("TERM", 15),
+    // Not a POSIX signal, but the number the C runtime uses for CTRL+BREAK.
+    ("BREAK", 21),
+];
+
+const INT: usize = 2;
+const KILL: usize = 9;
+const BREAK: usize = 21;
+
+pub(crate) fn signal_by_name_or_value(signal_name_or_value: &str) -> Option<usize> {
+    let signal_name_upcase = signal_name_or_value.to_uppercase();
+    if let Ok(value) = signal_name_upcase.parse() {
+        return SIGNALS.iter().any(|&(_, v)| v == value).then_some(value);
+    }
+    let signal_name = signal_name_upcase.trim_start_matches("SIG");
+    SIGNALS
+        .iter()
+        .find(|&&(name, _)| name == signal_name)
+        .map(|&(_, value)| value)
+}
+
+pub(crate) fn signal_list_name_by_value(signal_value: usize) -> Option<String> {
+    SIGNALS
+        .iter()
+        .find(|&&(_, value)| value == signal_value)
+        .map(|&(name, _)| name.to_string())
+}
+
+/// Wait for `process` to exit for at most `duration`, or without a limit if it
+/// is zero.
+fn wait_or_timeout(
+    process: &mut Child,
+    duration: Duration,
+) -> std::io::Result<Option<process::ExitStatus>> {
+    if duration.is_zero() {
+        return process.wait().map(Some);
+    }
+    let deadline = Instant::now() + duration;
+    loop {
+        if let Some(status) = process.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Deliver `signal` to `process`, which runs in a process group of its own
+/// unless `foreground` is set.
+///
+/// `INT` and `BREAK` are sent as CTRL+BREAK to that group: CTRL+C can't be sent
+/// to a single group, and both end a console program by default. When there
+/// is no such group or no console to send it through, the command is
+/// terminated instead, as it is for any other signal but 0, which only checks
+/// that a process exists.
+fn send_signal(process: &mut Child, signal: usize, foreground: bool) {
+    // NOTE: GNU timeout doesn't check for errors of signal.
+    // The subprocess might have exited just after the timeout.
+    if signal == 0 {
+        return;
+    }
+    if matches!(signal, INT | BREAK) && !foreground {
+        // SAFETY: the id of the group created with the process is its own id.
+        if unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, process.id()) } != 0 {
+            return;
+        }
+    }
+    let _ = process.kill();
+}
+
+pub(crate) fn timeout(
+    cmd: &[String],
+    duration: Duration,
+    signal: usize,
+    kill_after: Option<Duration>,
+    foreground: bool,
+    preserve_status: bool,
+    verbose: bool,
+) -> UResult<()> {
+    let mut command = process::Command::new(&cmd[0]);
+    // Like the process group of GNU timeout, this keeps the console's CTRL+C
+    // from reaching the command, and lets INT be sent to the command alone.
+    if !foreground {
+        command.creation_flags(CREATE_NEW_PROCESS_GROUP);
+    }
+    let mut process = command
+        .args(&cmd[1..])
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|err| {
+            let status_code = match err.kind() {
+                ErrorKind::NotFound => ExitStatus::CommandNotFound.into(),
+                _ => ExitStatus::CannotInvoke.into(),
+            };
+            USimpleError::new(
+                status_code,
+                translate!(
+                    "timeout-error-failed-to-run-command",
+                    "command" => cmd[0].quote(),
+                    "error" => uucore::error::strip_errno(&err)
+                ),
+            )
+        })?;
+
+    if let Some(status) = wait_or_timeout(&mut process, duration)? {
+        return Err(uucore::spawn::exit_code(status).into());
+    }
+
+    report_if_verbose(signal, &cmd[0], verbose);
+    send_signal(&mut process, signal, foreground);
+
+    let status = match kill_after {
+        Some(kill_after) if signal != KILL => {
+            let Some(status) = wait_or_timeout(&mut process, kill_after)? else {
+                report_if_verbose(KILL, &cmd[0], verbose);
+                send_signal(&mut process, KILL, foreground);
+                process.wait()?;
+                return Err(ExitStatus::SignalSent(KILL).into());
+            };
+            status
+        }
+        _ => process.wait()?,
+    };
+    if signal == KILL {
+        Err(ExitStatus::SignalSent(KILL).into())
+    } else if !preserve_status {
+        Err(ExitStatus::CommandTimedOut.into())
+    } else if signal == 0 {
+        Err(uucore::spawn::exit_code(status).into())
+    } else {
+        Err(ExitStatus::SignalSent(signal).into())
+    }
+}
@@ -571,6 +571,24 @@ fn update_times(
     atime: FileTime,
     mtime: FileTime,
 ) -> UResult<()> {
+    // With -h, let the kernel leave the other timestamp alone (UTIME_OMIT)
+    // instead of reading it first: the link could change in between.
+    #[cfg(all(unix, not(target_os = "redox")))]
+    if opts.no_deref && !is_stdout {
+        // UTIME_OMIT is negative on some systems, so it can't go through a FileTime.
+        let omit = rustix::fs::Timespec {
+            tv_sec: 0,
+            tv_nsec: rustix::fs::UTIME_OMIT,
+        };
+        let mut timestamps = build_timestamps(atime, mtime);
+        match opts.change_times {
+            ChangeTimes::AtimeOnly => timestamps.last_modification = omit,
+            ChangeTimes::MtimeOnly => timestamps.last_access = omit,
+            ChangeTimes::Both => {}
+        }
+        return set_symlink_times_by_path(path, &timestamps);
+    }
+
     // If changing "only" atime or mtime, grab the existing value of the other.
     let (atime, mtime) = match opts.change_times {
         ChangeTimes::AtimeOnly => (
@@ -594,7 +612,7 @@ fn update_times(
 
     // sets the file access and modification times for a file or a symbolic link.
     // The filename, access time (atime), and modification time (mtime) are provided as inputs.
-
+    // Platforms without utimensat go through filetime, which falls back to lutimes.
     if opts.no_deref && !is_stdout {
         return set_symlink_file_times(path, atime, mtime).map_err_context(
             || translate!("touch-error-setting-times-of-path", "path" => path.quote()),
@@ -670,6 +688,20 @@ fn set_times_by_path(path: &Path, atime: FileTime, mtime: FileTime) -> UResult<(
     .map_err_context(|| translate!("touch-error-setting-times-of-path", "path" => path.quote()))
 }
 
+#[cfg(all(unix, not(target_os = "redox")))]
+/// Set the times of `path` itself, not of the file a symlink points to, using
+/// `utimensat(AT_SYMLINK_NOFOLLOW)`.
+fn set_symlink_times_by_path(path: &Path, timestamps: &Timestamps) -> UResult<()> {
+    rustix::fs::utimensat(
+        rustix::fs::CWD,
+        path,
+        timestamps,
+        rustix::fs::AtFlags::SYMLINK_NOFOLLOW,
+    )
+    .map_err(|e| Error::from_raw_os_error(e.raw_os_error()))
+    .map_err_context(|| translate!("touch-error-setting-times-of-path", "path" => path.quote()))
+}
+
 #[cfg(target_os = "redox")]
 /// Set file times by path on Redox, which lacks `rustix::fs::utimensat`.
 ///
@@ -732,13 +764,12 @@ fn stat(path: &Path, follow: bool) -> std::io::Result<(FileTime, FileTime)> {
 }
 
 fn parse_date(ref_zoned: Zoned, s: &str) -> Result<FileTime, TouchError> {
-    // This isn't actually compatible with GNU touch, but there doesn't seem to
-    // be any simple specification for what format this parameter allows and I'm
-    // not about to implement GNU parse_datetime.
+    // The fixed formats below are fast paths; everything else, including
+    // relative items like "2 days ago" or "next fri 09:00", goes through the
+    // GNU-compatible grammar of parse_datetime, anchored at `ref_zoned` so
+    // `-r FILE -d ADJUSTMENT` adjusts the reference file's times.
     // http://git.savannah.gnu.org/gitweb/?p=gnulib.git;a=blob_plain;f=lib/parse-datetime.y
 
-    // TODO: match on char count?
-
     // "The preferred date and time representation for the current locale."
     // "(In the POSIX locale this is equivalent to %a %b %e %H:%M:%S %Y.)"
     // time 0.1.43 parsed this as 'a b e T Y'
@@ -746,29 +777,33 @@ fn parse_date(ref_zoned: Zoned, s: &str) -> Result<FileTime, TouchError> {
     // Tue Dec  3 ...
     // ("%c", POSIX_LOCALE_FORMAT),
     //
-    if let Ok(parsed) = strtime::parse(format::POSIX_LOCALE, s)
-        .and_then(|tm| tm.to_datetime())
-        .and_then(|dt| TimeZone::UTC.to_zoned(dt))
-    {
-        return Ok(timestamp_to_filetime(parsed.timestamp()));
-    }
-
     // Also support other formats found in the GNU tests like
     // in tests/misc/stat-nanoseconds.sh
     // or tests/touch/no-rights.sh
+    //
+    // Like GNU, a date without a zone is a wall-clock time in the local
+    // time zone (TZ), not UTC.
     for fmt in [
+        format::POSIX_LOCALE,
         format::YYYYMMDDHHMMS,
         format::YYYYMMDDHHMMSS,
-        format::YYYYMMDDHHMM_OFFSET,
     ] {
-        if let Ok(parsed) = strtime::parse(fmt, s)
+        if let Ok(filetime) = strtime::parse(fmt, s)
             .and_then(|tm| tm.to_datetime())
-            .and_then(|dt| TimeZone::UTC.to_zoned(dt))
+            .and_then(|dt| TimeZone::system().to_ambiguous_zoned(dt).unambiguous())
+            .map(|zdt| timestamp_to_filetime(zdt.timestamp()))
         {
-            return Ok(timestamp_to_filetime(parsed.timestamp()));
+            return Ok(filetime);
         }
     }
 
+    // An explicit numeric offset (tests/touch/relative.sh) pins the instant
+    // regardless of TZ.
+    if let Ok(ts) = strtime::parse(format::YYYYMMDDHHMM_OFFSET, s).and_then(|tm| tm.to_timestamp())
+    {
+        return Ok(timestamp_to_filetime(ts));
+    }
+
     // "Equivalent to %Y-%m-%d (the ISO 8601 date format). (C99)"
     // ("%F", ISO_8601_FORMAT),
     if let Ok(filetime) = strtime::parse(format::ISO_8601, s)
@@ -63,11 +63,8 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         let stdin = std::io::stdin();
         let stdin_handle = stdin.as_handle();
         if stdin_handle.is_terminal() {
-            writeln!(
-                stdout,
-                "{}",
-                file_name(stdin_handle).as_deref().unwrap_or(r"\\.\CON")
-            )
+            let name = file_name(stdin_handle).map(|name| cygwin_pty_name(&name).unwrap_or(name));
+            writeln!(stdout, "{}", name.as_deref().unwrap_or(r"\\.\CON"))
         } else {
             set_exit_code(1);
             writeln!(stdout, "{}", translate!("tty-not-a-tty"))
@@ -121,6 +118,24 @@ fn file_name(handle: std::os::windows::io::BorrowedHandle) -> Option<String> {
     }
 }
 
+/// Cygwin and MSYS implement their ptys with named pipes such as
+/// `\msys-1888ae32e00d56aa-pty0-from-master`. Report these as `/dev/pty0`,
+/// the name their own `tty` prints.
+#[cfg(target_os = "windows")]
+fn cygwin_pty_name(pipe_name: &str) -> Option<String> {
+    let mut parts = pipe_name.strip_prefix('\\')?.split('-');
+    if !matches!(parts.next(), Some("cygwin" | "msys")) {
+        return None;
+    }
+    let _hash = parts.next()?;
+    let pty = parts.next()?;
+    let number = pty.strip_prefix("pty")?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    matches!(parts.next(), Some("from" | "to")).then(|| format!("/dev/{pty}"))
+}
+
 pub fn uu_app() -> Command {
     let cmd = Command::new("tty")
         .version(uucore::crate_version!())
@@ -136,3 +151,38 @@ pub fn uu_app() -> Command {
             .action(ArgAction::SetTrue),
     )
 }
+
+#[cfg(all(test, target_os = "windows"))]
+mod tests {
+    use super::cygwin_pty_name;
+
+    #[test]
+    fn test_cygwin_pty_name() {
+        for (pipe_name, expected) in [
+            (
+                r"\msys-1888ae32e00d56aa-pty0-from-master",
+                Some("/dev/pty0"),
+            ),
+            (
+                r"\cygwin-e022582115c10879-pty12-to-master",
+                Some("/dev/pty12"),
+            ),
+            // Not a pty pipe.
+            (r"\msys-1888ae32e00d56aa-sigwait", None),
+            (r"\other-1888ae32e00d56aa-pty0-from-master", None),
+            (r"msys-1888ae32e00d56aa-pty0-from-master", None),
+            // Malformed pty suffixes.
+            (r"\msys-1888ae32e00d56aa-pty-from-master", None),
+            (r"\msys-1888ae32e00d56aa-pty0x-from-master", None),
+            (r"\msys-1888ae32e00d56aa-pty0", None),
+            (r"\msys-1888ae32e00d56aa-pty0-echoloop", None),
+            (r"\msys-1888ae32e00d56aa", None),
+        ] {
+            assert_eq!(
+                cygwin_pty_name(pipe_name).as_deref(),
+                expected,
+                "{pipe_name}"
+            );
+        }
+    }
+}
@@ -84,15 +84,13 @@ impl UNameOutput {
 
         let os = (opts.os || opts.all).then(|| uname.osname().to_owned());
 
-        // This option is unsupported on modern Linux systems
+        // These options are unsupported on modern Linux systems, where they print "unknown"
         // See: https://lists.gnu.org/archive/html/bug-coreutils/2005-09/msg00063.html
-        let processor = opts.processor.then(|| translate!("uname-unknown").into());
+        // Like GNU, `--all` omits them unless their value is known.
+        let processor = unknown_if_none(processor_name(), opts.processor, opts.all);
 
-        // This option is unsupported on modern Linux systems
-        // See: https://lists.gnu.org/archive/html/bug-coreutils/2005-09/msg00063.html
-        let hardware_platform = opts
-            .hardware_platform
-            .then(|| translate!("uname-unknown").into());
+        let hardware_platform =
+            unknown_if_none(hardware_platform_name(), opts.hardware_platform, opts.all);
 
         Ok(Self {
             kernel_name,
@@ -107,6 +105,94 @@ impl UNameOutput {
     }
 }
 
+fn unknown_if_none(value: Option<OsString>, requested: bool, all: bool) -> Option<OsString> {
+    match value {
+        Some(value) if requested || all => Some(value),
+        None if requested => Some(translate!("uname-unknown").into()),
+        _ => None,
+    }
+}
+
+/// The processor type, as GNU reports it on macOS.
+#[cfg(target_vendor = "apple")]
+fn processor_name() -> Option<OsString> {
+    if cfg!(any(target_arch = "aarch64", target_arch = "arm")) {
+        Some("arm".into())
+    } else if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+        Some("i386".into())
+    } else if cfg!(any(target_arch = "powerpc", target_arch = "powerpc64")) {
+        Some("powerpc".into())
+    } else {
+        None
+    }
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "dragonfly"))]
+fn processor_name() -> Option<OsString> {
+    sysctl_string(c"hw.machine_arch")
+}
+
+#[cfg(not(any(
+    target_vendor = "apple",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+)))]
+fn processor_name() -> Option<OsString> {
+    None
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "dragonfly"))]
+fn hardware_platform_name() -> Option<OsString> {
+    sysctl_string(c"hw.model")
+}
+
+#[cfg(not(any(target_os = "freebsd", target_os = "netbsd", target_os = "dragonfly")))]
+fn hardware_platform_name() -> Option<OsString> {
+    None
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "dragonfly"))]
+fn sysctl_string(name: &std::ffi::CStr) -> Option<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+
+    let mut len: libc::size_t = 0;
+    // SAFETY: a null buffer asks for the size of the value.
+    let ret = unsafe {
+        libc::sysctlbyname(
+            name.as_ptr(),
+            std::ptr::null_mut(),
+            &raw mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if ret != 0 || len == 0 {
+        return None;
+    }
+
+    let mut buf = vec![0_u8; len];
+    // SAFETY: `len` is the size of `buf`.
+    let ret = unsafe {
+        libc::sysctlbyname(
+            name.as_ptr(),
+            buf.as_mut_ptr().cast(),
+            &raw mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if ret != 0 {
+        return None;
+    }
+
+    buf.truncate(len);
+    if let Some(nul) = buf.iter().position(|&b| b == 0) {
+        buf.truncate(nul);
+    }
+    (!buf.is_empty()).then(|| OsString::from_vec(buf))
+}
+
 pub struct Options {
     pub all: bool,
     pub kernel_name: bool,
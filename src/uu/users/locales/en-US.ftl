@@ -5,3 +5,5 @@ users-about-musl-warning =
     due to musl's stub implementation of utmpx functions.
 users-long-usage = Output who is currently logged in according to FILE.
     If FILE is not specified, use { $default_path }.  /var/log/wtmp as FILE is common.
+
+users-error-file-windows = { $file }: Windows has no file of login records
@@ -5,3 +5,5 @@ users-about-musl-warning =
     à cause de l'implémentation stub des fonctions utmpx de musl.
 users-long-usage = Afficher qui est actuellement connecté selon FICHIER.
     Si FICHIER n'est pas spécifié, utiliser { $default_path }. /var/log/wtmp comme FICHIER est courant.
+
+users-error-file-windows = { $file } : Windows n'a pas de fichier d'enregistrements de connexion
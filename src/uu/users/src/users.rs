@@ -11,13 +11,17 @@ use std::path::Path;
 
 use clap::builder::ValueParser;
 use clap::{Arg, Command};
+#[cfg(windows)]
+use uucore::display::Quotable;
 use uucore::error::UResult;
+#[cfg(windows)]
+use uucore::error::USimpleError;
 use uucore::format_usage;
 use uucore::translate;
 
 #[cfg(target_os = "openbsd")]
 use utmp_classic::{UtmpEntry, parse_from_path};
-#[cfg(not(target_os = "openbsd"))]
+#[cfg(not(any(target_os = "openbsd", windows)))]
 use uucore::utmpx::{self, Utmpx};
 
 #[cfg(target_os = "openbsd")]
@@ -25,6 +29,7 @@ const OPENBSD_UTMP_FILE: &str = "/var/run/utmp";
 
 static ARG_FILE: &str = "file";
 
+#[cfg(not(windows))]
 fn get_long_usage() -> String {
     #[cfg(not(target_os = "openbsd"))]
     let default_path: &str = utmpx::DEFAULT_FILE;
@@ -61,7 +66,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             }
         }
     };
-    #[cfg(not(target_os = "openbsd"))]
+    #[cfg(not(any(target_os = "openbsd", windows)))]
     {
         let filename = maybe_file.unwrap_or(utmpx::DEFAULT_FILE.as_ref());
 
@@ -70,6 +75,17 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             .map(|ut| ut.user())
             .collect::<Vec<_>>();
     };
+    // Windows has no utmp file, the users are those of the Remote Desktop Services sessions
+    #[cfg(windows)]
+    {
+        if let Some(file) = maybe_file {
+            return Err(USimpleError::new(
+                1,
+                translate!("users-error-file-windows", "file" => file.quote()),
+            ));
+        }
+        users = uucore::uptime::get_session_users();
+    };
 
     if !users.is_empty() {
         users.sort();
@@ -85,17 +101,19 @@ pub fn uu_app() -> Command {
     #[cfg(target_env = "musl")]
     let about = translate!("users-about") + &translate!("users-about-musl-warning");
 
-    Command::new("users")
+    let cmd = Command::new("users")
         .version(uucore::crate_version!())
         .help_template(uucore::localized_help_template("users"))
         .about(about)
         .override_usage(format_usage(&translate!("users-usage")))
-        .infer_long_args(true)
-        .after_help(get_long_usage())
-        .arg(
-            Arg::new(ARG_FILE)
-                .num_args(1)
-                .value_hint(clap::ValueHint::FilePath)
-                .value_parser(ValueParser::os_string()),
-        )
+        .infer_long_args(true);
+    #[cfg(not(windows))]
+    let cmd = cmd.after_help(get_long_usage());
+
+    cmd.arg(
+        Arg::new(ARG_FILE)
+            .num_args(1)
+            .value_hint(clap::ValueHint::FilePath)
+            .value_parser(ValueParser::os_string()),
+    )
 }
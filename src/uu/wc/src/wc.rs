@@ -13,7 +13,6 @@ mod word_count;
 use std::{
     borrow::{Borrow, Cow},
     cmp::max,
-    env,
     ffi::{OsStr, OsString},
     fs::{self, File},
     io::{self, Write, stderr},
@@ -33,6 +32,7 @@ use uucore::{
     format_usage,
     hardware::{HardwareFeature, HasHardwareFeatures as _, SimdPolicy},
     parser::shortcut_value_parser::ShortcutValueParser,
+    posix::posixly_correct,
     quoting_style::{self, QuotingStyle},
     show,
 };
@@ -1039,5 +1039,4 @@ fn print_stats(
     writeln!(stdout)
 }
 
-static IS_POSIXLY_CORRECT: LazyLock<bool> =
-    LazyLock::new(|| env::var_os("POSIXLY_CORRECT").is_some());
+static IS_POSIXLY_CORRECT: LazyLock<bool> = LazyLock::new(posixly_correct);
@@ -61,6 +61,7 @@ safe-traversal-error-stat-failed = failed to stat { $path }: { $source }
 safe-traversal-error-read-dir-failed = failed to read directory { $path }: { $source }
 safe-traversal-error-unlink-failed = failed to unlink { $path }: { $source }
 safe-traversal-error-invalid-fd = invalid file descriptor
+safe-traversal-error-directory-changed = directory { $path } changed during traversal
 safe-traversal-current-directory = <current directory>
 safe-traversal-directory = <directory>
 
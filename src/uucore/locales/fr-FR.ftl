@@ -55,6 +55,7 @@ safe-traversal-error-stat-failed = échec de l'analyse de { $path } : { $source
 safe-traversal-error-read-dir-failed = échec de la lecture du répertoire { $path } : { $source }
 safe-traversal-error-unlink-failed = échec de la suppression de { $path } : { $source }
 safe-traversal-error-invalid-fd = descripteur de fichier invalide
+safe-traversal-error-directory-changed = le répertoire { $path } a changé pendant le parcours
 safe-traversal-current-directory = <répertoire courant>
 safe-traversal-directory = <répertoire>
 
@@ -45,6 +45,8 @@ pub mod quoting_style;
 pub mod ranges;
 #[cfg(feature = "ringbuffer")]
 pub mod ringbuffer;
+#[cfg(feature = "sandbox")]
+pub mod sandbox;
 #[cfg(feature = "sum")]
 pub mod sum;
 #[cfg(feature = "time")]
@@ -104,5 +106,7 @@ pub mod systemd_logind;
 ))]
 pub mod utmpx;
 // ** windows-only
+#[cfg(all(windows, feature = "spawn"))]
+pub mod spawn;
 #[cfg(all(windows, feature = "wide"))]
 pub mod wide;
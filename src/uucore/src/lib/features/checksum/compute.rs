@@ -5,10 +5,15 @@
 
 // spell-checker:ignore bitlen
 
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs::File;
 use std::io;
+use std::num::NonZero;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
 
 use crate::checksum::{
     AlgoKind, ChecksumError, ReadingMode, SizedAlgoKind, digest_reader, escape_filename,
@@ -219,6 +224,130 @@ fn write_untagged_checksum(
     w.write_all(escaped_filename.as_bytes())
 }
 
+/// The outcome of digesting one input.
+///
+/// Inputs may be digested on worker threads, which can't translate messages,
+/// so errors are only turned into user-facing ones when they are reported.
+enum InputDigest {
+    /// The digest of the input and its size in bytes.
+    Done(DigestOutput, usize),
+    /// The input is a directory and is skipped.
+    IsDirectory,
+    /// The input couldn't be opened and is skipped.
+    OpenFailed(io::Error),
+    /// Reading the input failed, which aborts the computation.
+    ReadFailed(io::Error),
+}
+
+/// Compute the digest of the file `filename`, or of stdin for `-`.
+fn digest_input(filename: &OsStr, algo_kind: SizedAlgoKind) -> InputDigest {
+    let stdin_buf;
+    let file_buf;
+
+    // Handle the file input
+    let mut file = io::BufReader::with_capacity(
+        READ_BUFFER_SIZE,
+        if filename == "-" {
+            stdin_buf = io::stdin();
+            Box::new(stdin_buf) as Box<dyn io::Read>
+        } else {
+            let filepath = Path::new(filename);
+            if filepath.is_dir() {
+                return InputDigest::IsDirectory;
+            }
+            file_buf = match File::open(filepath) {
+                Ok(file) => {
+                    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+                    let _ = rustix::fs::fadvise(&file, 0, None, rustix::fs::Advice::Sequential);
+                    file
+                }
+                Err(err) => return InputDigest::OpenFailed(err),
+            };
+            Box::new(file_buf) as Box<dyn io::Read>
+        },
+    );
+
+    let mut digest = algo_kind.create_digest();
+
+    // Always compute the "binary" version of the digest, i.e. on Windows,
+    // never handle CRLFs specifically.
+    match digest_reader(&mut digest, &mut file, ReadingMode::Binary) {
+        Ok((digest_output, sz)) => InputDigest::Done(digest_output, sz),
+        Err(err) => InputDigest::ReadFailed(err),
+    }
+}
+
+/// Digest `files` and pass each outcome to `handle`, in the order of `files`.
+///
+/// Files are digested on a pool of worker threads so that hashing many files
+/// keeps every CPU busy, while stdin is read by the calling thread when its
+/// turn comes. If `handle` fails, the remaining files are not digested.
+fn digest_inputs<'a, F>(files: &[&'a OsStr], algo_kind: SizedAlgoKind, mut handle: F) -> UResult<()>
+where
+    F: FnMut(&'a OsStr, InputDigest) -> UResult<()>,
+{
+    let jobs = thread::available_parallelism()
+        .map_or(1, NonZero::get)
+        .min(files.iter().filter(|&&f| f != "-").count());
+    if jobs <= 1 {
+        for &filename in files {
+            handle(filename, digest_input(filename, algo_kind))?;
+        }
+        return Ok(());
+    }
+
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let (next, stop) = (&next, &stop);
+            scope.spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&filename) = files.get(index) else {
+                        break;
+                    };
+                    if filename == "-" {
+                        continue;
+                    }
+                    let digest = digest_input(filename, algo_kind);
+                    if sender.send((index, digest)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Outcomes arrive in any order, keep them until it is their turn.
+        let mut pending = HashMap::new();
+        for (index, &filename) in files.iter().enumerate() {
+            let digest = if filename == "-" {
+                digest_input(filename, algo_kind)
+            } else {
+                loop {
+                    if let Some(digest) = pending.remove(&index) {
+                        break digest;
+                    }
+                    // Every worker is gone, which can only happen if one of
+                    // them panicked: `thread::scope` re-raises that panic.
+                    let Ok((received, digest)) = receiver.recv() else {
+                        return Ok(());
+                    };
+                    pending.insert(received, digest);
+                }
+            };
+            if let Err(err) = handle(filename, digest) {
+                stop.store(true, Ordering::Relaxed);
+                return Err(err);
+            }
+        }
+        Ok(())
+    })
+}
+
 /// Calculate checksum
 ///
 /// # Arguments
@@ -234,57 +363,33 @@ where
     W: io::Write,
     I: Iterator<Item = &'a OsStr>,
 {
-    let mut files = files.peekable();
-
-    while let Some(filename) = files.next() {
-        // Check that in raw mode, we are not provided with several files.
-        if options.output_format.is_raw() && files.peek().is_some() {
-            return Err(Box::new(ChecksumError::RawMultipleFiles));
-        }
-
-        let stdin_buf;
-        let file_buf;
-
-        // Handle the file input
-        let mut file = io::BufReader::with_capacity(
-            READ_BUFFER_SIZE,
-            if filename == "-" {
-                stdin_buf = io::stdin();
-                Box::new(stdin_buf) as Box<dyn io::Read>
-            } else {
-                let filepath = Path::new(filename);
-                if filepath.is_dir() {
-                    show!(USimpleError::new(
-                        1,
-                        translate!("error-is-a-directory", "file" => filepath.display())
-                    ));
-                    continue;
-                }
-                file_buf = match File::open(filepath) {
-                    Ok(file) => {
-                        #[cfg(any(
-                            target_os = "linux",
-                            target_os = "android",
-                            target_os = "freebsd"
-                        ))]
-                        let _ = rustix::fs::fadvise(&file, 0, None, rustix::fs::Advice::Sequential);
-                        file
-                    }
-                    Err(err) => {
-                        show!(err.map_err_context(|| filepath.to_string_lossy().into()));
-                        continue;
-                    }
-                };
-                Box::new(file_buf) as Box<dyn io::Read>
-            },
-        );
+    let files: Vec<&OsStr> = files.collect();
 
-        let mut digest = options.algo_kind.create_digest();
+    // Check that in raw mode, we are not provided with several files.
+    if options.output_format.is_raw() && files.len() > 1 {
+        return Err(Box::new(ChecksumError::RawMultipleFiles));
+    }
 
-        // Always compute the "binary" version of the digest, i.e. on Windows,
-        // never handle CRLFs specifically.
-        let (digest_output, sz) = digest_reader(&mut digest, &mut file, ReadingMode::Binary)
-            .map_err_context(|| translate!("checksum-error-failed-to-read-input"))?;
+    digest_inputs(&files, options.algo_kind, |filename, digest| {
+        let (digest_output, sz) = match digest {
+            InputDigest::Done(digest_output, sz) => (digest_output, sz),
+            InputDigest::IsDirectory => {
+                show!(USimpleError::new(
+                    1,
+                    translate!("error-is-a-directory", "file" => Path::new(filename).display())
+                ));
+                return Ok(());
+            }
+            InputDigest::OpenFailed(err) => {
+                show!(err.map_err_context(|| filename.to_string_lossy().into()));
+                return Ok(());
+            }
+            InputDigest::ReadFailed(err) => {
+                return Err(
+                    err.map_err_context(|| translate!("checksum-error-failed-to-read-input"))
+                );
+            }
+        };
 
         // Encodes the sum if df is Base64, leaves as-is otherwise.
         let encode_sum = |sum: DigestOutput, df: DigestFormat| {
@@ -325,7 +430,8 @@ where
         }
 
         write!(w, "{}", options.line_ending).map_err(ChecksumError::Write)?;
-    }
+        Ok(())
+    })?;
 
     if options.line_ending != LineEnding::Newline {
         w.flush().map_err(ChecksumError::Write)?;
@@ -470,6 +470,8 @@ pub enum ChecksumError {
     TextWithoutUntagged,
     #[error("the --tag option is meaningless when verifying checksums")]
     TagCheck,
+    #[error("the --zero option is not supported when verifying checksums")]
+    ZeroCheck,
     #[error("--tag does not support --text mode")]
     TextAfterTag,
     #[error("--check is not supported with --algorithm={{bsd,sysv,crc,crc32b}}")]
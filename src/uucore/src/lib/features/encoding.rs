@@ -445,6 +445,20 @@ impl SupportsFastDecodeAndEncode for Z85Wrapper {
         5
     }
 
+    fn pad_remainder(&self, remainder: &[u8]) -> Option<PadResult> {
+        // The z85 crate also decodes a padded variant whose last group is shorter than 5
+        // characters. Like GNU, decode the whole groups and reject the rest.
+        let len = remainder.len() - remainder.len() % self.valid_decoding_multiple();
+        if len == remainder.len() {
+            return None;
+        }
+
+        Some(PadResult {
+            chunk: remainder[..len].to_vec(),
+            had_invalid_tail: true,
+        })
+    }
+
     fn encode_to_vec_deque(&self, input: &[u8], output: &mut VecDeque<u8>) -> UResult<()> {
         // According to the spec we should not accept inputs whose len is not a multiple of 4.
         // However, the z85 crate implements a padded encoding and accepts such inputs. We have to manually check for them.
@@ -42,7 +42,10 @@ use crate::extendedbigdecimal::ExtendedBigDecimal;
 pub use argument::{FormatArgument, FormatArguments};
 
 use self::{escape::parse_escape_code, num_format::Formatter};
-use crate::{NonUtf8OsStrError, error::UError};
+use crate::{
+    NonUtf8OsStrError,
+    error::{UError, USimpleError},
+};
 pub use spec::Spec;
 use std::{
     error::Error,
@@ -56,7 +59,12 @@ use os_display::Quotable;
 
 #[derive(Debug)]
 pub enum FormatError {
-    SpecError(Vec<u8>),
+    /// An invalid `%` directive. `spec` holds the directive without its
+    /// leading `%`.
+    SpecError {
+        spec: Vec<u8>,
+        location: SpecLocation,
+    },
     IoError(std::io::Error),
     NoMoreArguments,
     InvalidArgument(FormatArgument),
@@ -74,6 +82,127 @@ pub enum FormatError {
     InvalidEncoding(NonUtf8OsStrError),
 }
 
+/// Position of an invalid directive within the format string it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecLocation {
+    /// The complete format string.
+    pub format: Vec<u8>,
+    /// Byte offset of the directive's `%` within `format`.
+    pub offset: usize,
+}
+
+impl SpecLocation {
+    /// Render the format string with a caret underline below the directive
+    /// of `len` bytes, e.g.
+    ///
+    /// ```text
+    ///   a %7b c
+    ///     ^^^ column 3
+    /// ```
+    ///
+    /// Control characters are shown escaped so the caret stays aligned.
+    pub fn underline(&self, len: usize) -> String {
+        fn push_escaped(line: &mut String, bytes: &[u8]) -> usize {
+            for c in String::from_utf8_lossy(bytes).chars() {
+                if c.is_control() {
+                    line.extend(c.escape_default());
+                } else {
+                    line.push(c);
+                }
+            }
+            line.chars().count()
+        }
+
+        let end = (self.offset + len).min(self.format.len());
+        let mut line = String::new();
+        let start = push_escaped(&mut line, &self.format[..self.offset]);
+        let stop = push_escaped(&mut line, &self.format[self.offset..end]);
+        push_escaped(&mut line, &self.format[end..]);
+        let column = String::from_utf8_lossy(&self.format[..self.offset])
+            .chars()
+            .count()
+            + 1;
+        format!(
+            "  {line}\n  {}{} column {column}",
+            " ".repeat(start),
+            "^".repeat((stop - start).max(1))
+        )
+    }
+}
+
+/// Suggest a valid directive close to the invalid one, `spec` being the
+/// directive without its leading `%`.
+///
+/// A known conversion used with modifiers it does not accept (`%7b`, `%#s`)
+/// is reduced to the bare conversion; an unknown conversion letter keeps its
+/// modifiers and gets the closest C conversion (`%D` -> `%d`, `%v` -> `%s`).
+pub fn suggest_directive(spec: &[u8]) -> Option<String> {
+    const CONVERSIONS: &[u8] = b"diouxXeEfFgGaAcsbq";
+    let (&conversion, modifiers) = spec.split_last()?;
+    if CONVERSIONS.contains(&conversion) {
+        return (!modifiers.is_empty()).then(|| format!("%{}", conversion as char));
+    }
+    let replacement = match conversion {
+        b'D' | b'I' => 'd',
+        b'U' => 'u',
+        b'O' => 'o',
+        b'S' | b'v' | b'r' | b'p' => 's',
+        b'C' => 'c',
+        b'B' => 'b',
+        b'Q' => 'q',
+        // A length modifier without a conversion, e.g. `%l` or `%ll`.
+        b'h' | b'l' | b'j' | b'z' | b't' => 'd',
+        b'L' => 'f',
+        _ => return None,
+    };
+    let modifiers = if matches!(conversion, b'h' | b'l' | b'j' | b'z' | b't' | b'L') {
+        spec
+    } else {
+        modifiers
+    };
+    // Only suggest something the parser will actually accept.
+    let mut candidate = modifiers.to_vec();
+    candidate.push(replacement as u8);
+    let mut rest = candidate.as_slice();
+    match Spec::parse(&mut rest) {
+        Ok(_) if rest.is_empty() => Some(format!("%{}", String::from_utf8_lossy(&candidate))),
+        _ => Some(format!("%{replacement}")),
+    }
+}
+
+/// Whether to append the location and suggestion to a spec error. The extra
+/// lines are only meant for people reading a terminal; scripts comparing
+/// stderr keep seeing the exact GNU message.
+pub fn show_spec_context() -> bool {
+    use std::io::IsTerminal;
+    std::io::stderr().is_terminal()
+}
+
+impl FormatError {
+    /// The location and suggestion lines for an invalid directive, if any.
+    pub fn spec_context(&self) -> Option<String> {
+        let Self::SpecError { spec, location } = self else {
+            return None;
+        };
+        let underline = location.underline(spec.len() + 1);
+        Some(match suggest_directive(spec) {
+            Some(suggestion) => format!("{underline}\n  did you mean '{suggestion}'?"),
+            None => underline,
+        })
+    }
+
+    /// Turn this error into the one to report, with the [`spec_context`](Self::spec_context)
+    /// appended when [`show_spec_context`] allows it.
+    pub fn with_spec_context(self) -> Box<dyn UError> {
+        match self.spec_context() {
+            Some(context) if show_spec_context() => {
+                USimpleError::new(self.code(), format!("{self}\n{context}"))
+            }
+            _ => self.into(),
+        }
+    }
+}
+
 impl Error for FormatError {}
 impl UError for FormatError {}
 
@@ -92,10 +221,10 @@ impl From<NonUtf8OsStrError> for FormatError {
 impl Display for FormatError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::SpecError(s) => write!(
+            Self::SpecError { spec, .. } => write!(
                 f,
                 "%{}: invalid conversion specification",
-                String::from_utf8_lossy(s)
+                String::from_utf8_lossy(spec)
             ),
             Self::TooManySpecs(s) => write!(
                 f,
@@ -209,6 +338,16 @@ impl<C: FormatChar> FormatItem<C> {
     }
 }
 
+fn spec_error(fmt: &[u8], offset: usize, spec: &[u8]) -> FormatError {
+    FormatError::SpecError {
+        spec: spec.to_vec(),
+        location: SpecLocation {
+            format: fmt.to_vec(),
+            offset,
+        },
+    }
+}
+
 /// Parse a format string containing % directives and escape sequences
 pub fn parse_spec_and_escape(
     fmt: &[u8],
@@ -221,10 +360,11 @@ pub fn parse_spec_and_escape(
             Some(Ok(FormatItem::Char(EscapedChar::Byte(b'%'))))
         }
         [b'%', rest @ ..] => {
+            let offset = fmt.len() - current.len();
             current = rest;
             let spec = match Spec::parse(&mut current) {
                 Ok(spec) => spec,
-                Err(slice) => return Some(Err(FormatError::SpecError(slice.to_vec()))),
+                Err(slice) => return Some(Err(spec_error(fmt, offset, slice))),
             };
             Some(Ok(FormatItem::Spec(spec)))
         }
@@ -252,10 +392,11 @@ pub fn parse_spec_only(
             Some(Ok(FormatItem::Char(b'%')))
         }
         [b'%', rest @ ..] => {
+            let offset = fmt.len() - current.len();
             current = rest;
             let spec = match Spec::parse(&mut current) {
                 Ok(spec) => spec,
-                Err(slice) => return Some(Err(FormatError::SpecError(slice.to_vec()))),
+                Err(slice) => return Some(Err(spec_error(fmt, offset, slice))),
             };
             Some(Ok(FormatItem::Spec(spec)))
         }
@@ -425,7 +566,7 @@ impl<F: Formatter<T>, T> Format<F, T> {
 
 #[cfg(test)]
 mod tests {
-    use super::{FormatError, check_precision};
+    use super::{FormatError, check_precision, parse_spec_only, suggest_directive};
 
     #[test]
     fn check_precision_caps_at_i32_max() {
@@ -447,4 +588,45 @@ mod tests {
             Err(FormatError::InvalidPrecision(_))
         ));
     }
+
+    fn first_spec_error(format: &[u8]) -> FormatError {
+        parse_spec_only(format)
+            .find_map(Result::err)
+            .expect("format should be invalid")
+    }
+
+    #[test]
+    fn spec_error_points_at_directive() {
+        let err = first_spec_error(b"a %d %7b c");
+        assert_eq!(
+            err.spec_context().unwrap(),
+            "  a %d %7b c\n       ^^^ column 6\n  did you mean '%b'?"
+        );
+    }
+
+    #[test]
+    fn spec_error_escapes_control_characters() {
+        let err = first_spec_error(b"\t%y");
+        assert_eq!(err.spec_context().unwrap(), "  \\t%y\n    ^^ column 2");
+    }
+
+    #[test]
+    fn spec_error_message_is_unchanged() {
+        // The message always matches GNU; the context is only added by
+        // `with_spec_context` when reporting to a terminal.
+        let err = first_spec_error(b"%7b");
+        assert_eq!(err.to_string(), "%7b: invalid conversion specification");
+    }
+
+    #[test]
+    fn suggestions() {
+        assert_eq!(suggest_directive(b"7b").as_deref(), Some("%b"));
+        assert_eq!(suggest_directive(b"#s").as_deref(), Some("%s"));
+        assert_eq!(suggest_directive(b"-5D").as_deref(), Some("%-5d"));
+        assert_eq!(suggest_directive(b"v").as_deref(), Some("%s"));
+        assert_eq!(suggest_directive(b"ll").as_deref(), Some("%lld"));
+        assert_eq!(suggest_directive(b"d"), None);
+        assert_eq!(suggest_directive(b"y"), None);
+        assert_eq!(suggest_directive(b""), None);
+    }
 }
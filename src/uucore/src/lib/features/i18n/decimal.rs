@@ -71,6 +71,59 @@ pub fn locale_grouping_separator() -> &'static str {
     })
 }
 
+/// Insert the grouping separator of the locale between every group of three
+/// digits in the integer part of the number `s`.
+///
+/// `s` is returned as is when the locale has no grouping separator, like the
+/// C locale.
+pub fn apply_grouping(s: &str) -> String {
+    let grouping_separator = locale_grouping_separator();
+    if grouping_separator.is_empty() {
+        return s.to_string();
+    }
+
+    let (sign, rest) = if let Some(rest) = s.strip_prefix('-') {
+        ("-", rest)
+    } else {
+        ("", s)
+    };
+    let dec_sep = locale_decimal_separator();
+    let (integer, fraction) = rest.split_once(dec_sep).map_or((rest, ""), |(i, f)| (i, f));
+    if integer.len() < 4 {
+        return s.to_string();
+    }
+
+    let sep_len = grouping_separator.len();
+    let num_seps = (integer.len() - 1) / 3;
+    let mut grouped = String::with_capacity(
+        sign.len()
+            + integer.len()
+            + num_seps * sep_len
+            + if fraction.is_empty() {
+                0
+            } else {
+                1 + fraction.len()
+            },
+    );
+    grouped.push_str(sign);
+
+    let first_group = integer.len() % 3;
+    let first_group = if first_group == 0 { 3 } else { first_group };
+    grouped.push_str(&integer[..first_group]);
+    for chunk in integer.as_bytes()[first_group..].chunks(3) {
+        grouped.push_str(grouping_separator);
+        // SAFETY: integer is known to be valid UTF-8 ASCII digits
+        grouped.push_str(std::str::from_utf8(chunk).unwrap());
+    }
+
+    if !fraction.is_empty() {
+        grouped.push_str(dec_sep);
+        grouped.push_str(fraction);
+    }
+
+    grouped
+}
+
 #[cfg(test)]
 mod tests {
     use icu_locale::locale;
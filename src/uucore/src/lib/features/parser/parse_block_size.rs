@@ -53,7 +53,7 @@ impl BlockSizeEnv {
 pub fn block_size_from_env(vars: &[&str]) -> BlockSizeEnv {
     for var in vars {
         if let Ok(s) = std::env::var(var) {
-            return match parse_size_non_zero_u64(&s) {
+            return match parse_size_non_zero_u64(split_grouping(&s).0) {
                 Ok(n) => BlockSizeEnv::Found(n),
                 Err(_) => BlockSizeEnv::SetButInvalid,
             };
@@ -62,11 +62,31 @@ pub fn block_size_from_env(vars: &[&str]) -> BlockSizeEnv {
     BlockSizeEnv::NotSet
 }
 
+/// Split the leading `'` off a block size, which asks for the sizes to be
+/// printed with the thousands separator of the locale.
+///
+/// Returns the block size without it, and whether it was there.
+pub fn split_grouping(s: &str) -> (&str, bool) {
+    match s.strip_prefix('\'') {
+        Some(rest) => (rest, true),
+        None => (s, false),
+    }
+}
+
+/// Whether the block size taken from the given environment variables asks
+/// for thousands separators, see [`block_size_from_env`] and
+/// [`split_grouping`].
+pub fn grouping_from_env(vars: &[&str]) -> bool {
+    vars.iter()
+        .find_map(|var| std::env::var(var).ok())
+        .is_some_and(|s| split_grouping(&s).1)
+}
+
 /// Default block size when no env var or flag is set.
 ///
 /// Returns 512 if `POSIXLY_CORRECT` is set, 1024 otherwise.
 pub fn default_block_size() -> u64 {
-    if std::env::var("POSIXLY_CORRECT").is_ok() {
+    if crate::posix::posixly_correct() {
         512
     } else {
         1024
@@ -345,6 +365,28 @@ mod tests {
         clear_env_vars(&["BLOCKSIZE"]);
     }
 
+    #[test]
+    fn test_block_size_from_env_grouping() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        clear_env_vars(&["BLOCK_SIZE"]);
+
+        set_env_var("BLOCK_SIZE", "'1K");
+        assert_eq!(
+            block_size_from_env(&["BLOCK_SIZE"]),
+            BlockSizeEnv::Found(1024)
+        );
+        assert!(grouping_from_env(&["BLOCK_SIZE"]));
+        set_env_var("BLOCK_SIZE", "'");
+        assert_eq!(
+            block_size_from_env(&["BLOCK_SIZE"]),
+            BlockSizeEnv::SetButInvalid
+        );
+        set_env_var("BLOCK_SIZE", "1K");
+        assert!(!grouping_from_env(&["BLOCK_SIZE"]));
+
+        clear_env_vars(&["BLOCK_SIZE"]);
+    }
+
     #[test]
     fn test_default_block_size_without_posixly_correct() {
         let _guard = ENV_LOCK.lock().unwrap();
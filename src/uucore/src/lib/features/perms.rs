@@ -257,20 +257,23 @@ fn is_root(path: &Path, would_traverse_symlink: bool) -> bool {
     // FIXME: TOCTOU bug! canonicalize() runs at a different time than WalkDir's recursion decision.
     // However, we're forced to make the decision whether to warn about --preserve-root
     // *before* even attempting to chown the path, let alone doing the stat inside WalkDir.
-    if let Ok(p) = path.canonicalize() {
-        let path_buf = path.to_path_buf();
-        if p.parent().is_none() {
-            if path_buf.as_os_str() == "/" {
-                show_error!("it is dangerous to operate recursively on '/'");
-            } else {
-                show_error!(
-                    "it is dangerous to operate recursively on {} (same as '/')",
-                    path_buf.quote()
-                );
-            }
-            show_error!("use --no-preserve-root to override this failsafe");
-            return true;
+    // A bind mount of '/' doesn't canonicalize to '/', so compare the device and inode as well.
+    let same_as_root = path.canonicalize().is_ok_and(|p| p.parent().is_none())
+        || matches!(
+            (path.metadata(), Path::new("/").metadata()),
+            (Ok(meta), Ok(root)) if meta.dev() == root.dev() && meta.ino() == root.ino()
+        );
+    if same_as_root {
+        if path.as_os_str() == "/" {
+            show_error!("it is dangerous to operate recursively on '/'");
+        } else {
+            show_error!(
+                "it is dangerous to operate recursively on {} (same as '/')",
+                path.quote()
+            );
         }
+        show_error!("use --no-preserve-root to override this failsafe");
+        return true;
     }
 
     false
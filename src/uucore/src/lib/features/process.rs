@@ -10,7 +10,6 @@
 use libc::{gid_t, pid_t, uid_t};
 #[cfg(not(target_os = "redox"))]
 use nix::errno::Errno;
-use nix::sys::signal::{self as nix_signal, SigHandler, Signal};
 use nix::unistd::Pid;
 use std::io;
 use std::process::Child;
@@ -75,6 +74,47 @@ pub fn getsid(pid: i32) -> Result<pid_t, Errno> {
     nix::unistd::getsid(pid).map(Pid::as_raw)
 }
 
+/// Return the descendants of the process `pid`, each parent before its children.
+///
+/// The tree is read from `/proc/<pid>/task/<tid>/children`, so a process whose parent
+/// exited only shows up under the subreaper it was reparented to.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn descendants(pid: pid_t) -> Vec<pid_t> {
+    let mut found = Vec::new();
+    let mut pending = vec![pid];
+    while let Some(parent) = pending.pop() {
+        let Ok(tasks) = std::fs::read_dir(format!("/proc/{parent}/task")) else {
+            continue;
+        };
+        for task in tasks.flatten() {
+            let Ok(children) = std::fs::read_to_string(task.path().join("children")) else {
+                continue;
+            };
+            for child in children.split_whitespace().filter_map(|c| c.parse().ok()) {
+                found.push(child);
+                pending.push(child);
+            }
+        }
+    }
+    found
+}
+
+/// Send a signal to every descendant of the calling process.
+///
+/// Unlike signaling the process group, this also reaches processes that moved to
+/// another group or session, and it works for `SIGKILL` without killing the caller.
+/// Processes that exit in the meantime are skipped.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn signal_descendants(signal: usize) -> io::Result<()> {
+    for pid in descendants(getpid()) {
+        match kill_raw(pid, signal) {
+            Err(e) if e.raw_os_error() != Some(libc::ESRCH) => return Err(e),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 /// Missing methods for Child objects
 pub trait ChildExt {
     /// Send a signal to a Child process.
@@ -97,15 +137,7 @@ pub trait ChildExt {
 
 impl ChildExt for Child {
     fn send_signal(&mut self, signal: usize) -> io::Result<()> {
-        let pid = Pid::from_raw(self.id() as pid_t);
-        let result = if signal == 0 {
-            nix_signal::kill(pid, None)
-        } else {
-            let signal = Signal::try_from(signal as i32)
-                .map_err(|_| io::Error::from_raw_os_error(libc::EINVAL))?;
-            nix_signal::kill(pid, Some(signal))
-        };
-        result.map_err(|e| io::Error::from_raw_os_error(e as i32))
+        kill_raw(self.id() as pid_t, signal)
     }
 
     fn send_signal_group(&mut self, signal: usize) -> io::Result<()> {
@@ -118,20 +150,20 @@ impl ChildExt for Child {
         // Signal 0 is special - it just checks if process exists, doesn't send anything.
         // No need to manipulate signal handlers for it.
         if signal == 0 {
-            return nix_signal::kill(Pid::from_raw(0), None)
-                .map_err(|e| io::Error::from_raw_os_error(e as i32));
+            return kill_raw(0, 0);
         }
 
-        let signal = Signal::try_from(signal as i32)
-            .map_err(|_| io::Error::from_raw_os_error(libc::EINVAL))?;
+        let raw = signal_to_raw(signal)?;
 
         // Ignore the signal temporarily so we don't receive it ourselves.
-        let old_handler = unsafe { nix_signal::signal(signal, SigHandler::SigIgn) }
-            .map_err(|e| io::Error::from_raw_os_error(e as i32))?;
-        let result = nix_signal::kill(Pid::from_raw(0), Some(signal));
+        let old_handler = unsafe { libc::signal(raw, libc::SIG_IGN) };
+        if old_handler == libc::SIG_ERR {
+            return Err(io::Error::last_os_error());
+        }
+        let result = kill_raw(0, signal);
         // Restore the old handler
-        let _ = unsafe { nix_signal::signal(signal, old_handler) };
-        result.map_err(|e| io::Error::from_raw_os_error(e as i32))
+        unsafe { libc::signal(raw, old_handler) };
+        result
     }
 
     fn wait_or_timeout(
@@ -167,6 +199,19 @@ impl ChildExt for Child {
     }
 }
 
+fn signal_to_raw(signal: usize) -> io::Result<libc::c_int> {
+    libc::c_int::try_from(signal).map_err(|_| io::Error::from_raw_os_error(libc::EINVAL))
+}
+
+/// Send a signal by its number, which unlike nix's `Signal` also covers the realtime signals.
+fn kill_raw(pid: pid_t, signal: usize) -> io::Result<()> {
+    if unsafe { libc::kill(pid, signal_to_raw(signal)?) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -187,4 +232,36 @@ mod tests {
         // This might caused tests failure but the probability is low.
         assert!(getsid(999_999).is_err());
     }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn test_descendants() {
+        use super::{descendants, getpid, kill_raw};
+        use std::process::Command;
+        use std::time::{Duration, Instant};
+
+        let mut child = Command::new("sh")
+            .args(["-c", "sleep 10 & wait"])
+            .spawn()
+            .unwrap();
+        let sh = child.id() as i32;
+
+        // The shell may not have forked yet, so wait for its child to show up.
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut children = descendants(sh);
+        while children.is_empty() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+            children = descendants(sh);
+        }
+        assert_eq!(children.len(), 1);
+
+        let found = descendants(getpid());
+        let position = found.iter().position(|&pid| pid == sh).unwrap();
+        // The shell's own child is listed after it.
+        assert!(children.iter().all(|pid| found[position..].contains(pid)));
+
+        // Killing the sleep lets the shell's `wait` return, so it reaps it and exits.
+        kill_raw(children[0], libc::SIGKILL as usize).unwrap();
+        child.wait().unwrap();
+    }
 }
@@ -920,6 +920,325 @@ impl MetadataExt for Metadata {
     }
 }
 
+/// Number of directory descriptors a [`Walker`] keeps open when the open file
+/// limit allows it.
+pub const DEFAULT_MAX_OPEN_DIRS: usize = 64;
+
+/// The number of directory descriptors a traversal may keep open, derived
+/// from the soft `RLIMIT_NOFILE` so that deep trees do not fail with `EMFILE`
+/// under a low `ulimit -n`.
+///
+/// Half of the limit is left for standard streams, files being read and the
+/// descriptors [`Walker`] needs transiently.
+pub fn default_max_open_dirs() -> usize {
+    let mut limit = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+    // SAFETY: `limit` is a valid, writable rlimit.
+    if unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &raw mut limit) } != 0
+        || limit.rlim_cur == libc::RLIM_INFINITY
+    {
+        return DEFAULT_MAX_OPEN_DIRS;
+    }
+    let half = usize::try_from(limit.rlim_cur / 2).unwrap_or(usize::MAX);
+    half.saturating_sub(4).clamp(1, DEFAULT_MAX_OPEN_DIRS)
+}
+
+/// Why a [`Walker`] could not report an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkErrorKind {
+    /// The entry could not be stat'ed.
+    Stat,
+    /// The directory could not be opened or read. A [`WalkEvent::PostDir`]
+    /// for it still follows.
+    ReadDir,
+}
+
+/// An event reported by [`Walker`], in the order `fts(3)` would report it.
+///
+/// Depths are relative to the root, which has depth 0.
+#[derive(Debug)]
+pub enum WalkEvent {
+    /// A directory, before its contents. Call [`Walker::prune`] before the next
+    /// call to [`Walker::next`] to not descend into it.
+    PreDir {
+        path: PathBuf,
+        stat: FileStat,
+        depth: usize,
+    },
+    /// Any entry that is not a directory. Symlinks are never followed.
+    Entry {
+        path: PathBuf,
+        stat: FileStat,
+        depth: usize,
+    },
+    /// A directory, after all its contents.
+    PostDir { path: PathBuf, depth: usize },
+    /// A directory that is one of its own ancestors, e.g. through a bind
+    /// mount. It is not descended into.
+    Cycle { path: PathBuf, depth: usize },
+    /// An entry that could not be examined.
+    Error {
+        path: PathBuf,
+        error: io::Error,
+        kind: WalkErrorKind,
+        depth: usize,
+    },
+}
+
+/// A directory being traversed.
+struct WalkLevel {
+    name: OsString,
+    path: PathBuf,
+    id: FileInfo,
+    /// `None` once closed to stay within the descriptor budget; reopened
+    /// from the nearest open ancestor when needed again.
+    fd: Option<DirFd>,
+    entries: std::vec::IntoIter<OsString>,
+}
+
+/// A directory reported by [`WalkEvent::PreDir`] and not yet descended into.
+struct PendingDir {
+    name: OsString,
+    path: PathBuf,
+    id: FileInfo,
+    skip: bool,
+}
+
+/// An iterative, `fts`-like traversal of a directory tree using `openat`.
+///
+/// Unlike a recursive traversal that keeps one descriptor open per level,
+/// the walker closes the descriptors of the shallowest directories once more
+/// than `max_open` are open, and reopens them relative to their closest open
+/// ancestor when it gets back to them, checking that each one is still the
+/// directory it left. The depth of the tree is therefore not bounded by the
+/// open file limit.
+pub struct Walker {
+    root: PathBuf,
+    stack: Vec<WalkLevel>,
+    pending: Option<PendingDir>,
+    queued: Option<WalkEvent>,
+    max_open: usize,
+    open: usize,
+    /// Every level below this index is known to be closed.
+    first_open: usize,
+}
+
+impl Walker {
+    /// Start a traversal of the directory open as `root_fd`, known as `root`.
+    ///
+    /// `root` is only used to build the reported paths and, if all the
+    /// descriptors had to be closed, to reopen the root.
+    pub fn new(root_fd: DirFd, root: &Path, max_open: usize) -> io::Result<Self> {
+        let id = FileInfo::from_stat(&root_fd.fstat()?);
+        let entries = root_fd.read_dir()?.into_iter();
+        Ok(Self {
+            root: root.to_path_buf(),
+            stack: vec![WalkLevel {
+                name: root.as_os_str().to_os_string(),
+                path: root.to_path_buf(),
+                id,
+                fd: Some(root_fd),
+                entries,
+            }],
+            pending: None,
+            queued: None,
+            max_open: max_open.max(1),
+            open: 1,
+            first_open: 0,
+        })
+    }
+
+    /// Do not descend into the directory just reported by
+    /// [`WalkEvent::PreDir`].
+    pub fn prune(&mut self) {
+        if let Some(pending) = &mut self.pending {
+            pending.skip = true;
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Close the descriptors of the shallowest levels until at most
+    /// `max_open` are open. The deepest level is never closed.
+    fn close_excess(&mut self) {
+        let top = self.stack.len() - 1;
+        while self.open > self.max_open {
+            let Some(i) = (self.first_open..top).find(|&i| self.stack[i].fd.is_some()) else {
+                break;
+            };
+            self.stack[i].fd = None;
+            self.open -= 1;
+            self.first_open = i + 1;
+        }
+    }
+
+    /// Make sure the deepest level has an open descriptor.
+    fn reopen_top(&mut self) -> io::Result<()> {
+        let top = self.stack.len() - 1;
+        if self.stack[top].fd.is_some() {
+            return Ok(());
+        }
+        // Walk down from the closest open ancestor, or from the root path if
+        // none is left, checking each directory's identity on the way.
+        let (mut current, next) =
+            if let Some(i) = (0..top).rev().find(|&i| self.stack[i].fd.is_some()) {
+                let level = &self.stack[i + 1];
+                let dir = self.stack[i]
+                    .fd
+                    .as_ref()
+                    .expect("level has an open descriptor")
+                    .open_subdir(&level.name, SymlinkBehavior::NoFollow)?;
+                Self::check_identity(&dir, level)?;
+                (dir, i + 2)
+            } else {
+                let dir = DirFd::open(&self.root, SymlinkBehavior::Follow)?;
+                Self::check_identity(&dir, &self.stack[0])?;
+                (dir, 1)
+            };
+        for level in &self.stack[next..=top] {
+            let dir = current.open_subdir(&level.name, SymlinkBehavior::NoFollow)?;
+            Self::check_identity(&dir, level)?;
+            current = dir;
+        }
+        self.stack[top].fd = Some(current);
+        self.open += 1;
+        self.first_open = self.first_open.min(top);
+        self.close_excess();
+        Ok(())
+    }
+
+    fn check_identity(dir: &DirFd, level: &WalkLevel) -> io::Result<()> {
+        if FileInfo::from_stat(&dir.fstat()?) == level.id {
+            Ok(())
+        } else {
+            Err(io::Error::other(
+                translate!("safe-traversal-error-directory-changed", "path" => level.path.quote()),
+            ))
+        }
+    }
+
+    /// Descend into `pending`, or report why it could not be read.
+    fn descend(&mut self, pending: PendingDir) -> Option<WalkEvent> {
+        let depth = self.depth();
+        let opened = self
+            .reopen_top()
+            .and_then(|()| {
+                self.stack
+                    .last()
+                    .and_then(|level| level.fd.as_ref())
+                    .expect("top level is open")
+                    .open_subdir(&pending.name, SymlinkBehavior::NoFollow)
+            })
+            .and_then(|dir| {
+                let entries = dir.read_dir()?;
+                Ok((dir, entries))
+            });
+        match opened {
+            Ok((dir, entries)) => {
+                self.stack.push(WalkLevel {
+                    name: pending.name,
+                    path: pending.path,
+                    id: pending.id,
+                    fd: Some(dir),
+                    entries: entries.into_iter(),
+                });
+                self.open += 1;
+                self.close_excess();
+                None
+            }
+            Err(error) => {
+                self.queued = Some(WalkEvent::PostDir {
+                    path: pending.path.clone(),
+                    depth,
+                });
+                Some(WalkEvent::Error {
+                    path: pending.path,
+                    error,
+                    kind: WalkErrorKind::ReadDir,
+                    depth,
+                })
+            }
+        }
+    }
+
+    /// Leave the deepest directory.
+    fn pop(&mut self) -> WalkEvent {
+        let level = self.stack.pop().expect("stack is not empty");
+        if level.fd.is_some() {
+            self.open -= 1;
+        }
+        self.first_open = self.first_open.min(self.stack.len());
+        WalkEvent::PostDir {
+            path: level.path,
+            depth: self.stack.len(),
+        }
+    }
+}
+
+impl Iterator for Walker {
+    type Item = WalkEvent;
+
+    fn next(&mut self) -> Option<WalkEvent> {
+        if let Some(event) = self.queued.take() {
+            return Some(event);
+        }
+        if let Some(pending) = self.pending.take()
+            && !pending.skip
+            && let Some(event) = self.descend(pending)
+        {
+            return Some(event);
+        }
+
+        let depth = self.depth();
+        let level = self.stack.last_mut()?;
+        let Some(name) = level.entries.next() else {
+            return Some(self.pop());
+        };
+        let path = level.path.join(&name);
+        if let Err(error) = self.reopen_top() {
+            // The directory is gone or was replaced; abandon what is left
+            // of it.
+            self.stack.last_mut()?.entries = Vec::new().into_iter();
+            return Some(WalkEvent::Error {
+                path: self.stack.last()?.path.clone(),
+                error,
+                kind: WalkErrorKind::ReadDir,
+                depth: depth - 1,
+            });
+        }
+        let dir = self.stack.last()?.fd.as_ref()?;
+        let stat = match dir.stat_at(&name, SymlinkBehavior::NoFollow) {
+            Ok(stat) => stat,
+            Err(error) => {
+                return Some(WalkEvent::Error {
+                    path,
+                    error,
+                    kind: WalkErrorKind::Stat,
+                    depth,
+                });
+            }
+        };
+        if !FileType::from_mode(stat.st_mode).is_directory() {
+            return Some(WalkEvent::Entry { path, stat, depth });
+        }
+        let id = FileInfo::from_stat(&stat);
+        if self.stack.iter().any(|level| level.id == id) {
+            return Some(WalkEvent::Cycle { path, depth });
+        }
+        self.pending = Some(PendingDir {
+            name,
+            path: path.clone(),
+            id,
+            skip: false,
+        });
+        Some(WalkEvent::PreDir { path, stat, depth })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1436,4 +1755,59 @@ mod tests {
         let result_nofollow = DirFd::open(&link, SymlinkBehavior::NoFollow);
         assert!(result_nofollow.is_err());
     }
+
+    #[test]
+    fn test_walker_deep_tree_with_single_descriptor() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join("root");
+        let mut deepest = root.clone();
+        for _ in 0..10 {
+            deepest.push("d");
+        }
+        fs::create_dir_all(&deepest).unwrap();
+        fs::write(deepest.join("f"), "x").unwrap();
+        fs::write(root.join("d/g"), "y").unwrap();
+
+        let root_fd = DirFd::open(&root, SymlinkBehavior::NoFollow).unwrap();
+        let mut pre = 0;
+        let mut post = 0;
+        let mut files = Vec::new();
+        for event in Walker::new(root_fd, &root, 1).unwrap() {
+            match event {
+                WalkEvent::PreDir { depth, .. } => {
+                    pre += 1;
+                    assert_eq!(depth, pre);
+                }
+                WalkEvent::PostDir { .. } => post += 1,
+                WalkEvent::Entry { path, .. } => files.push(path),
+                event => panic!("unexpected event: {event:?}"),
+            }
+        }
+        assert_eq!(pre, 10);
+        // The root itself is left last.
+        assert_eq!(post, 11);
+        files.sort();
+        assert_eq!(files, vec![deepest.join("f"), root.join("d/g")]);
+    }
+
+    #[test]
+    fn test_walker_prune() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("a/b")).unwrap();
+        fs::write(root.join("a/b/f"), "x").unwrap();
+
+        let root_fd = DirFd::open(root, SymlinkBehavior::NoFollow).unwrap();
+        let mut walker = Walker::new(root_fd, root, DEFAULT_MAX_OPEN_DIRS).unwrap();
+        match walker.next() {
+            Some(WalkEvent::PreDir { path, .. }) => assert_eq!(path, root.join("a")),
+            _ => panic!("expected a directory"),
+        }
+        walker.prune();
+        assert!(matches!(
+            walker.next(),
+            Some(WalkEvent::PostDir { depth: 0, .. })
+        ));
+        assert!(walker.next().is_none());
+    }
 }
@@ -0,0 +1,419 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore landlock seccomp pledge unveil ruleset fprog rpath wpath cpath dpath fattr getpw execve execveat prctl privs
+
+//! Optional self-restriction of utilities at startup.
+//!
+//! Each utility declares in [`policy_for`] which filesystem scope it needs and
+//! whether it may open network sockets. When the multicall binary is built with
+//! the `feat_sandbox` feature, [`enter`] applies that policy right before the
+//! utility runs, using whatever the platform offers:
+//!
+//! - Linux: Landlock for the filesystem scope and a seccomp filter that only
+//!   allows `socket()` for local sockets. Other system calls are not filtered,
+//!   except that those made through a foreign ABI kill the process.
+//! - OpenBSD: `pledge` promises and `unveil`.
+//!
+//! Sandboxing is best effort. Kernels without Landlock or seccomp simply run
+//! the utility unrestricted, and utilities without an entry in the table (or
+//! that execute other programs, which would inherit the restrictions) are
+//! never restricted.
+
+/// Which parts of the filesystem a utility may open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FsScope {
+    /// No file may be opened, except devices under `/dev`.
+    None,
+    /// Files may be opened for reading; only devices under `/dev` are writable.
+    Read,
+    /// No filesystem restriction.
+    ReadWrite,
+}
+
+/// What a utility is allowed to do once sandboxed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy {
+    pub fs: FsScope,
+    /// Open non-local (e.g. `AF_INET`) sockets. Needed for DNS lookups and
+    /// for NSS backends such as LDAP that resolve users over the network.
+    pub network: bool,
+}
+
+impl Policy {
+    const fn new(fs: FsScope, network: bool) -> Self {
+        Self { fs, network }
+    }
+}
+
+/// The sandbox policy declared by `util`, or `None` if it must run
+/// unrestricted.
+///
+/// Utilities that execute other programs (`env`, `nice`, `timeout`, ...) are
+/// deliberately absent: Landlock, seccomp and pledge restrictions are
+/// inherited across `exec`, so the program they run would be confined too.
+pub fn policy_for(util: &str) -> Option<Policy> {
+    use FsScope::{None as NoFs, Read, ReadWrite};
+    let policy = match util {
+        // Pure computations on arguments.
+        "basename" | "dirname" | "echo" | "expr" | "factor" | "false" | "numfmt" | "printenv"
+        | "printf" | "seq" | "sleep" | "true" | "yes" | "arch" | "uname" => {
+            Policy::new(NoFs, false)
+        }
+        // Read files, write only to already open descriptors.
+        "b2sum" | "base32" | "base64" | "basenc" | "cat" | "cksum" | "comm" | "cut"
+        | "dircolors" | "expand" | "fmt" | "fold" | "head" | "hostid" | "join" | "md5sum"
+        | "more" | "nl" | "nproc" | "od" | "paste" | "pathchk" | "pr" | "ptx" | "pwd"
+        | "readlink" | "realpath" | "sha1sum" | "sha224sum" | "sha256sum" | "sha384sum"
+        | "sha512sum" | "sum" | "tac" | "tail" | "test" | "[" | "tr" | "tsort" | "tty"
+        | "unexpand" | "wc" | "df" | "du" | "kill" | "stty" => Policy::new(Read, false),
+        // Read-only, but may look up users and groups through NSS.
+        "dir" | "groups" | "id" | "logname" | "ls" | "stat" | "uptime" | "users" | "vdir"
+        | "whoami" => Policy::new(Read, true),
+        // Read-only, resolving host names.
+        "pinky" | "who" => Policy::new(Read, true),
+        // Create, modify or remove files, without network access.
+        "chmod" | "cp" | "csplit" | "dd" | "link" | "ln" | "mkdir" | "mkfifo" | "mknod"
+        | "mktemp" | "mv" | "rm" | "rmdir" | "shred" | "shuf" | "sync" | "tee" | "touch"
+        | "truncate" | "uniq" | "unlink" => Policy::new(ReadWrite, false),
+        _ => return None,
+    };
+    Some(policy)
+}
+
+/// Apply the policy of `util`, if it declares one.
+///
+/// Errors are deliberately ignored: a kernel that lacks the mechanism cannot
+/// enforce it, and the utility then behaves exactly as an unsandboxed build.
+pub fn enter(util: &str) {
+    if let Some(policy) = policy_for(util) {
+        let _ = apply(policy);
+    }
+}
+
+/// Apply `policy` to the current process and all its future children.
+pub fn apply(policy: Policy) -> std::io::Result<()> {
+    platform::apply(policy)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod platform {
+    use super::{FsScope, Policy};
+    use std::ffi::CString;
+    use std::io;
+
+    // Landlock uapi (linux/landlock.h).
+    const LANDLOCK_CREATE_RULESET_VERSION: u32 = 1 << 0;
+    const LANDLOCK_RULE_PATH_BENEATH: libc::c_int = 1;
+    const ACCESS_FS_EXECUTE: u64 = 1 << 0;
+    const ACCESS_FS_WRITE_FILE: u64 = 1 << 1;
+    const ACCESS_FS_READ_FILE: u64 = 1 << 2;
+    const ACCESS_FS_READ_DIR: u64 = 1 << 3;
+    /// `REMOVE_DIR` through `MAKE_SYM`: everything that changes the tree.
+    const ACCESS_FS_MODIFY_TREE: u64 = 0b1_1111_1111_0000;
+    /// Available from Landlock ABI 2.
+    const ACCESS_FS_REFER: u64 = 1 << 13;
+    /// Available from Landlock ABI 3.
+    const ACCESS_FS_TRUNCATE: u64 = 1 << 14;
+
+    #[repr(C)]
+    struct RulesetAttr {
+        handled_access_fs: u64,
+    }
+
+    #[repr(C, packed)]
+    struct PathBeneathAttr {
+        allowed_access: u64,
+        parent_fd: libc::c_int,
+    }
+
+    pub(super) fn apply(policy: Policy) -> io::Result<()> {
+        // Required for unprivileged Landlock and seccomp, and harmless since
+        // sandboxed utilities never execute other programs.
+        if unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let landlock = restrict_filesystem(policy.fs);
+        let seccomp = restrict_syscalls(policy);
+        landlock.and(seccomp)
+    }
+
+    fn check(ret: libc::c_long) -> io::Result<libc::c_long> {
+        if ret < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ret)
+        }
+    }
+
+    fn restrict_filesystem(scope: FsScope) -> io::Result<()> {
+        let abi = check(unsafe {
+            libc::syscall(
+                libc::SYS_landlock_create_ruleset,
+                std::ptr::null::<RulesetAttr>(),
+                0,
+                LANDLOCK_CREATE_RULESET_VERSION,
+            )
+        })?;
+
+        let mut write_access = ACCESS_FS_WRITE_FILE | ACCESS_FS_MODIFY_TREE;
+        if abi >= 2 {
+            write_access |= ACCESS_FS_REFER;
+        }
+        if abi >= 3 {
+            write_access |= ACCESS_FS_TRUNCATE;
+        }
+        let read_access = ACCESS_FS_READ_FILE | ACCESS_FS_READ_DIR | ACCESS_FS_EXECUTE;
+        let handled = match scope {
+            FsScope::ReadWrite => return Ok(()),
+            FsScope::Read => write_access,
+            FsScope::None => write_access | read_access,
+        };
+
+        let attr = RulesetAttr {
+            handled_access_fs: handled,
+        };
+        let ruleset = check(unsafe {
+            libc::syscall(
+                libc::SYS_landlock_create_ruleset,
+                &raw const attr,
+                size_of::<RulesetAttr>(),
+                0,
+            )
+        })? as libc::c_int;
+
+        // Keep devices such as /dev/null and /dev/tty usable.
+        let dev_access =
+            handled & (ACCESS_FS_READ_FILE | ACCESS_FS_WRITE_FILE | ACCESS_FS_TRUNCATE);
+        let result = allow_beneath(ruleset, "/dev", dev_access).and_then(|()| {
+            check(unsafe { libc::syscall(libc::SYS_landlock_restrict_self, ruleset, 0) })
+                .map(|_| ())
+        });
+        unsafe { libc::close(ruleset) };
+        result
+    }
+
+    fn allow_beneath(ruleset: libc::c_int, path: &str, access: u64) -> io::Result<()> {
+        let path = CString::new(path)?;
+        let fd = unsafe { libc::open(path.as_ptr(), libc::O_PATH | libc::O_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let attr = PathBeneathAttr {
+            allowed_access: access,
+            parent_fd: fd,
+        };
+        let result = check(unsafe {
+            libc::syscall(
+                libc::SYS_landlock_add_rule,
+                ruleset,
+                LANDLOCK_RULE_PATH_BENEATH,
+                &raw const attr,
+                0,
+            )
+        });
+        unsafe { libc::close(fd) };
+        result.map(|_| ())
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    const AUDIT_ARCH: u32 = 0xC000_003E;
+    #[cfg(target_arch = "aarch64")]
+    const AUDIT_ARCH: u32 = 0xC000_00B7;
+
+    /// Offsets into `struct seccomp_data`.
+    const DATA_NR: u32 = 0;
+    const DATA_ARCH: u32 = 4;
+    const DATA_ARG0: u32 = 16;
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn restrict_syscalls(policy: Policy) -> io::Result<()> {
+        use libc::{
+            BPF_ABS, BPF_JEQ, BPF_JGE, BPF_JMP, BPF_K, BPF_LD, BPF_RET, BPF_W, SECCOMP_RET_ALLOW,
+            SECCOMP_RET_ERRNO, SECCOMP_RET_KILL_PROCESS, sock_filter, sock_fprog,
+        };
+
+        fn stmt(code: u32, k: u32) -> sock_filter {
+            sock_filter {
+                code: code as u16,
+                jt: 0,
+                jf: 0,
+                k,
+            }
+        }
+        fn jump(code: u32, k: u32, jt: u8, jf: u8) -> sock_filter {
+            sock_filter {
+                code: code as u16,
+                jt,
+                jf,
+                k,
+            }
+        }
+
+        if policy.network {
+            return Ok(());
+        }
+
+        let deny = SECCOMP_RET_ERRNO | libc::EACCES as u32;
+        // Jump offsets are relative to the next instruction.
+        let program = [
+            // 0-2: syscalls through a foreign ABI (such as `int 0x80` on
+            // x86_64) have other numbers and would bypass the checks below.
+            stmt(BPF_LD | BPF_W | BPF_ABS, DATA_ARCH),
+            jump(BPF_JMP | BPF_JEQ | BPF_K, AUDIT_ARCH, 1, 0),
+            stmt(BPF_RET | BPF_K, SECCOMP_RET_KILL_PROCESS),
+            // 3-4: x32 syscalls on x86_64 carry bit 30; refuse them outright.
+            stmt(BPF_LD | BPF_W | BPF_ABS, DATA_NR),
+            jump(BPF_JMP | BPF_JGE | BPF_K, 0x4000_0000, 3, 0),
+            // 5-7: socket(domain, ...) only for local sockets (NSS daemons).
+            jump(BPF_JMP | BPF_JEQ | BPF_K, libc::SYS_socket as u32, 0, 3),
+            stmt(BPF_LD | BPF_W | BPF_ABS, DATA_ARG0),
+            jump(BPF_JMP | BPF_JEQ | BPF_K, libc::AF_UNIX as u32, 1, 0),
+            // 8: deny, 9: allow.
+            stmt(BPF_RET | BPF_K, deny),
+            stmt(BPF_RET | BPF_K, SECCOMP_RET_ALLOW),
+        ];
+        let prog = sock_fprog {
+            len: program.len() as u16,
+            filter: program.as_ptr().cast_mut(),
+        };
+        if unsafe {
+            libc::prctl(
+                libc::PR_SET_SECCOMP,
+                libc::SECCOMP_MODE_FILTER,
+                &raw const prog,
+                0,
+                0,
+            )
+        } != 0
+        {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn restrict_syscalls(_policy: Policy) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "openbsd")]
+mod platform {
+    use super::{FsScope, Policy};
+    use std::ffi::CString;
+    use std::io;
+
+    fn check(ret: libc::c_int) -> io::Result<()> {
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    fn unveil(path: &str, permissions: &str) -> io::Result<()> {
+        let path = CString::new(path)?;
+        let permissions = CString::new(permissions)?;
+        check(unsafe { libc::unveil(path.as_ptr(), permissions.as_ptr()) })
+    }
+
+    pub(super) fn apply(policy: Policy) -> io::Result<()> {
+        let mut promises = String::from("stdio tty");
+        match policy.fs {
+            FsScope::None => {}
+            FsScope::Read => promises.push_str(" rpath getpw proc"),
+            FsScope::ReadWrite => {
+                promises.push_str(" rpath wpath cpath dpath fattr chown flock getpw proc");
+            }
+        }
+        if policy.network {
+            promises.push_str(" inet dns");
+        }
+
+        if policy.fs == FsScope::Read {
+            unveil("/", "r")?;
+            unveil("/dev", "rw")?;
+            check(unsafe { libc::unveil(std::ptr::null(), std::ptr::null()) })?;
+        }
+
+        let promises = CString::new(promises)?;
+        check(unsafe { libc::pledge(promises.as_ptr(), std::ptr::null()) })
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "openbsd")))]
+mod platform {
+    use super::Policy;
+
+    pub(super) fn apply(_policy: Policy) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FsScope, policy_for};
+
+    /// Set in the child process of [`sandbox_denies_network_sockets`].
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    const CHILD_ENV: &str = "UUCORE_SANDBOX_TEST_CHILD";
+
+    /// The sandbox is inherited by every later thread and child, so it is
+    /// entered in a copy of the test binary that only runs this test.
+    #[test]
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    fn sandbox_denies_network_sockets() {
+        let socket = |domain| unsafe { libc::socket(domain, libc::SOCK_STREAM, 0) };
+
+        if std::env::var_os(CHILD_ENV).is_some() {
+            super::enter("cat");
+            let inet = socket(libc::AF_INET);
+            assert_eq!(inet, -1);
+            assert_eq!(
+                std::io::Error::last_os_error().raw_os_error(),
+                Some(libc::EACCES)
+            );
+            let unix = socket(libc::AF_UNIX);
+            assert!(unix >= 0);
+            unsafe { libc::close(unix) };
+            return;
+        }
+
+        let status = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "features::sandbox::tests::sandbox_denies_network_sockets",
+            ])
+            .env(CHILD_ENV, "1")
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn exec_wrappers_are_never_sandboxed() {
+        for util in [
+            "env", "nice", "nohup", "timeout", "stdbuf", "chroot", "runcon",
+        ] {
+            assert_eq!(policy_for(util), None, "{util}");
+        }
+    }
+
+    #[test]
+    fn declared_scopes() {
+        assert_eq!(policy_for("echo").unwrap().fs, FsScope::None);
+        assert_eq!(policy_for("cat").unwrap().fs, FsScope::Read);
+        assert!(!policy_for("cat").unwrap().network);
+        assert!(policy_for("ls").unwrap().network);
+        assert_eq!(policy_for("rm").unwrap().fs, FsScope::ReadWrite);
+    }
+}
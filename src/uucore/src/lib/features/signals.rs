@@ -12,7 +12,7 @@
 
 #[cfg(unix)]
 use nix::errno::Errno;
-#[cfg(any(target_os = "linux", target_os = "android"))]
+#[cfg(unix)]
 use nix::libc;
 #[cfg(unix)]
 use nix::sys::signal::{
@@ -470,8 +470,13 @@ pub fn signal_name_by_value(signal_value: usize) -> Option<String> {
         } else if signal_value == rtmax {
             Some("RTMAX".to_string())
         } else if signal_value > rtmin && signal_value < rtmax {
-            let n = signal_value - rtmin;
-            Some(format!("RTMIN+{n}"))
+            // Like glibc, name the lower half relative to RTMIN and the upper
+            // half relative to RTMAX.
+            if signal_value - rtmin <= (rtmax - rtmin) / 2 {
+                Some(format!("RTMIN+{}", signal_value - rtmin))
+            } else {
+                Some(format!("RTMAX-{}", rtmax - signal_value))
+            }
         } else {
             None
         }
@@ -537,6 +542,25 @@ pub fn signal_list_value_by_name_or_number(spec: &str) -> Option<usize> {
     })
 }
 
+/// Returns the description of a signal, as given by `strsignal`.
+#[cfg(unix)]
+pub fn signal_description(signal_value: usize) -> Option<String> {
+    if signal_value == 0 || !is_signal(signal_value) {
+        return None;
+    }
+    let signal = i32::try_from(signal_value).ok()?;
+    // SAFETY: strsignal returns a NUL-terminated string, which is copied
+    // before anything else can call strsignal and overwrite it.
+    unsafe {
+        let description = libc::strsignal(signal);
+        (!description.is_null()).then(|| {
+            std::ffi::CStr::from_ptr(description)
+                .to_string_lossy()
+                .into_owned()
+        })
+    }
+}
+
 /// Restores SIGPIPE to default behavior (process terminates on broken pipe).
 #[cfg(unix)]
 pub fn enable_pipe_errors() -> Result<(), Errno> {
@@ -797,6 +821,14 @@ fn list_signal_numbers_follow_upper_bound() {
     );
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+fn signal_descriptions() {
+    assert_eq!(signal_description(0), None);
+    assert_eq!(signal_description(9).as_deref(), Some("Killed"));
+    assert_eq!(signal_description(signal_number_upper_bound() + 1), None);
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 #[test]
 fn linux_realtime_signal_upper_bound_includes_rtmax() {
@@ -813,6 +845,19 @@ fn linux_realtime_signal_names_are_listed() {
     assert_eq!(signal_list_name_by_value(rtmax), Some("RTMAX".to_string()));
 }
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn linux_realtime_signal_names_round_trip() {
+    let (rtmin, rtmax) = realtime_signal_bounds().unwrap();
+
+    assert_eq!(signal_name_by_value(rtmin + 1), Some("RTMIN+1".to_string()));
+    assert_eq!(signal_name_by_value(rtmax - 1), Some("RTMAX-1".to_string()));
+    for value in rtmin..=rtmax {
+        let name = signal_name_by_value(value).unwrap();
+        assert_eq!(signal_by_name_or_value(&name), Some(value));
+    }
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 #[test]
 fn linux_realtime_signal_names_resolve_to_runtime_values() {
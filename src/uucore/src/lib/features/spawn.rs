@@ -0,0 +1,70 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore (windows) NTSTATUS
+
+//! Running commands on Windows.
+//!
+//! Windows has no signals, so a process that is interrupted or crashes
+//! reports an `NTSTATUS` as its exit code. The utilities that run a command
+//! on behalf of the user, such as `env`, `nice` and `nohup`, translate it
+//! with [`exit_code`] to what a shell would report on a POSIX system.
+
+use std::process::ExitStatus;
+
+use windows_sys::Win32::Foundation::{
+    STATUS_ACCESS_VIOLATION, STATUS_CONTROL_C_EXIT, STATUS_FLOAT_DIVIDE_BY_ZERO,
+    STATUS_ILLEGAL_INSTRUCTION, STATUS_INTEGER_DIVIDE_BY_ZERO, STATUS_PRIVILEGED_INSTRUCTION,
+    STATUS_STACK_BUFFER_OVERRUN, STATUS_STACK_OVERFLOW,
+};
+
+// POSIX signal numbers, as they are used for the exit status of a shell.
+const SIGINT: i32 = 2;
+const SIGILL: i32 = 4;
+const SIGABRT: i32 = 6;
+const SIGFPE: i32 = 8;
+const SIGSEGV: i32 = 11;
+
+/// The exit code to report for a command that exited with `status`.
+///
+/// A process that is interrupted with CTRL+C or dies from an exception exits
+/// with an `NTSTATUS` rather than a small number. These are translated to
+/// 128 + the POSIX signal with the same meaning, as a shell would report it.
+pub fn exit_code(status: ExitStatus) -> i32 {
+    let Some(code) = status.code() else {
+        return 1;
+    };
+    let signal = match code {
+        STATUS_CONTROL_C_EXIT => SIGINT,
+        STATUS_ILLEGAL_INSTRUCTION | STATUS_PRIVILEGED_INSTRUCTION => SIGILL,
+        // `abort()` and failed security checks end with a fast fail.
+        STATUS_STACK_BUFFER_OVERRUN => SIGABRT,
+        STATUS_INTEGER_DIVIDE_BY_ZERO | STATUS_FLOAT_DIVIDE_BY_ZERO => SIGFPE,
+        STATUS_ACCESS_VIOLATION | STATUS_STACK_OVERFLOW => SIGSEGV,
+        _ => return code,
+    };
+    128 + signal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::windows::process::ExitStatusExt;
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(exit_code(ExitStatus::from_raw(3)), 3);
+        assert_eq!(
+            exit_code(ExitStatus::from_raw(STATUS_CONTROL_C_EXIT.cast_unsigned())),
+            130
+        );
+        assert_eq!(
+            exit_code(ExitStatus::from_raw(
+                STATUS_ACCESS_VIOLATION.cast_unsigned()
+            )),
+            139
+        );
+    }
+}
@@ -45,46 +45,39 @@ pub fn get_formatted_time() -> String {
         .to_string()
 }
 
-/// Safely get macOS boot time using sysctl command
+/// Get the boot time recorded by the kernel, i.e. `sysctl kern.boottime`
 ///
-/// This function uses the sysctl command-line tool to retrieve the kernel
-/// boot time on macOS, avoiding any unsafe code. It parses the output
-/// of the sysctl command to extract the boot time.
+/// Unlike the utmpx `BOOT_TIME` record, which can be unreliable or absent
+/// (see issue #3621: https://github.com/uutils/coreutils/issues/3621), this
+/// is always available, including in jails and containers.
 ///
 /// # Returns
 ///
 /// Returns Some(time_t) if successful, None if the call fails.
-#[cfg(target_os = "macos")]
-fn get_macos_boot_time_sysctl() -> Option<time_t> {
-    use std::process::Command;
-
-    // Execute sysctl command to get boot time
-    let output = Command::new("sysctl")
-        .arg("-n")
-        .arg("kern.boottime")
-        .output();
-
-    if let Ok(output) = output
-        && output.status.success()
-    {
-        // Parse output format: { sec = 1729338352, usec = 0 } Wed Oct 19 08:25:52 2025
-        // We need to extract the seconds value from the structured output
-        let stdout = String::from_utf8_lossy(&output.stdout);
-
-        // Extract the seconds from the output
-        // Look for "sec = " pattern
-        if let Some(sec_start) = stdout.find("sec = ") {
-            let sec_part = &stdout[sec_start + 6..];
-            if let Some(sec_end) = sec_part.find(',') {
-                let sec_str = &sec_part[..sec_end];
-                if let Ok(boot_time) = sec_str.trim().parse::<i64>() {
-                    return Some(boot_time as time_t);
-                }
-            }
-        }
-    }
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "dragonfly"))]
+fn get_sysctl_boot_time() -> Option<time_t> {
+    use std::mem::size_of;
+    use std::ptr;
+
+    let mut mib = [libc::CTL_KERN, libc::KERN_BOOTTIME];
+    let mut boot_time = libc::timeval {
+        tv_sec: 0,
+        tv_usec: 0,
+    };
+    let mut size = size_of::<libc::timeval>();
+    // SAFETY: the buffer is a timeval and `size` is its size
+    let ret = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            (&raw mut boot_time).cast(),
+            &raw mut size,
+            ptr::null_mut(),
+            0,
+        )
+    };
 
-    None
+    (ret == 0 && boot_time.tv_sec > 0).then_some(boot_time.tv_sec)
 }
 
 /// Get the system uptime
@@ -145,6 +138,12 @@ pub fn get_uptime(boot_time: Option<time_t>) -> UResult<i64> {
         return Ok(uptime);
     }
 
+    // Without a boot time from the caller, e.g. from the FILE of `uptime`, the
+    // kernel's one takes precedence over the utmpx records, which containers
+    // usually don't have.
+    #[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "dragonfly"))]
+    let boot_time = boot_time.or_else(get_sysctl_boot_time);
+
     // Try provided boot_time or derive from utmpx
     let derived_boot_time = boot_time.or_else(|| {
         Utmpx::iter_all_records()
@@ -154,26 +153,6 @@ pub fn get_uptime(boot_time: Option<time_t>) -> UResult<i64> {
             .map(|ts| ts as time_t)
     });
 
-    // macOS-specific fallback: use sysctl kern.boottime when utmpx did not provide BOOT_TIME
-    //
-    // On macOS, the utmpx BOOT_TIME record can be unreliable or absent, causing intermittent
-    // test failures (see issue #3621: https://github.com/uutils/coreutils/issues/3621).
-    // The sysctl(CTL_KERN, KERN_BOOTTIME) approach is the canonical way to retrieve boot time
-    // on macOS and is always available, making uptime more reliable on this platform.
-    //
-    // This fallback only runs if utmpx failed to provide a boot time.
-    #[cfg(target_os = "macos")]
-    let derived_boot_time = {
-        let mut t = derived_boot_time;
-        if t.is_none() {
-            // Use a safe wrapper function to get boot time via sysctl
-            if let Some(boot_time) = get_macos_boot_time_sysctl() {
-                t = Some(boot_time);
-            }
-        }
-        t
-    };
-
     if let Some(t) = derived_boot_time {
         let now = Timestamp::now().as_second();
         #[cfg(target_pointer_width = "64")]
@@ -248,10 +227,11 @@ impl FormattedUptime {
 #[cfg(windows)]
 #[allow(clippy::unnecessary_wraps, reason = "needed on some platforms")]
 pub fn get_uptime(_boot_time: Option<time_t>) -> UResult<i64> {
-    use windows_sys::Win32::System::SystemInformation::GetTickCount;
-    // SAFETY: always return u32
-    let uptime = unsafe { GetTickCount() };
-    Ok(uptime as i64 / 1000)
+    use windows_sys::Win32::System::SystemInformation::GetTickCount64;
+    // SAFETY: always return u64; unlike GetTickCount, it doesn't wrap around
+    // after 49.7 days
+    let uptime = unsafe { GetTickCount64() };
+    Ok((uptime / 1000) as i64)
 }
 
 /// Get the system uptime in a human-readable format
@@ -295,13 +275,20 @@ pub fn get_nusers() -> usize {
     use crate::utmpx::USER_PROCESS;
     use crate::utmpx::Utmpx;
 
-    let mut num_user = 0;
-    Utmpx::iter_all_records().for_each(|ut| {
-        if ut.record_type() == USER_PROCESS {
-            num_user += 1;
-        }
-    });
-    num_user
+    // Containers usually run without systemd-logind, so its sessions are only
+    // counted when it is running, and utmp is used otherwise.
+    #[cfg(feature = "feat_systemd_logind")]
+    let records = if std::path::Path::new("/run/systemd/sessions").is_dir() {
+        Utmpx::iter_all_records()
+    } else {
+        Utmpx::iter_utmp_records()
+    };
+    #[cfg(not(feature = "feat_systemd_logind"))]
+    let records = Utmpx::iter_all_records();
+
+    records
+        .filter(|ut| ut.record_type() == USER_PROCESS)
+        .count()
 }
 
 /// Get the number of users currently logged in
@@ -331,20 +318,20 @@ pub fn get_nusers(file: &str) -> usize {
         .count()
 }
 
-/// Get the number of users currently logged in
+/// Get the names of the users logged in to a Windows session
 ///
 /// # Returns
 ///
-/// Returns the number of users currently logged in if successful, otherwise 0
+/// Returns one name per session with a user, or an empty list if the sessions can't be enumerated
 #[cfg(target_os = "windows")]
-pub fn get_nusers() -> usize {
+pub fn get_session_users() -> Vec<String> {
     use std::ptr;
     use windows_sys::Win32::System::RemoteDesktop::{
         WTS_CURRENT_SERVER_HANDLE, WTSEnumerateSessionsW, WTSFreeMemory,
-        WTSQuerySessionInformationW,
+        WTSQuerySessionInformationW, WTSUserName,
     };
 
-    let mut num_user = 0;
+    let mut users = Vec::new();
 
     // SAFETY: WTS_CURRENT_SERVER_HANDLE is a valid handle
     unsafe {
@@ -359,7 +346,7 @@ pub fn get_nusers() -> usize {
             &raw mut session_count,
         );
         if result == 0 {
-            return 0;
+            return users;
         }
 
         let sessions = std::slice::from_raw_parts(session_info_ptr, session_count as usize);
@@ -371,7 +358,7 @@ pub fn get_nusers() -> usize {
             let result = WTSQuerySessionInformationW(
                 WTS_CURRENT_SERVER_HANDLE,
                 session.SessionId,
-                5,
+                WTSUserName,
                 &raw mut buffer,
                 &raw mut bytes_returned,
             );
@@ -379,9 +366,11 @@ pub fn get_nusers() -> usize {
                 continue;
             }
 
-            let cstr = std::ffi::CStr::from_ptr(buffer.cast());
-            if !cstr.is_empty() {
-                num_user += 1;
+            // The name is a NUL-terminated UTF-16 string of at most `bytes_returned` bytes.
+            let wide = std::slice::from_raw_parts(buffer, bytes_returned as usize / 2);
+            let len = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+            if len > 0 {
+                users.push(String::from_utf16_lossy(&wide[..len]));
             }
 
             WTSFreeMemory(buffer.cast());
@@ -390,7 +379,17 @@ pub fn get_nusers() -> usize {
         WTSFreeMemory(session_info_ptr.cast());
     }
 
-    num_user
+    users
+}
+
+/// Get the number of users currently logged in
+///
+/// # Returns
+///
+/// Returns the number of users currently logged in if successful, otherwise 0
+#[cfg(target_os = "windows")]
+pub fn get_nusers() -> usize {
+    get_session_users().len()
 }
 
 /// Format the number of users to a human-readable string
@@ -515,13 +514,11 @@ mod tests {
     #[test]
     #[cfg(target_os = "macos")]
     fn test_macos_sysctl_boottime_available() {
-        // Test the safe wrapper function
-        let boot_time = get_macos_boot_time_sysctl();
+        let boot_time = get_sysctl_boot_time();
 
-        // Verify the safe wrapper succeeded
         assert!(
             boot_time.is_some(),
-            "get_macos_boot_time_sysctl should succeed on macOS"
+            "get_sysctl_boot_time should succeed on macOS"
         );
 
         let boot_time = boot_time.unwrap();
@@ -566,6 +563,21 @@ mod tests {
         );
     }
 
+    /// Test that a boot time passed by the caller, e.g. read from the FILE of
+    /// `uptime`, is used instead of the one of the running system.
+    #[test]
+    #[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "dragonfly"))]
+    fn test_get_uptime_prefers_given_boot_time() {
+        let boot_time = Timestamp::now().as_second() - 1000 * 86400;
+
+        let uptime = get_uptime(Some(boot_time as time_t)).unwrap();
+
+        assert!(
+            (1000 * 86400..=1000 * 86400 + 1).contains(&uptime),
+            "Uptime should be computed from the given boot time, got {uptime} seconds"
+        );
+    }
+
     /// Test get_uptime consistency by calling it multiple times.
     /// Verifies the sysctl fallback produces stable results.
     #[test]
@@ -340,17 +340,23 @@ impl Utmpx {
         }
 
         #[cfg(not(feature = "feat_systemd_logind"))]
-        {
-            let iter = UtmpxIter::new();
-            unsafe {
-                // This can technically fail, and it would be nice to detect that,
-                // but it doesn't return anything so we'd have to do nasty things
-                // with errno.
-                #[cfg_attr(target_env = "musl", allow(deprecated))]
-                setutxent();
-            }
-            iter
+        Self::iter_utmp_records()
+    }
+
+    /// Iterate through all the records of the utmp file, even on systems with
+    /// the systemd-logind feature enabled.
+    ///
+    /// The same caveats as for [`Utmpx::iter_all_records`] apply.
+    pub fn iter_utmp_records() -> UtmpxIter {
+        let iter = UtmpxIter::new();
+        unsafe {
+            // This can technically fail, and it would be nice to detect that,
+            // but it doesn't return anything so we'd have to do nasty things
+            // with errno.
+            #[cfg_attr(target_env = "musl", allow(deprecated))]
+            setutxent();
         }
+        iter
     }
 
     /// Iterate through all the utmp records from a specific file.
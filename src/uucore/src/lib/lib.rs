@@ -73,6 +73,8 @@ pub use crate::features::quoting_style;
 pub use crate::features::ranges;
 #[cfg(feature = "ringbuffer")]
 pub use crate::features::ringbuffer;
+#[cfg(feature = "sandbox")]
+pub use crate::features::sandbox;
 #[cfg(feature = "sum")]
 pub use crate::features::sum;
 #[cfg(feature = "feat_systemd_logind")]
@@ -118,6 +120,8 @@ pub use crate::features::signals;
 ))]
 pub use crate::features::utmpx;
 // ** windows-only
+#[cfg(all(windows, feature = "spawn"))]
+pub use crate::features::spawn;
 #[cfg(all(windows, feature = "wide"))]
 pub use crate::features::wide;
 
@@ -190,7 +194,6 @@ pub fn get_canonical_util_name(util_name: &str) -> &str {
 macro_rules! bin_inner {
     ($util:ident, $post:expr) => {
         pub fn main() {
-            use std::io::Write;
             use uucore::locale;
 
             // Preserve inherited SIGPIPE settings (e.g., from env --default-signal=PIPE)
@@ -212,9 +215,8 @@ macro_rules! bin_inner {
 
             // execute utility code
             let code = $util::uumain(uucore::args_os());
-            $post
 
-            std::process::exit(code);
+            std::process::exit(($post)(code));
         }
     };
 }
@@ -225,18 +227,35 @@ macro_rules! bin_inner {
 #[macro_export]
 macro_rules! bin {
     ($util:ident, no_flush) => {
-        ::uucore::bin_inner! {$util, {}}
+        ::uucore::bin_inner! {$util, |code: i32| code}
     };
     ($util:ident) => {
-        ::uucore::bin_inner! {$util, {
-            // (defensively) flush stdout for utility prior to exit; see <https://github.com/rust-lang/rust/issues/23818>
-            if let Err(e) = std::io::stdout().flush() {
-                eprintln!("Error flushing stdout: {e}");
-            }
-        }}
+        // (defensively) flush stdout for utility prior to exit; see <https://github.com/rust-lang/rust/issues/23818>
+        ::uucore::bin_inner! {$util, ::uucore::close_stdout}
     };
 }
 
+/// Flush stdout before exiting with `code`, and return the exit code to use.
+///
+/// A failure is reported and makes the exit code non-zero, like GNU's
+/// `close_stdout`. It is not reported if the utility already failed, as it
+/// has then usually reported the same write error.
+pub fn close_stdout(code: i32) -> i32 {
+    use std::io::Write;
+
+    match std::io::stdout().flush() {
+        Err(e) if code == 0 => {
+            show_error!(
+                "{}: {}",
+                translate!("common-write-error"),
+                error::strip_errno(&e)
+            );
+            1
+        }
+        _ => code,
+    }
+}
+
 /// Generate the version string for clap.
 ///
 /// The generated string has the format `(<project name>) <version>`, for
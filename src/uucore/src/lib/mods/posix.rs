@@ -10,11 +10,14 @@
 //! and has value that can be parsed.
 //! Otherwise returns None, so the calling utility would assume default behavior.
 //!
+//! It also provides [`posixly_correct`], for the `POSIXLY_CORRECT` environment
+//! variable.
+//!
 //! NOTE: GNU (as of v9.4) recognizes three distinct values for POSIX version
 //!
 //! Utilities that rely on this module:
-//! `sort` (TBD)
-//! `tail` (TBD)
+//! `sort`
+//! `tail`
 //! `touch` (TBD)
 //! `uniq`
 //!
@@ -36,6 +39,19 @@ pub fn posix_version() -> Option<usize> {
         .and_then(|v| v.parse::<usize>().ok())
 }
 
+/// Returns true if the obsolete usage of `sort` and `tail` (`+POS` operands) is
+/// permitted, i.e. unless `_POSIX2_VERSION` is in the [TRADITIONAL, MODERN)
+/// range (matches GNU behaviour).
+pub fn allows_traditional_usage() -> bool {
+    !matches!(posix_version(), Some(ver) if (TRADITIONAL..MODERN).contains(&ver))
+}
+
+/// Returns true if the `POSIXLY_CORRECT` environment variable is set, even to
+/// an empty value
+pub fn posixly_correct() -> bool {
+    env::var_os("POSIXLY_CORRECT").is_some()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::posix::*;
@@ -44,12 +60,16 @@ mod tests {
     fn test_posix_version() {
         // default
         assert_eq!(posix_version(), None);
+        assert!(allows_traditional_usage());
         // set specific version
         unsafe { env::set_var("_POSIX2_VERSION", OBSOLETE.to_string()) };
         assert_eq!(posix_version(), Some(OBSOLETE));
+        assert!(allows_traditional_usage());
         unsafe { env::set_var("_POSIX2_VERSION", TRADITIONAL.to_string()) };
         assert_eq!(posix_version(), Some(TRADITIONAL));
+        assert!(!allows_traditional_usage());
         unsafe { env::set_var("_POSIX2_VERSION", MODERN.to_string()) };
         assert_eq!(posix_version(), Some(MODERN));
+        assert!(allows_traditional_usage());
     }
 }
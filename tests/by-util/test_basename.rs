@@ -297,3 +297,11 @@ fn test_emoji_handling() {
         .succeeds()
         .stdout_only("file🎯\n");
 }
+
+#[test]
+fn test_suffix_multiple_zero() {
+    new_ucmd!()
+        .args(&["-a", "-s", ".c", "-z", "dir/file1.c", "file2.c", ".c"])
+        .succeeds()
+        .stdout_only("file1\0file2\0.c\0");
+}
@@ -27,6 +27,17 @@ fn test_z85_not_padded_decode() {
         .stderr_only("basenc: error: invalid input\n");
 }
 
+#[test]
+fn test_z85_incomplete_group_decode() {
+    // Whole groups are still decoded before the trailing partial group is rejected
+    new_ucmd!()
+        .args(&["--z85", "-d"])
+        .pipe_in("nm=QNz.92jz/PV8nm")
+        .fails()
+        .stdout_is("Hello, World")
+        .stderr_is("basenc: error: invalid input\n");
+}
+
 #[test]
 fn test_z85_not_padded_encode() {
     // The z85 crate deviates from the standard in some cases; we have to catch those
@@ -550,6 +550,26 @@ fn test_chmod_preserve_root_with_paths_that_resolve_to_root() {
         .stderr_contains("chmod: it is dangerous to operate recursively on '/'");
 }
 
+#[test]
+fn test_chmod_preserve_root_symlink_to_root() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.symlink_file("/", "root");
+    // "a+" doesn't change any mode, in case '/' were traversed anyway.
+    ucmd.args(&["-R", "-H", "--preserve-root", "a+", "root"])
+        .fails_with_code(1)
+        .stderr_contains("chmod: it is dangerous to operate recursively on '/'");
+}
+
+#[test]
+fn test_chmod_preserve_root_during_traversal() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("dir");
+    at.symlink_file("/", "dir/root");
+    ucmd.args(&["-R", "-L", "--preserve-root", "a+", "dir"])
+        .fails_with_code(1)
+        .stderr_is("chmod: it is dangerous to operate recursively on 'dir/root' (same as '/')\nchmod: use --no-preserve-root to override this failsafe\n");
+}
+
 #[test]
 fn test_chmod_symlink_non_existing_file() {
     let scene = TestScenario::new(util_name!());
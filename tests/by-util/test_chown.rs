@@ -750,6 +750,17 @@ fn test_root_preserve() {
     result.stderr_contains("chown: it is dangerous to operate recursively");
 }
 
+#[test]
+fn test_root_preserve_during_traversal() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("dir");
+    at.symlink_file("/", "dir/root");
+    // No file is owned by 54321:54321, in case '/' were traversed anyway.
+    ucmd.args(&["-R", "-L", "--preserve-root", "--from=54321:54321", "0", "dir"])
+        .fails_with_code(1)
+        .stderr_is("chown: it is dangerous to operate recursively on 'dir/root' (same as '/')\nchown: use --no-preserve-root to override this failsafe\n");
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 #[test]
 fn test_big_p() {
@@ -376,3 +376,30 @@ fn test_chroot_userspec_unknown_uid() {
         println!("Test skipped; requires root user");
     }
 }
+
+#[test]
+fn test_chroot_userspec_numeric_ids_without_entries() {
+    // IDs with a leading '+' are never looked up, and need no passwd or group
+    // entries.
+    let ts = TestScenario::new(util_name!());
+    if let Ok(result) = run_ucmd_as_root(&ts, &["--userspec=+65533:+65532", "/", "id", "-u"]) {
+        result.success().no_stderr().stdout_is("65533\n");
+    } else {
+        println!("Test skipped; requires root user");
+    }
+
+    if let Ok(result) = run_ucmd_as_root(
+        &ts,
+        &[
+            "--userspec=+65533:+65532",
+            "--groups=+65531",
+            "/",
+            "id",
+            "-G",
+        ],
+    ) {
+        result.success().no_stderr().stdout_is("65532 65531\n");
+    } else {
+        println!("Test skipped; requires root user");
+    }
+}
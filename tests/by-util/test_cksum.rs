@@ -1242,6 +1242,15 @@ fn test_conflicting_options() {
         .fails_with_code(1)
         .no_stdout()
         .stderr_contains("cksum: the --tag option is meaningless when verifying checksums");
+
+    scene
+        .ucmd()
+        .arg("--zero")
+        .arg("-c")
+        .arg("f")
+        .fails_with_code(1)
+        .no_stdout()
+        .stderr_contains("cksum: the --zero option is not supported when verifying checksums");
 }
 
 #[test]
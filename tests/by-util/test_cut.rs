@@ -80,6 +80,31 @@ fn test_char_sequence() {
     }
 }
 
+#[test]
+fn test_char_sequence_utf8() {
+    new_ucmd!()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(&["-c", "2-3,5"])
+        .pipe_in("aéb€ü\n")
+        .succeeds()
+        .stdout_only("ébü\n");
+
+    // Bytes of invalid sequences count as one character each
+    new_ucmd!()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(&["-c", "2,4"])
+        .pipe_in(b"a\xe2\x82b\n")
+        .succeeds()
+        .stdout_only_bytes(b"\xe2b\n");
+
+    new_ucmd!()
+        .env("LC_ALL", "C")
+        .args(&["-c", "2-3"])
+        .pipe_in("aéb\n")
+        .succeeds()
+        .stdout_only("é\n");
+}
+
 #[test]
 fn test_field_sequence() {
     for param in ["-f", "--fields", "--fie"] {
@@ -435,10 +435,12 @@ fn test_date_format_literal() {
 fn test_date_set_valid() {
     if geteuid().is_root() {
         new_ucmd!()
+            .env("TZ", "UTC")
             .arg("--set")
             .arg("2020-03-12 13:30:00+08:00")
+            .arg("+%F %T")
             .succeeds()
-            .no_output();
+            .stdout_is("2020-03-12 05:30:00\n");
     }
 }
 
@@ -454,12 +456,15 @@ fn test_date_set_invalid() {
 #[cfg(all(unix, not(any(target_os = "android", target_os = "macos"))))]
 fn test_date_set_permissions_error() {
     if !(geteuid().is_root() || uucore::os::is_wsl_1()) {
-        let result = new_ucmd!()
+        // Like GNU, the requested date is still printed.
+        new_ucmd!()
             .arg("--set")
             .arg("2020-03-11 21:45:00+08:00")
-            .fails();
-        result.no_stdout();
-        assert!(result.stderr_str().starts_with("date: cannot set date: "));
+            .arg("-u")
+            .arg("+%F %T")
+            .fails_with_code(1)
+            .stdout_is("2020-03-11 13:45:00\n")
+            .stderr_is("date: cannot set date: Operation not permitted\n");
     }
 }
 
@@ -472,7 +477,6 @@ fn test_date_set_hyphen_prefixed_values() {
 
         for date_str in test_cases {
             let result = new_ucmd!().arg("--set").arg(date_str).fails();
-            result.no_stdout();
             // permission error, not argument parsing error
             assert!(
                 result.stderr_str().starts_with("date: cannot set date: "),
@@ -483,6 +487,38 @@ fn test_date_set_hyphen_prefixed_values() {
     }
 }
 
+#[test]
+fn test_date_set_with_date_source() {
+    for args in [
+        ["--set", "2020-03-11", "--date", "now"],
+        ["--set", "2020-03-11", "--reference", "."],
+        ["--set", "2020-03-11", "--file", "-"],
+        ["--set", "2020-03-11", "--resolution", "+%s"],
+    ] {
+        new_ucmd!()
+            .args(&args)
+            .fails_with_code(1)
+            .no_stdout()
+            .usage_error("the options to print and set the time may not be used together");
+    }
+}
+
+#[test]
+#[cfg(all(unix, not(any(target_os = "android", target_os = "macos"))))]
+fn test_date_set_same_grammar_as_date() {
+    // Pure numbers are a time of day, as with --date.
+    if !(geteuid().is_root() || uucore::os::is_wsl_1()) {
+        new_ucmd!()
+            .env("TZ", "UTC")
+            .arg("--set")
+            .arg("0730")
+            .arg("+%T")
+            .fails_with_code(1)
+            .stdout_is("07:30:00\n")
+            .stderr_is("date: cannot set date: Operation not permitted\n");
+    }
+}
+
 #[test]
 #[cfg(target_os = "macos")]
 fn test_date_set_mac_unavailable() {
@@ -503,10 +539,12 @@ fn test_date_set_mac_unavailable() {
 fn test_date_set_valid_2() {
     if geteuid().is_root() {
         new_ucmd!()
+            .env("TZ", "UTC")
             .arg("--set")
             .arg("Sat 20 Mar 2021 14:53:01 AWST") // spell-checker:disable-line
+            .arg("+%F %T")
             .succeeds()
-            .no_output();
+            .stdout_is("2021-03-20 06:53:01\n");
     }
 }
 
@@ -562,6 +600,39 @@ fn test_date_for_file() {
     ucmd.arg("--file").arg(file).succeeds();
 }
 
+#[test]
+fn test_date_for_file_with_invalid_lines() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let file = "test_date_for_file_with_invalid_lines";
+    at.write(file, "2024-01-01\nbogus\n2024-02-02 10:00\n");
+    ucmd.env("TZ", "UTC")
+        .arg("--file")
+        .arg(file)
+        .arg("+%F %T")
+        .fails_with_code(1)
+        .stdout_is("2024-01-01 00:00:00\n2024-02-02 10:00:00\n")
+        .stderr_is("date: invalid date 'bogus'\n");
+}
+
+#[test]
+fn test_date_multiple_date_sources() {
+    let scene = TestScenario::new(util_name!());
+    scene.fixtures.touch("file");
+    for args in [
+        ["--file", "file", "--date", "now"],
+        ["--reference", "file", "--date", "now"],
+        ["--file", "file", "--reference", "file"],
+        ["--file", "file", "--resolution", "+%s"],
+    ] {
+        scene
+            .ucmd()
+            .args(&args)
+            .fails_with_code(1)
+            .no_stdout()
+            .usage_error("the options to specify dates for printing are mutually exclusive");
+    }
+}
+
 #[test]
 fn test_date_for_file_mtime() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -583,10 +654,12 @@ fn test_date_for_file_mtime() {
 fn test_date_set_valid_3() {
     if geteuid().is_root() {
         new_ucmd!()
+            .env("TZ", "UTC")
             .arg("--set")
             .arg("Sat 20 Mar 2021 14:53:01") // Local timezone
+            .arg("+%F %T")
             .succeeds()
-            .no_output();
+            .stdout_is("2021-03-20 14:53:01\n");
     }
 }
 
@@ -595,10 +668,12 @@ fn test_date_set_valid_3() {
 fn test_date_set_valid_4() {
     if geteuid().is_root() {
         new_ucmd!()
+            .env("TZ", "UTC")
             .arg("--set")
             .arg("2020-03-11 21:45:00") // Local timezone
+            .arg("+%F %T")
             .succeeds()
-            .no_output();
+            .stdout_is("2020-03-11 21:45:00\n");
     }
 }
 
@@ -682,6 +757,52 @@ fn test_negative_offset() {
     }
 }
 
+#[test]
+fn test_hence() {
+    new_ucmd!()
+        .env("TZ", "UTC")
+        .arg("-d")
+        .arg("2024-01-01 10:00 2 days hence")
+        .arg("+%F %T")
+        .succeeds()
+        .stdout_is("2024-01-03 10:00:00\n");
+    new_ucmd!()
+        .arg("-d")
+        .arg("1 week HENCE")
+        .arg("--rfc-3339=seconds")
+        .succeeds()
+        .stdout_str_check(|out| {
+            let date = out.trim().parse::<Timestamp>().unwrap();
+            let expected_date = Timestamp::now() + (7 * 24).hours();
+            (date - expected_date).abs().compare(10.minutes()).unwrap() == Ordering::Less
+        });
+    // Like GNU, "hence" is only valid after a relative item.
+    new_ucmd!()
+        .arg("-d")
+        .arg("hence 3 days")
+        .fails()
+        .stderr_contains("invalid date");
+}
+
+#[test]
+fn test_abbreviations_with_period() {
+    for input in ["Mon. Jan. 1 2024", "jan.1 2024"] {
+        new_ucmd!()
+            .env("TZ", "UTC")
+            .arg("-d")
+            .arg(input)
+            .arg("+%F %a")
+            .succeeds()
+            .stdout_is("2024-01-01 Mon\n");
+    }
+    // Only three-letter abbreviations take a period.
+    new_ucmd!()
+        .arg("-d")
+        .arg("January. 1 2024")
+        .fails()
+        .stderr_contains("invalid date");
+}
+
 #[test]
 fn test_relative_weekdays() {
     // Truncate time component to midnight
@@ -897,6 +1018,29 @@ fn test_date_tz_with_relative_time() {
         .stdout_matches(&Regex::new(r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} P[DS]T\n$").unwrap());
 }
 
+#[test]
+fn test_date_tz_prefix_with_relative_time() {
+    // The time of day is kept, as without the prefix.
+    new_ucmd!()
+        .env("TZ", "UTC")
+        .arg("-d")
+        .arg("TZ=\"Asia/Tokyo\" tomorrow")
+        .arg("--rfc-3339=seconds")
+        .succeeds()
+        .stdout_str_check(|out| {
+            let date = out.trim().parse::<Timestamp>().unwrap();
+            let expected_date = Timestamp::now() + 24.hours();
+            (date - expected_date).abs().compare(10.minutes()).unwrap() == Ordering::Less
+        });
+    new_ucmd!()
+        .env("TZ", "UTC")
+        .arg("-d")
+        .arg("TZ=\"EST5\" 2024-01-01 10:00 +1 day")
+        .arg("+%F %T")
+        .succeeds()
+        .stdout_is("2024-01-02 15:00:00\n");
+}
+
 #[test]
 fn test_date_utc_time() {
     // Test that -u flag shows correct UTC time
@@ -1992,9 +2136,8 @@ fn test_date_strftime_case_flag_on_alt_ampm() {
 }
 
 #[test]
-#[ignore = "https://github.com/uutils/coreutils/issues/11658 — GNU date applies flags/widths to `%N` (nanoseconds); uutils ignores/mishandles them."]
 fn test_date_strftime_n_width_and_flags() {
-    // `%_3N` should space-pad nanoseconds to width 3. GNU outputs `0  `; uutils outputs `0`.
+    // `%_3N` space-pads the trailing zeros of the 3-digit fraction.
     new_ucmd!()
         .env("LC_ALL", "C")
         .env("TZ", "UTC")
@@ -2003,8 +2146,7 @@ fn test_date_strftime_n_width_and_flags() {
         .arg("+%_3N")
         .succeeds()
         .stdout_is("0  \n");
-    // `%-N` (no-padding flag) should still output the full 9-digit default.
-    // GNU: `000000000`; uutils: `0`.
+    // `%-N` (no-padding flag) still outputs the full 9-digit default.
     new_ucmd!()
         .env("LC_ALL", "C")
         .env("TZ", "UTC")
@@ -2013,6 +2155,32 @@ fn test_date_strftime_n_width_and_flags() {
         .arg("+%-N")
         .succeeds()
         .stdout_is("000000000\n");
+    // The width is a precision, and `-` then drops trailing zeros.
+    new_ucmd!()
+        .env("TZ", "UTC")
+        .arg("-d")
+        .arg("@0.05")
+        .arg("+%3N %-3N %_6N %12N %+4N")
+        .succeeds()
+        .stdout_is("050 05 05     050000000000 0500\n");
+}
+
+#[test]
+fn test_date_strftime_offset_without_padding() {
+    new_ucmd!()
+        .env("TZ", "America/St_Johns")
+        .arg("-d")
+        .arg("2024-01-01")
+        .arg("+%-z|%_z|%-:z|%_8:z|%-::z|%_:::z")
+        .succeeds()
+        .stdout_is("-330| -330|-3:30|   -3:30|-3:30:00| -3:30\n");
+    new_ucmd!()
+        .env("TZ", "UTC")
+        .arg("-d")
+        .arg("2024-01-01")
+        .arg("+%-z|%_z|%-:::z|%_:::z")
+        .succeeds()
+        .stdout_is("+0|   +0|+0| +0\n");
 }
 
 #[test]
@@ -2046,10 +2214,8 @@ fn test_date_strftime_o_modifier() {
 }
 
 #[test]
-#[ignore = "https://github.com/uutils/parse_datetime/issues/280 — GNU date accepts bare timezone abbreviations (UT, GMT, ...) meaning `now in that TZ`; parse_datetime rejects them."]
 fn test_date_bare_timezone_abbreviation() {
-    // GNU: `date -d ut`, `date -d UT`, `date -d gmt` → current time in UTC.
-    // uutils: "invalid date" error.
+    // GNU: `date -d ut`, `date -d UT`, `date -d gmt` → midnight today in UTC.
     for input in ["ut", "UT", "gmt", "GMT"] {
         new_ucmd!()
             .env("TZ", "UTC+1")
@@ -3082,15 +3248,47 @@ fn test_date_debug_with_flags() {
 
 #[test]
 fn test_date_debug_current_time() {
-    // Test that debug mode without -d doesn't produce debug output (no parsing)
-    let result = new_ucmd!()
+    // Without -d nothing is parsed, so like GNU only the output format is shown
+    new_ucmd!()
         .env("TZ", "UTC")
         .args(&["--debug", "+%Y"])
-        .succeeds();
+        .succeeds()
+        .stderr_is("date: output format: '%Y'\n");
+}
 
-    let stderr = result.stderr_str();
-    // No parsing happens for "now", so no debug output
-    assert_eq!(stderr, "");
+#[test]
+fn test_date_debug_final() {
+    new_ucmd!()
+        .env("TZ", "Asia/Kolkata")
+        .args(&["--debug", "-d", "2024-01-01 10:00 EST", "+%T"])
+        .succeeds()
+        .stdout_is("20:30:00\n")
+        .stderr_contains("date: input timezone: parsed date/time string (-05)\n")
+        .stderr_contains(
+            "date: timezone: TZ=\"Asia/Kolkata\" environment value\n\
+             date: final: 1704121200.000000000 (epoch-seconds)\n\
+             date: final: (Y-M-D) 2024-01-01 15:00:00 (UTC)\n\
+             date: final: (Y-M-D) 2024-01-01 20:30:00 (UTC+05:30)\n\
+             date: output format: '%T'\n",
+        );
+
+    new_ucmd!()
+        .env("TZ", "Europe/Paris")
+        .args(&["--debug", "-u", "-d", "@-1.5", "+%T"])
+        .succeeds()
+        .stdout_is("23:59:58\n")
+        .stderr_contains("date: input timezone: '@timespec' - always UTC\n")
+        .stderr_contains(
+            "date: timezone: Universal Time\n\
+             date: final: -2.500000000 (epoch-seconds)\n",
+        );
+
+    new_ucmd!()
+        .env("TZ", "UTC")
+        .args(&["--debug", "-d", "TZ=\"Asia/Tokyo\" 2024-01-01 10:00", "+%T"])
+        .succeeds()
+        .stdout_is("01:00:00\n")
+        .stderr_contains("date: input timezone: TZ=\"Asia/Tokyo\" in date string\n");
 }
 
 #[test]
@@ -650,6 +650,24 @@ fn test_block_size_with_suffix() {
     assert_eq!(get_header("1GB"), "1GB-blocks");
 }
 
+#[test]
+fn test_block_size_grouping() {
+    fn get_size(lc_all: &str) -> String {
+        let output = new_ucmd!()
+            .env("LC_ALL", lc_all)
+            .args(&["-B'1", "--output=size", "."])
+            .succeeds()
+            .stdout_str_lossy();
+        output.lines().nth(1).unwrap().trim().to_string()
+    }
+
+    // Any file system is larger than 1000 bytes.
+    let grouped = get_size("en_US.UTF-8");
+    let plain = get_size("C");
+    assert!(grouped.contains(','), "{grouped}");
+    assert!(plain.bytes().all(|b| b.is_ascii_digit()), "{plain}");
+}
+
 #[test]
 fn test_df_binary_block_size() {
     fn get_header(block_size: &str) -> String {
@@ -55,6 +55,14 @@ fn test_repeated_zero() {
         .stdout_only("foo\u{0}");
 }
 
+#[test]
+fn test_multiple_operands_zero() {
+    new_ucmd!()
+        .args(&["-z", "foo/bar", "baz", "/", "a//b//"])
+        .succeeds()
+        .stdout_only("foo\0.\0/\0a\0");
+}
+
 #[test]
 fn test_root() {
     new_ucmd!().arg("/").succeeds().stdout_is("/\n");
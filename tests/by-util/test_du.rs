@@ -4,7 +4,7 @@
 // file that was distributed with this source code.
 
 // spell-checker:ignore (paths) atim sublink subwords azerty azeaze xcwww azeaz amaz azea qzerty tazerty tsublink testfile1 testfile2 filelist fpath testdir testfile
-// spell-checker:ignore selfref ELOOP smallfile
+// spell-checker:ignore selfref ELOOP smallfile NOFILE
 
 #[cfg(not(windows))]
 use regex::Regex;
@@ -2445,6 +2445,30 @@ fn test_du_symlinks_multiple_links_in_args() {
     result.stdout_does_not_contain("dir1/link");
 }
 
+#[test]
+fn test_block_size_grouping() {
+    let ts = TestScenario::new(util_name!());
+    ts.fixtures.write("file", &"x".repeat(10_000));
+
+    ts.ucmd()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(&["--apparent-size", "-B'1", "file"])
+        .succeeds()
+        .stdout_only("10,000\tfile\n");
+    ts.ucmd()
+        .env("LC_ALL", "en_US.UTF-8")
+        .env("DU_BLOCK_SIZE", "'1")
+        .args(&["--apparent-size", "file"])
+        .succeeds()
+        .stdout_only("10,000\tfile\n");
+    // The C locale has no thousands separator.
+    ts.ucmd()
+        .env("LC_ALL", "C")
+        .args(&["--apparent-size", "-B'1", "file"])
+        .succeeds()
+        .stdout_only("10000\tfile\n");
+}
+
 #[test]
 fn test_block_size_args_override() {
     let ts = TestScenario::new(util_name!());
@@ -2806,3 +2830,21 @@ fn test_du_repeated_time_style() {
         .succeeds();
     result.stdout_only("0\t2016-06-16 00:00:00.000000000 +0000\tdate_test\n");
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_du_deep_tree_low_fd_limit() {
+    // The traversal must not keep one descriptor open per level.
+    use rlimit::Resource;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    let path = vec!["d"; 60].join("/");
+    at.mkdir_all(&path);
+    at.write(&format!("{path}/f"), "x");
+
+    ucmd.args(&["-a", "--apparent-size", "-b", "d"])
+        .limit(Resource::NOFILE, 20, 20)
+        .succeeds()
+        .stdout_contains(format!("1\t{path}/f\n"))
+        .no_stderr();
+}
@@ -238,6 +238,8 @@ fn test_debug1_part_of_string_arg() {
         .succeeds();
     result.stderr_matches(
         &Regex::new(concat!(
+            r"^split -S:  ' FOO=BAR'\n",
+            r" into:    'FOO=BAR'\n",
             r"executing: [^\n]+(\/|\\)coreutils(\.exe)?\n",
             r"   arg\[0\]= '[^\n]+(\/|\\)coreutils(\.exe)?'\n",
             r"   arg\[1\]= 'echo'\n",
@@ -265,6 +267,8 @@ fn test_debug2_part_of_string_arg() {
             r"arg\[2\]: '[^\n]+(\/|\\)coreutils(.exe)?'\n",
             r"arg\[3\]: 'echo'\n",
             r"arg\[4\]: 'hello2'\n",
+            r"split -S:  ' FOO=BAR'\n",
+            r" into:    'FOO=BAR'\n",
             r"executing: [^\n]+(\/|\\)coreutils(.exe)?\n",
             r"   arg\[0\]= '[^\n]+(\/|\\)coreutils(.exe)?'\n",
             r"   arg\[1\]= 'echo'\n",
@@ -393,6 +397,14 @@ fn test_null_delimiter() {
     assert_eq!(vars[2], "FOO=bar");
 }
 
+#[test]
+fn test_null_delimiter_keeps_newlines_in_values() {
+    new_ucmd!()
+        .args(&["-i", "-0", "FOO=a\nb", "BAR="])
+        .succeeds()
+        .stdout_is("FOO=a\nb\0BAR=\0");
+}
+
 #[test]
 fn test_unset_variable() {
     let out = TestScenario::new(util_name!())
@@ -482,6 +494,7 @@ fn test_fail_change_directory() {
         .fails()
         .stderr_move_str();
     assert!(out.contains("env: cannot change directory to "));
+    assert!(!out.contains("os error"), "unexpected error message: {out}");
 }
 
 #[test]
@@ -626,11 +639,42 @@ fn test_split_string_into_args_debug_output_whitespace_handling() {
     assert_eq!(
         out.stderr_str(),
         "input args:\narg[0]: 'env'\narg[1]: $\
-        '-vvS printf x%sx\\\\n A \\t B \\x0B\\x0C\\r\\n'\nexecuting: printf\
+        '-vvS printf x%sx\\\\n A \\t B \\x0B\\x0C\\r\\n'\nsplit -S:  $\
+        ' printf x%sx\\\\n A \\t B \\x0B\\x0C\\r\\n'\n into:    'printf'\
+        \n     &    $'x%sx\\n'\n     &    'A'\n     &    'B'\nexecuting: printf\
         \n   arg[0]= 'printf'\n   arg[1]= $'x%sx\\n'\n   arg[2]= 'A'\n   arg[3]= 'B'\n"
     );
 }
 
+#[cfg(not(target_os = "windows"))] // no printf available
+#[test]
+fn test_split_string_debug_output_for_each_string() {
+    new_ucmd!()
+        .args(&["-v", "-S", "-i A=1", "-S", "printf [%s] 'a b'"])
+        .succeeds()
+        .stdout_is("[a b]")
+        .stderr_is(
+            "split -S:  '-i A=1'\n into:    '-i'\n     &    'A=1'\n\
+            split -S:  \"printf [%s] 'a b'\"\n into:    'printf'\n     &    '[%s]'\
+            \n     &    'a b'\nexecuting: printf\n   arg[0]= 'printf'\
+            \n   arg[1]= '[%s]'\n   arg[2]= 'a b'\n",
+        );
+}
+
+#[cfg(not(target_os = "windows"))] // no printf available
+#[test]
+fn test_split_string_backslash_newline_is_invalid() {
+    new_ucmd!()
+        .args(&["-S", "printf x\\\ny"])
+        .fails_with_code(125)
+        .no_stdout()
+        .stderr_contains("invalid sequence '\\\n' in -S");
+    new_ucmd!()
+        .args(&["-S", "printf [%s] \"\\'\""])
+        .succeeds()
+        .stdout_is("[']");
+}
+
 // FixMe: This test fails on MACOS:
 // thread 'test_env::test_gnu_e20' panicked at 'assertion failed: `(left == right)`
 // left: `"A=B C=D\n__CF_USER_TEXT_ENCODING=0x1F5:0x0:0x0\n"`,
@@ -834,6 +878,19 @@ fn test_env_overwrite_arg0() {
         .stderr_is("");
 }
 
+#[test]
+#[cfg(unix)]
+fn test_env_argv0_debug_output() {
+    new_ucmd!()
+        .args(&["-v", "-a", "hijacked", "sh", "-c", "echo $0"])
+        .succeeds()
+        .stdout_is("hijacked\n")
+        .stderr_is(
+            "argv0:     'hijacked'\nexecuting: sh\n   arg[0]= 'hijacked'\n   arg[1]= '-c'\
+            \n   arg[2]= 'echo $0'\n",
+        );
+}
+
 // Do not assume that coreutils uses argv0
 #[test]
 #[cfg(unix)]
@@ -1070,6 +1127,99 @@ fn test_env_list_signal_handling_reports_ignore() {
     );
 }
 
+#[test]
+#[cfg(unix)]
+fn test_env_signal_options_last_one_wins() {
+    new_ucmd!()
+        .env("PATH", PATH)
+        .args(&[
+            "--default-signal",
+            "--ignore-signal=INT",
+            "--block-signal=INT,USR1",
+            "--default-signal=USR1",
+            "--list-signal-handling",
+            "true",
+        ])
+        .succeeds()
+        .stderr_only("INT        ( 2): BLOCK,IGNORE\n");
+    new_ucmd!()
+        .env("PATH", PATH)
+        .args(&[
+            "--default-signal",
+            "--ignore-signal=INT",
+            "--default-signal=INT",
+            "--list-signal-handling",
+            "true",
+        ])
+        .succeeds()
+        .no_output();
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_invalid_signal_is_usage_error() {
+    new_ucmd!()
+        .args(&["--ignore-signal=KILL,x", "true"])
+        .fails_with_code(125)
+        .usage_error("'x': invalid signal");
+    new_ucmd!()
+        .args(&["--block-signal=0", "true"])
+        .fails_with_code(125)
+        .usage_error("'0': invalid signal");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_env_signals_applied_without_command() {
+    new_ucmd!()
+        .args(&["-i", "--ignore-signal=INT", "--list-signal-handling"])
+        .succeeds()
+        .no_stdout()
+        .stderr_is("INT        ( 2): IGNORE\n");
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_env_debug_signal_handling_and_chdir() {
+    new_ucmd!()
+        .env("PATH", PATH)
+        .args(&[
+            "-v",
+            "--default-signal=PIPE",
+            "--ignore-signal=rtmax-1,kill",
+            "--ignore-signal",
+            "--block-signal=RTMIN+1",
+            "-C",
+            "/",
+            "true",
+        ])
+        .succeeds()
+        .stderr_contains("Reset signal KILL (9) to IGNORE (failure ignored)\n")
+        .stderr_contains(format!(
+            "Reset signal RTMAX-1 ({}) to IGNORE\n",
+            libc::SIGRTMAX() - 1
+        ));
+    new_ucmd!()
+        .env("PATH", PATH)
+        .args(&[
+            "-v",
+            "--default-signal=PIPE",
+            "--block-signal=RTMIN+1",
+            "-C",
+            "/",
+            "true",
+        ])
+        .succeeds()
+        .stderr_only(format!(
+            "Reset signal PIPE (13) to DEFAULT\n\
+            signal PIPE (13) mask set to UNBLOCK\n\
+            signal RTMIN+1 ({}) mask set to BLOCK\n\
+            chdir:    '/'\n\
+            executing: true\n   arg[0]= 'true'\n",
+            libc::SIGRTMIN() + 1
+        ));
+}
+
 #[cfg(unix)]
 fn run_sigpipe_script(ts: &TestScenario, extra_args: &[&str]) {
     let shell = env::var("SHELL").unwrap_or_else(|_| String::from("sh"));
@@ -1337,7 +1487,7 @@ mod tests_split_iterator {
             (r#""\"""#, &["\""]),
             (r#""\\""#, &["\\"]),
             ("\"\n\"", &["\n"]),
-            ("\"\\\n\"", &[""]),
+            (r#""\'""#, &["'"]),
         ]);
     }
 
@@ -1350,10 +1500,7 @@ mod tests_split_iterator {
             (r"\$", &[r"$"]),
             (r#"\""#, &[r#"""#]),
             (r"\'", &[r"'"]),
-            ("\\\n", &[]),
-            (" \\\n \n", &[]),
             ("a\nb\nc", &["a", "b", "c"]),
-            ("a\\\nb\\\nc", &["abc"]),
             ("foo bar baz", &["foo", "bar", "baz"]),
         ]);
     }
@@ -1381,6 +1528,30 @@ mod tests_split_iterator {
                 "Unquoted".into()
             ))
         );
+        assert_eq!(
+            split("\"\\"),
+            Err(EnvError::EnvInvalidBackslashAtEndOfStringInMinusS(
+                2,
+                "DoubleQuoted".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn split_backslash_newline_is_not_a_line_continuation() {
+        assert_eq!(
+            split("\\\n"),
+            Err(EnvError::EnvInvalidSequenceBackslashXInMinusS(1, '\n'))
+        );
+        assert_eq!(
+            split("a\\\nb"),
+            Err(EnvError::EnvInvalidSequenceBackslashXInMinusS(2, '\n'))
+        );
+        assert_eq!(
+            split("\"\\\n\""),
+            Err(EnvError::EnvInvalidSequenceBackslashXInMinusS(2, '\n'))
+        );
+        assert_eq!(split("'\\\n'"), Ok(vec![OsString::from("\\\n")]));
     }
 
     #[test]
@@ -2039,3 +2039,21 @@ fn test_emoji_operations() {
         .succeeds()
         .stdout_only("1\n");
 }
+
+#[cfg(any(target_os = "linux", target_os = "freebsd", target_os = "netbsd"))]
+#[test]
+fn test_reports_write_error_on_dev_full() {
+    use std::fs::OpenOptions;
+    use std::process::Stdio;
+
+    let dev_full = OpenOptions::new()
+        .write(true)
+        .open("/dev/full")
+        .expect("/dev/full must exist on supported targets");
+
+    new_ucmd!()
+        .args(&["1", "+", "1"])
+        .set_stdout(Stdio::from(dev_full))
+        .fails_with_code(1)
+        .stderr_only("expr: write error: No space left on device\n");
+}
@@ -50,3 +50,23 @@ fn test_hostname_domain_empty() {
         assert!(domain_short.stdout().is_empty());
     }
 }
+
+#[test]
+fn test_hostname_domain_is_fqdn_suffix() {
+    let fqdn = new_ucmd!().arg("-f").succeeds();
+    let domain = new_ucmd!().arg("-d").succeeds();
+
+    match fqdn.stdout_str().trim_end().split_once('.') {
+        Some((_, expected)) => domain.stdout_only(format!("{expected}\n")),
+        None => domain.no_output(),
+    };
+}
+
+#[test]
+fn test_hostname_fqdn_overrides_short() {
+    let fqdn = new_ucmd!().arg("-f").succeeds();
+    new_ucmd!()
+        .args(&["-s", "-f"])
+        .succeeds()
+        .stdout_only(fqdn.stdout_str());
+}
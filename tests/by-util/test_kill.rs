@@ -150,6 +150,29 @@ fn test_kill_list_rtmax_from_name() {
         .stdout_only(format!("{}\n", libc::SIGRTMAX()));
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+fn test_kill_table_shows_descriptions() {
+    new_ucmd!()
+        .arg("-t")
+        .succeeds()
+        .stdout_contains("\n 9 KILL     Killed\n")
+        .stdout_contains("\n15 TERM     Terminated\n");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_kill_table_of_given_signals() {
+    new_ucmd!()
+        .args(&["-t", "137", "HUP", "IAMNOTASIGNAL", "sigrtmin+2"])
+        .fails_with_code(1)
+        .stdout_is(format!(
+            " 9 KILL     Killed\n 1 HUP      Hangup\n{} RTMIN+2  Real-time signal 2\n",
+            libc::SIGRTMIN() + 2
+        ))
+        .stderr_is("kill: 'IAMNOTASIGNAL': invalid signal\n");
+}
+
 #[test]
 fn test_kill_list_one_signal_from_invalid_number() {
     new_ucmd!()
@@ -27,6 +27,8 @@ use std::{path::Path, time::SystemTime};
 use uutests::new_ucmd;
 #[cfg(unix)]
 use uutests::unwrap_or_return;
+#[cfg(all(unix, not(feature = "feat_selinux")))]
+use uutests::util::Normalizer;
 use uutests::util::TestScenario;
 #[cfg(any(unix, feature = "feat_selinux"))]
 use uutests::util::expected_result;
@@ -6166,6 +6168,40 @@ fn test_ls_block_size_si_file_size() {
         .stdout_contains(" 2 ");
 }
 
+#[test]
+fn test_ls_block_size_grouping() {
+    let scene = TestScenario::new(util_name!());
+    scene.fixtures.write_bytes("file", &[0u8; 10_000]);
+
+    scene
+        .ucmd()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(&["-l", "--block-size='1", "file"])
+        .succeeds()
+        .stdout_contains(" 10,000 ");
+    scene
+        .ucmd()
+        .env("LC_ALL", "en_US.UTF-8")
+        .env("LS_BLOCK_SIZE", "'1")
+        .args(&["-l", "file"])
+        .succeeds()
+        .stdout_contains(" 10,000 ");
+    // The C locale has no thousands separator.
+    scene
+        .ucmd()
+        .env("LC_ALL", "C")
+        .args(&["-l", "--block-size='1", "file"])
+        .succeeds()
+        .stdout_contains(" 10000 ");
+    // Human-readable sizes aren't grouped.
+    scene
+        .ucmd()
+        .env("LC_ALL", "en_US.UTF-8")
+        .args(&["-l", "--block-size='1", "-h", "file"])
+        .succeeds()
+        .stdout_contains(" 9.8K ");
+}
+
 #[test]
 fn test_ls_block_size_override_self() {
     new_ucmd!()
@@ -7068,6 +7104,25 @@ fn test_ls_time_style_env_full_iso() {
     );
 }
 
+#[test]
+#[cfg(all(unix, not(feature = "feat_selinux")))]
+fn test_ls_long_normalized() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("t1");
+    at.set_mode("t1", 0o644);
+
+    scene
+        .ucmd()
+        .env("TZ", "UTC")
+        .args(&["-l", "--time-style=full-iso", "t1"])
+        .succeeds()
+        .normalized_stdout_is(
+            "-rw-r--r-- 1 USER USER 0 TIMESTAMP +0000 t1\n",
+            &[Normalizer::timestamps(), Normalizer::users()],
+        );
+}
+
 #[test]
 fn test_ls_time_style_iso_recent_and_older() {
     let scene = TestScenario::new(util_name!());
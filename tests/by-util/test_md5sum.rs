@@ -339,6 +339,11 @@ fn test_invalid_arg() {
 fn test_conflicting_arg() {
     new_ucmd!().arg("--tag").arg("--check").fails_with_code(1);
     new_ucmd!().arg("--tag").arg("--text").fails_with_code(1);
+    new_ucmd!()
+        .arg("--zero")
+        .arg("--check")
+        .fails_with_code(1)
+        .stderr_contains("the --zero option is not supported when verifying checksums");
 }
 
 #[test]
@@ -830,3 +835,42 @@ fn test_check_md5_comment_leading_space() {
         .stdout_contains("foo: OK")
         .stderr_contains("WARNING: 1 line is improperly formatted");
 }
+
+#[test]
+fn test_many_files_keep_argument_order() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    let mut args = Vec::new();
+    for i in 0..32 {
+        let name = format!("f{i}");
+        at.write(&name, &"x".repeat(i * 1000));
+        args.push(name);
+    }
+    args.insert(10, "missing".to_string());
+    args.insert(20, "-".to_string());
+
+    let result = scene
+        .ccmd("md5sum")
+        .args(&args)
+        .pipe_in("")
+        .fails_with_code(1);
+    result.stderr_contains("md5sum: missing: ");
+
+    let names: Vec<&str> = result
+        .stdout_str()
+        .lines()
+        .map(|line| line.split_once("  ").unwrap().1)
+        .collect();
+    let expected: Vec<&str> = args
+        .iter()
+        .map(String::as_str)
+        .filter(|&name| name != "missing")
+        .collect();
+    assert_eq!(names, expected);
+    assert!(
+        result
+            .stdout_str()
+            .contains("d41d8cd98f00b204e9800998ecf8427e  -\n")
+    );
+}
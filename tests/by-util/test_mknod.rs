@@ -128,6 +128,37 @@ fn test_mknod_character_device_requires_major_and_minor() {
         .stderr_contains("invalid value 'c'");
 }
 
+#[test]
+fn test_mknod_invalid_device_number() {
+    for number in ["0x", "09", "0o8", "++1", "1a", "0xg"] {
+        new_ucmd!()
+            .args(&["test_file", "c", number, "1"])
+            .fails_with_code(1)
+            .no_stdout()
+            .stderr_contains(format!("invalid device number '{number}'"));
+    }
+}
+
+#[test]
+fn test_mknod_device_number_radix() {
+    let ts = TestScenario::new(util_name!());
+    for (name, major, minor) in [
+        ("hex", "0x1", "0x3"),
+        ("oct", "0o1", "03"),
+        ("dec", "1", "3"),
+        ("plus", "+1", "+0x3"),
+    ] {
+        if let Ok(result) = run_ucmd_as_root(&ts, &[name, "c", major, minor]) {
+            result.success().no_stdout();
+        } else {
+            print!("Test skipped; `mknod c 1 3` for null char dev requires root user");
+            break;
+        }
+        let rdev = std::os::unix::fs::MetadataExt::rdev(&ts.fixtures.metadata(name));
+        assert_eq!(rdev, uucore::fs::makedev(1, 3) as u64);
+    }
+}
+
 #[test]
 fn test_mknod_invalid_mode() {
     new_ucmd!()
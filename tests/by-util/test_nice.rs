@@ -4,17 +4,40 @@
 // file that was distributed with this source code.
 // spell-checker:ignore getpriority setpriority
 use uutests::new_ucmd;
+#[cfg(not(target_os = "android"))]
+use uutests::{util::TestScenario, util_name};
 
 #[test]
-#[cfg(not(target_os = "android"))]
+#[cfg(all(unix, not(target_os = "android")))]
 fn test_get_current_niceness() {
     // Test that the nice command with no arguments returns the default nice value
     let nice = rustix::process::getpriority_process(None).unwrap();
     new_ucmd!().succeeds().stdout_is(format!("{nice}\n"));
 }
 
+#[test]
+#[cfg(windows)]
+fn test_get_current_niceness() {
+    // Processes get the normal priority class by default
+    new_ucmd!().succeeds().stdout_is("0\n");
+}
+
 #[test]
 #[cfg(not(target_os = "android"))]
+fn test_command_gets_adjusted_niceness() {
+    // The idle priority class is reported as 19 on Windows
+    let scene = TestScenario::new(util_name!());
+    scene
+        .ucmd()
+        .args(&["-n", "19"])
+        .arg(&scene.bin_path)
+        .arg("nice")
+        .succeeds()
+        .stdout_is("19\n");
+}
+
+#[test]
+#[cfg(all(unix, not(target_os = "android")))]
 fn test_nice_adj_negative() {
     // This assumes the test suite is run as a normal (non-root) user, and as
     // such attempting to set a negative niceness value will be rejected by
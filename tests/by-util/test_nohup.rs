@@ -216,6 +216,15 @@ fn test_nohup_command_not_found() {
     );
 }
 
+#[test]
+fn test_nohup_exit_status_of_command() {
+    #[cfg(unix)]
+    let args = ["sh", "-c", "exit 3"];
+    #[cfg(windows)]
+    let args = ["cmd", "/C", "exit 3"];
+    new_ucmd!().args(&args).fails_with_code(3).no_output();
+}
+
 // Test stderr is redirected to stdout
 #[test]
 #[cfg(any(
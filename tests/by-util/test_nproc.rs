@@ -18,6 +18,20 @@ fn test_nproc() {
     assert!(nproc > 0);
 }
 
+#[test]
+fn test_nproc_not_above_all() {
+    // Affinity and cgroup quotas can only lower the number of processors.
+    let nproc: usize = new_ucmd!().succeeds().stdout_str().trim().parse().unwrap();
+    let all: usize = new_ucmd!()
+        .arg("--all")
+        .succeeds()
+        .stdout_str()
+        .trim()
+        .parse()
+        .unwrap();
+    assert!(nproc <= all);
+}
+
 #[test]
 fn test_nproc_all_omp() {
     let result = new_ucmd!().arg("--all").succeeds();
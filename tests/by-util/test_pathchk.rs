@@ -5,6 +5,7 @@
 #[cfg(target_os = "linux")]
 use std::os::unix::ffi::OsStringExt;
 #[cfg(unix)]
+use uutests::at_and_ucmd;
 use uutests::new_ucmd;
 
 #[test]
@@ -180,3 +181,69 @@ fn test_pathchk_non_utf8_paths() {
     let filename = std::ffi::OsString::from_vec(vec![0xFF, 0xFE]);
     new_ucmd!().arg(&filename).succeeds();
 }
+
+#[test]
+fn test_posix_messages() {
+    new_ucmd!()
+        .args(&["-P", "dir/-file"])
+        .fails_with_code(1)
+        .stderr_only("pathchk: leading '-' in a component of file name 'dir/-file'\n");
+    new_ucmd!()
+        .args(&["-P", ""])
+        .fails_with_code(1)
+        .stderr_only("pathchk: empty file name\n");
+    new_ucmd!()
+        .args(&["-p", "dir/a:b"])
+        .fails_with_code(1)
+        .stderr_only("pathchk: nonportable character ':' in file name 'dir/a:b'\n");
+    new_ucmd!()
+        .args(&["-p", "dir/abcdefghijklmno"])
+        .fails_with_code(1)
+        .stderr_only(
+            "pathchk: limit 14 exceeded by length 15 of file name component 'abcdefghijklmno'\n",
+        );
+    new_ucmd!()
+        .args(&["-p", &"ab/".repeat(100)])
+        .fails_with_code(1)
+        .stderr_contains("pathchk: limit 255 exceeded by length 300 of file name");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_default_mode_lstat_error() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.touch("file");
+    ucmd.arg("file/sub")
+        .fails_with_code(1)
+        .stderr_only("pathchk: file/sub: Not a directory\n");
+}
+
+#[test]
+fn test_windows() {
+    new_ucmd!()
+        .args(&["--windows", "dir/file.txt", "./../x"])
+        .succeeds()
+        .no_output();
+    new_ucmd!()
+        .args(&["--windows", "dir/NUL.txt"])
+        .fails_with_code(1)
+        .stderr_only(
+            "pathchk: component 'NUL.txt' of file name 'dir/NUL.txt' is a reserved device name on Windows\n",
+        );
+    new_ucmd!()
+        .args(&["--windows", "com1"])
+        .fails_with_code(1)
+        .stderr_contains("reserved device name");
+    new_ucmd!()
+        .args(&["--windows", "COM10"])
+        .succeeds()
+        .no_output();
+    new_ucmd!()
+        .args(&["--windows", "dir./file"])
+        .fails_with_code(1)
+        .stderr_only("pathchk: trailing dot or space in component 'dir.' of file name 'dir./file' is not allowed on Windows\n");
+    new_ucmd!()
+        .args(&["--windows", "dir/what?"])
+        .fails_with_code(1)
+        .stderr_only("pathchk: character '?' is not allowed on Windows in file name 'dir/what?'\n");
+}
@@ -7,7 +7,7 @@
 use jiff::{Timestamp, ToSpan};
 use regex::Regex;
 use std::fs::metadata;
-use uutests::util::UCommand;
+use uutests::util::{Normalizer, UCommand};
 use uutests::{at_and_ucmd, new_ucmd};
 
 const DATE_TIME_FORMAT_DEFAULT: &str = "%Y-%m-%d %H:%M";
@@ -125,6 +125,14 @@ fn test_with_long_header_option() {
         .stdout_matches(&regex);
 }
 
+#[test]
+fn test_with_header_normalized() {
+    new_ucmd!()
+        .args(&["-l", "15", "-h", "Title", "short.log"])
+        .succeeds()
+        .stdout_is_normalized_fixture("short.log.expected", &[Normalizer::timestamps()]);
+}
+
 #[test]
 fn test_with_double_space_option() {
     let test_file_path = "test_one_page.log";
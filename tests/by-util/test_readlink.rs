@@ -484,3 +484,42 @@ fn test_verbose_or_silent() {
         )
         .no_stdout();
 }
+
+#[test]
+#[cfg(not(windows))]
+fn test_multiple_operands_continue_after_error() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.relative_symlink_file("target1", "link1");
+    at.relative_symlink_file("target2", "link2");
+    at.touch("regfile");
+
+    scene
+        .ucmd()
+        .args(&["link1", "regfile", "link2"])
+        .fails_with_code(1)
+        .stdout_only("target1\ntarget2\n");
+    scene
+        .ucmd()
+        .args(&["-v", "-z", "link1", "missing", "link2"])
+        .fails_with_code(1)
+        .stderr_contains("missing: No such file or directory")
+        .stdout_is("target1\0target2\0");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn test_last_canonicalize_mode_wins() {
+    new_ucmd!()
+        .args(&["-e", "-m", "/missing/a"])
+        .succeeds()
+        .stdout_only("/missing/a\n");
+    new_ucmd!()
+        .args(&["-m", "-f", "/missing/a"])
+        .fails_with_code(1)
+        .no_output();
+    new_ucmd!()
+        .args(&["-m", "-e", "/missing"])
+        .fails_with_code(1)
+        .no_output();
+}
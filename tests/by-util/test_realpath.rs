@@ -160,6 +160,41 @@ fn test_realpath_logical_mode() {
         .stdout_contains("dir1\n");
 }
 
+#[test]
+fn test_realpath_logical_mode_requires_components_before_dotdot() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.mkdir("dir");
+    at.symlink_file("nowhere", "dir/dangling");
+
+    scene
+        .ucmd()
+        .args(&["-L", "dir/dangling/.."])
+        .fails_with_code(1)
+        .stderr_only("realpath: dir/dangling/..: No such file or directory\n");
+    scene
+        .ucmd()
+        .args(&["-L", "dir/missing/.."])
+        .fails_with_code(1)
+        .stderr_only("realpath: dir/missing/..: No such file or directory\n");
+    scene
+        .ucmd()
+        .args(&["-L", "-m", "dir/dangling/.."])
+        .succeeds()
+        .stdout_is(format!("{}\n", at.plus_as_string("dir")));
+    // Only the last component may be missing without -e.
+    scene
+        .ucmd()
+        .args(&["-L", "dir/missing"])
+        .succeeds()
+        .stdout_is(format!("{}\n", at.plus_as_string("dir/missing")));
+    scene
+        .ucmd()
+        .args(&["-L", "-e", "dir/missing"])
+        .fails_with_code(1);
+}
+
 #[test]
 fn test_realpath_dangling() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -119,6 +119,25 @@ fn test_rmdir_ignore_nonempty_directory_with_parents() {
     assert!(at.dir_exists(NESTED_DIR));
 }
 
+#[test]
+fn test_rmdir_parents_stop_at_nonempty_ancestor() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    at.mkdir_all(NESTED_DIR);
+    at.touch("dir/file");
+
+    ucmd.arg("-p")
+        .arg(NESTED_DIR)
+        .fails_with_code(1)
+        .no_stdout()
+        .stderr_is(format!(
+            "rmdir: failed to remove directory 'dir': {NOT_EMPTY}\n"
+        ));
+
+    assert!(!at.dir_exists("dir/ect"));
+    assert!(at.dir_exists(DIR));
+}
+
 #[test]
 fn test_rmdir_not_a_directory() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -180,7 +199,9 @@ fn test_verbose_nested_failure() {
             "rmdir: removing directory, 'dir/ect/ory'\n\
              rmdir: removing directory, 'dir/ect'\n",
         )
-        .stderr_is(format!("rmdir: failed to remove 'dir/ect': {NOT_EMPTY}\n"));
+        .stderr_is(format!(
+            "rmdir: failed to remove directory 'dir/ect': {NOT_EMPTY}\n"
+        ));
 }
 
 #[cfg(unix)]
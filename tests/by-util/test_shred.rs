@@ -171,6 +171,22 @@ fn test_hex() {
     ucmd.arg("--size=0x10").arg(file).succeeds();
 }
 
+#[test]
+fn test_shred_size_overwrites_requested_bytes() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    let file = "test_size";
+    at.write_bytes(file, &vec![b'x'; 70000]);
+
+    ucmd.args(&["-n", "0", "-z", "--size=5000", file])
+        .succeeds();
+
+    let content = at.read_bytes(file);
+    assert_eq!(content.len(), 70000);
+    assert!(content[..5000].iter().all(|&b| b == 0));
+    assert!(content[5000..].iter().all(|&b| b == b'x'));
+}
+
 #[test]
 fn test_shred_empty() {
     let scene = TestScenario::new(util_name!());
@@ -224,6 +224,70 @@ fn test_sleep_when_multiple_inputs_exceed_max_duration_then_no_error() {
         .no_output();
 }
 
+#[rstest]
+#[case::inf("inf")]
+#[case::infinity("infinity")]
+#[case::upper_case("INFINITY")]
+#[case::with_suffix("infd")]
+fn test_sleep_infinity(#[case] input: &str) {
+    let mut child = new_ucmd!()
+        .arg(input)
+        .timeout(Duration::from_secs(10))
+        .run_no_wait();
+
+    #[cfg(unix)]
+    child
+        .delay(100)
+        .kill()
+        .make_assertion()
+        .with_current_output()
+        .signal_is(9) // make sure it was us who terminated the process
+        .no_output();
+    #[cfg(windows)]
+    child
+        .delay(100)
+        .kill()
+        .make_assertion()
+        .with_current_output()
+        .failure()
+        .no_output();
+}
+
+#[test]
+fn test_sleep_sum_fractional_durations() {
+    let before_test = Instant::now();
+
+    new_ucmd!()
+        .args(&["0.05", "0.0001s", "0.0009", "0.049"])
+        .succeeds()
+        .no_output();
+
+    assert!(before_test.elapsed() >= Duration::from_millis(100));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_sleep_resumes_after_stop_and_continue() {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    let before_test = Instant::now();
+    let mut child = new_ucmd!()
+        .arg("0.5")
+        .timeout(Duration::from_secs(10))
+        .run_no_wait();
+    let pid = Pid::from_raw(child.id() as i32);
+
+    child.delay(100);
+    kill(pid, Signal::SIGSTOP).unwrap();
+    child.delay(100);
+    kill(pid, Signal::SIGCONT).unwrap();
+
+    child.make_assertion().is_alive();
+    child.wait().unwrap().success().no_output();
+    assert!(before_test.elapsed() >= Duration::from_millis(500));
+}
+
 #[rstest]
 #[case::whitespace_prefix(" 0.1s")]
 #[case::multiple_whitespace_prefix("   0.1s")]
@@ -141,6 +141,37 @@ fn test_legacy_plus_minus_accepts_with_size_max() {
         .stdout_is("aa\nbb\n");
 }
 
+#[test]
+fn test_legacy_plus_minus_outside_traditional_usage() {
+    let scene = TestScenario::new("sort");
+    scene.fixtures.write("input.txt", "b 1\na 2\n");
+
+    // `+POS1 -POS2` is still a key under POSIX 1003.1-2001, but `+POS1` alone is a file.
+    scene
+        .ucmd()
+        .env("_POSIX2_VERSION", "200112")
+        .args(&["+1", "-2", "input.txt"])
+        .succeeds()
+        .stdout_is("b 1\na 2\n");
+    scene
+        .ucmd()
+        .env("_POSIX2_VERSION", "200112")
+        .args(&["+1", "input.txt"])
+        .fails_with_code(2)
+        .stderr_contains("No such file or directory");
+}
+
+#[test]
+fn test_legacy_plus_minus_posixly_correct() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("input.txt", "b 1\na 2\n");
+
+    ucmd.env("_POSIX2_VERSION", "200112")
+        .env("POSIXLY_CORRECT", "1")
+        .args(&["+1", "-2", "input.txt"])
+        .fails_with_code(2);
+}
+
 #[test]
 fn test_ext_sort_stable() {
     new_ucmd!()
@@ -2,7 +2,7 @@
 //
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
-// spell-checker:ignore cmdline dyld dylib PDEATHSIG setvbuf
+// spell-checker:ignore cmdline dyld dylib PDEATHSIG setvbuf phentsize phnum phoff shentsize shnum shoff shstrndx ehsize filesz memsz paddr vaddr
 
 #[cfg(target_os = "linux")]
 use uutests::at_and_ucmd;
@@ -328,6 +328,132 @@ fn test_libstdbuf_preload() {
     );
 }
 
+#[cfg(target_os = "macos")]
+#[test]
+fn test_libstdbuf_preload_macos() {
+    // dyld reports every image it loads when DYLD_PRINT_LIBRARIES is set.
+    let scene = TestScenario::new(util_name!());
+    scene
+        .ucmd()
+        .env("DYLD_PRINT_LIBRARIES", "1")
+        .args(&["-oL"])
+        .arg(&scene.bin_path)
+        .args(&["echo", "test"])
+        .succeeds()
+        .stdout_is("test\n")
+        .stderr_contains("libstdbuf.dylib");
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+fn test_libstdbuf_preload_appended() {
+    let scene = TestScenario::new(util_name!());
+    let output = scene
+        .ucmd()
+        .env("LD_PRELOAD", "libstdbuf-test-missing.so")
+        .arg("-oL")
+        .arg(&scene.bin_path)
+        .args(&["printenv", "LD_PRELOAD"])
+        .succeeds()
+        .stdout_str()
+        .to_owned();
+    assert!(output.starts_with("libstdbuf-test-missing.so:"), "{output}");
+    assert!(output.trim_end().ends_with("libstdbuf.so"), "{output}");
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn test_stdbuf_warns_about_statically_linked_command() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // A minimal x86_64 ELF executable with a single PT_LOAD segment and no
+    // PT_INTERP, running `exit(0)`.
+    let code: &[u8] = &[0xb8, 0x3c, 0, 0, 0, 0x31, 0xff, 0x0f, 0x05];
+    let size = (64 + 56 + code.len()) as u64;
+    let mut elf = vec![0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    elf.extend(2u16.to_le_bytes()); // e_type: ET_EXEC
+    elf.extend(0x3eu16.to_le_bytes()); // e_machine: EM_X86_64
+    elf.extend(1u32.to_le_bytes()); // e_version
+    elf.extend(0x40_0078u64.to_le_bytes()); // e_entry
+    elf.extend(64u64.to_le_bytes()); // e_phoff
+    elf.extend(0u64.to_le_bytes()); // e_shoff
+    elf.extend(0u32.to_le_bytes()); // e_flags
+    for half in [64u16, 56, 1, 0, 0, 0] {
+        // e_ehsize, e_phentsize, e_phnum, e_shentsize, e_shnum, e_shstrndx
+        elf.extend(half.to_le_bytes());
+    }
+    elf.extend(1u32.to_le_bytes()); // p_type: PT_LOAD
+    elf.extend(5u32.to_le_bytes()); // p_flags: R+X
+    for word in [0u64, 0x40_0000, 0x40_0000, size, size, 0x1000] {
+        // p_offset, p_vaddr, p_paddr, p_filesz, p_memsz, p_align
+        elf.extend(word.to_le_bytes());
+    }
+    elf.extend(code);
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write_bytes("static", &elf);
+    std::fs::set_permissions(at.plus("static"), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    ucmd.args(&["-oL", "./static"])
+        .succeeds()
+        .no_stdout()
+        .stderr_is(
+            "stdbuf: warning: './static' is statically linked; its buffering will not be changed\n",
+        );
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn test_stdbuf_ignores_oversized_interpreter() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // An x86_64 ELF header with a PT_INTERP segment claiming to be 2^62 bytes
+    // long. The kernel refuses to run it, so it ends up being run by `sh`,
+    // which doesn't find a command named after its first line.
+    let mut elf = vec![0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    elf.extend(2u16.to_le_bytes()); // e_type: ET_EXEC
+    elf.extend(0x3eu16.to_le_bytes()); // e_machine: EM_X86_64
+    elf.extend(1u32.to_le_bytes()); // e_version
+    elf.extend(0x40_0078u64.to_le_bytes()); // e_entry
+    elf.extend(64u64.to_le_bytes()); // e_phoff
+    elf.extend(0u64.to_le_bytes()); // e_shoff
+    elf.extend(0u32.to_le_bytes()); // e_flags
+    for half in [64u16, 56, 1, 0, 0, 0] {
+        // e_ehsize, e_phentsize, e_phnum, e_shentsize, e_shnum, e_shstrndx
+        elf.extend(half.to_le_bytes());
+    }
+    elf.extend(3u32.to_le_bytes()); // p_type: PT_INTERP
+    elf.extend(4u32.to_le_bytes()); // p_flags: R
+    for word in [120u64, 0, 0, 1 << 62, 1 << 62, 1] {
+        // p_offset, p_vaddr, p_paddr, p_filesz, p_memsz, p_align
+        elf.extend(word.to_le_bytes());
+    }
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write_bytes("oversized", &elf);
+    std::fs::set_permissions(at.plus("oversized"), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    ucmd.args(&["-oL", "./oversized"])
+        .fails_with_code(127)
+        .stderr_does_not_contain("warning");
+}
+
+// The test binary is statically linked on musl.
+#[cfg(target_os = "linux")]
+#[cfg(not(target_env = "musl"))]
+#[test]
+fn test_stdbuf_no_warning_for_dynamic_command() {
+    let scene = TestScenario::new(util_name!());
+    scene
+        .ucmd()
+        .arg("-oL")
+        .arg(&scene.bin_path)
+        .args(&["echo", "test"])
+        .succeeds()
+        .stdout_is("test\n")
+        .no_stderr();
+}
+
 #[cfg(target_os = "linux")]
 #[cfg(not(target_env = "musl"))]
 #[test]
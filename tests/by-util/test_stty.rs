@@ -611,6 +611,61 @@ fn test_set_control_flags() {
         .stdout_contains("cstopb");
 }
 
+#[test]
+#[cfg(unix)]
+fn test_unchanged_by_sane_flags_only_shown_with_all() {
+    let (path, _controller, _replica) = pty_path();
+    new_ucmd!()
+        .args(&["--file", &path, "ignpar", "istrip", "-icanon"])
+        .succeeds();
+
+    new_ucmd!()
+        .args(&["--file", &path])
+        .succeeds()
+        .stdout_does_not_contain("ignpar")
+        .stdout_does_not_contain("istrip")
+        .stdout_does_not_contain("ixon")
+        .stdout_contains("min = 1; time = 0;")
+        .stdout_contains("-icanon");
+
+    new_ucmd!()
+        .args(&["--all", "--file", &path])
+        .succeeds()
+        .stdout_contains(" ignpar ")
+        .stdout_contains(" istrip ")
+        .stdout_does_not_contain("tandem");
+}
+
+#[test]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn test_combo_lcase() {
+    let (path, _controller, _replica) = pty_path();
+    new_ucmd!().args(&["--file", &path, "lcase"]).succeeds();
+    new_ucmd!()
+        .args(&["--file", &path])
+        .succeeds()
+        .stdout_contains("iuclc")
+        .stdout_contains("olcuc")
+        .stdout_contains("xcase");
+
+    new_ucmd!().args(&["--file", &path, "-lcase"]).succeeds();
+    new_ucmd!()
+        .args(&["--all", "--file", &path])
+        .succeeds()
+        .stdout_contains("-iuclc")
+        .stdout_contains("-olcuc")
+        .stdout_contains("-xcase");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_nonexistent_device() {
+    new_ucmd!()
+        .args(&["--file", "/nonexistent"])
+        .fails_with_code(1)
+        .stderr_only("stty: /nonexistent: No such file or directory\n");
+}
+
 // Tests for saved state parsing and restoration
 #[test]
 #[cfg(unix)]
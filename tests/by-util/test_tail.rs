@@ -803,6 +803,46 @@ fn test_follow_with_pid() {
         .success();
 }
 
+#[test]
+#[cfg(all(
+    not(target_vendor = "apple"),
+    not(target_os = "windows"),
+    not(target_os = "android"),
+    not(target_os = "freebsd")
+))] // FIXME: for currently not working platforms
+fn test_follow_with_multiple_pids() {
+    use std::process::Command;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.touch("f");
+
+    let mut first = Command::new("sleep").arg("60").spawn().unwrap();
+    let mut second = Command::new("sleep").arg("60").spawn().unwrap();
+
+    let mut child = ucmd
+        .arg("-f")
+        .arg(format!("--pid={}", first.id()))
+        .arg(format!("--pid={}", second.id()))
+        .arg("f")
+        .run_no_wait();
+
+    // tail keeps following as long as one of the processes is alive
+    first.kill().unwrap();
+    let _ = first.wait();
+    child
+        .make_assertion_with_delay(DEFAULT_SLEEP_INTERVAL_MILLIS * 2)
+        .is_alive();
+
+    second.kill().unwrap();
+    let _ = second.wait();
+    child
+        .make_assertion_with_delay(DEFAULT_SLEEP_INTERVAL_MILLIS * 2)
+        .is_not_alive()
+        .with_current_output()
+        .no_output()
+        .success();
+}
+
 #[test]
 fn test_single_big_args() {
     const FILE: &str = "single_big_args.txt";
@@ -1179,6 +1219,24 @@ fn test_obsolete_syntax_zero_lines_file() {
         .no_output();
 }
 
+/// Test that `tail +2` names a file when `_POSIX2_VERSION` selects POSIX 1003.1-2001.
+#[test]
+fn test_obsolete_syntax_plus_posix2_version() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("+2", "a\nb\n");
+
+    ucmd.env("_POSIX2_VERSION", "200112")
+        .arg("+2")
+        .succeeds()
+        .stdout_only("a\nb\n");
+    new_ucmd!()
+        .env("_POSIX2_VERSION", "200809")
+        .arg("+2")
+        .pipe_in("a\nb\n")
+        .succeeds()
+        .stdout_only("b\n");
+}
+
 /// Test for reading all lines, specified by `tail -n +0`.
 #[test]
 fn test_positive_zero_lines() {
@@ -9,7 +9,7 @@ use rstest::rstest;
 use std::time::Duration;
 use uucore::display::Quotable;
 use uutests::util::TestScenario;
-use uutests::{new_ucmd, util_name};
+use uutests::{at_and_ucmd, new_ucmd, util_name};
 
 #[test]
 fn test_invalid_arg() {
@@ -234,7 +234,11 @@ fn test_command_not_found() {
     // Test exit code 127 when command doesn't exist
     new_ucmd!()
         .args(&["1", "/this/command/definitely/does/not/exist"])
-        .fails_with_code(127);
+        .fails_with_code(127)
+        .stderr_is(
+            "timeout: failed to run command '/this/command/definitely/does/not/exist': \
+             No such file or directory\n",
+        );
 }
 
 #[test]
@@ -314,3 +318,90 @@ fn test_realtime_signal_names() {
         .fails()
         .stderr_contains("sending signal RTMAX to command");
 }
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_preserve_status_realtime_signal() {
+    new_ucmd!()
+        .args(&["--preserve-status", "-s", "RTMIN+1", ".1", "sleep", "10"])
+        .fails_with_code(128 + libc::SIGRTMIN() + 1)
+        .no_output();
+}
+
+#[test]
+fn test_kill_signal_exit_status() {
+    // SIGKILL cannot be handled, so the status is 128 + 9 rather than 124.
+    for args in [
+        &["-s", "KILL", ".1", "sleep", "10"][..],
+        &["--foreground", "-s", "KILL", ".1", "sleep", "10"],
+    ] {
+        new_ucmd!().args(args).fails_with_code(137).no_output();
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_kill_after_ignored_signal() {
+    for arg in ["-k.1", "--kill-after=.1"] {
+        new_ucmd!()
+            .args(&[arg, ".1", "sh", "-c", "trap '' TERM; sleep 10"])
+            .fails_with_code(137)
+            .no_output();
+    }
+}
+
+#[test]
+fn test_signal_and_kill_after_last_one_wins() {
+    new_ucmd!()
+        .args(&[
+            "-s",
+            "INT",
+            "-s",
+            "TERM",
+            "--preserve-status",
+            ".1",
+            "sleep",
+            "10",
+        ])
+        .fails_with_code(128 + 15)
+        .no_output();
+    new_ucmd!()
+        .args(&["-k", "10", "-k", ".1", "-s", "CONT", ".1", "sleep", "10"])
+        .fails_with_code(137)
+        .no_output();
+}
+
+#[test]
+#[cfg(unix)]
+fn test_foreground_does_not_create_process_group() {
+    // Without --foreground, timeout leads its own process group, which the command joins.
+    let script = "kill -0 -$PPID 2>/dev/null && echo leader || echo member";
+    new_ucmd!()
+        .args(&["10", "sh", "-c", script])
+        .succeeds()
+        .stdout_is("leader\n");
+    new_ucmd!()
+        .args(&["--foreground", "10", "sh", "-c", script])
+        .succeeds()
+        .stdout_is("member\n");
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_kill_after_kills_orphaned_descendants() {
+    // The background shell ignores the signal and outlives the command.
+    let (at, mut ucmd) = at_and_ucmd!();
+    ucmd.args(&[
+        "-k",
+        ".5",
+        ".5",
+        "sh",
+        "-c",
+        "sh -c 'trap \"\" TERM; echo $$ > pid; while :; do sleep .05; done' & sleep 10",
+    ])
+    .fails_with_code(124)
+    .no_output();
+
+    let pid = at.read("pid");
+    assert!(!std::path::Path::new(&format!("/proc/{}", pid.trim())).exists());
+}
@@ -638,6 +638,64 @@ fn test_touch_set_date_without_leading_zeroes() {
     assert_eq!(mtime, expected);
 }
 
+#[test]
+fn test_touch_set_date_uses_local_time_zone() {
+    // Zone-less dates are local wall-clock times, whichever format matched.
+    for date in [
+        "2004-01-16 12:00:00",
+        "2004-01-16 12:00:00.0",
+        "Fri Jan 16 12:00:00 2004",
+        "2004-01-16 12:00",
+    ] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        ucmd.env("TZ", "UTC-8")
+            .args(&["-d", date, "f"])
+            .succeeds()
+            .no_output();
+        let expected = FileTime::from_unix_time(1_074_225_600, 0);
+        assert_eq!(get_file_times(&at, "f"), (expected, expected), "{date}");
+    }
+}
+
+#[test]
+fn test_touch_set_date_with_offset() {
+    for date in [
+        "2004-01-16 12:00 +0530",
+        "2004-01-16T12:00:00+05:30",
+        "2004-01-16 12:00:00 +05:30",
+    ] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        ucmd.env("TZ", "UTC-8")
+            .args(&["-d", date, "f"])
+            .succeeds()
+            .no_output();
+        let expected = FileTime::from_unix_time(1_074_234_600, 0);
+        assert_eq!(get_file_times(&at, "f"), (expected, expected), "{date}");
+    }
+}
+
+#[test]
+fn test_touch_set_date_relative_to_reference() {
+    // 2015-01-01 12:34 UTC was a Thursday.
+    let start = str_to_filetime("%Y%m%d%H%M", "201501011234");
+    for (date, expected) in [
+        ("2 days ago", "201412301234"),
+        ("next fri", "201501020000"),
+        ("next monday 09:00", "201501050900"),
+        ("last fri + 3 days", "201412290000"),
+        ("tomorrow 14:00", "201501021400"),
+    ] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        at.touch("ref");
+        set_file_times(&at, "ref", start, start);
+        ucmd.args(&["-r", "ref", "-d", date, "f"])
+            .succeeds()
+            .no_output();
+        let expected = str_to_filetime("%Y%m%d%H%M", expected);
+        assert_eq!(get_file_times(&at, "f"), (expected, expected), "{date}");
+    }
+}
+
 /// Regression test for https://github.com/uutils/coreutils/issues/11804
 ///
 /// Setting a pre-epoch date like `0000-01-01` used to panic on 32-bit targets
@@ -1067,6 +1125,66 @@ fn test_touch_symlink_with_no_deref() {
     assert_eq!(time, get_symlink_times(&at, symlink).1);
 }
 
+#[test]
+#[cfg(not(target_os = "freebsd"))]
+fn test_touch_time_word_with_no_deref_dangling() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    let symlink = "dangling";
+    let time = FileTime::from_unix_time(123, 0);
+
+    at.relative_symlink_file("nowhere", symlink);
+    set_symlink_file_times(at.plus(symlink), time, time).unwrap();
+
+    scene
+        .ucmd()
+        .args(&["-h", "--time=mtime", "-t", "200001010000", symlink])
+        .succeeds()
+        .no_output();
+    let (atime, mtime) = get_symlink_times(at, symlink);
+    assert_eq!(time, atime);
+    assert_ne!(time, mtime);
+    assert!(!at.file_exists("nowhere"));
+
+    scene
+        .ucmd()
+        .args(&["-h", "--time=access", "-t", "200001010000", symlink])
+        .succeeds()
+        .no_output();
+    assert_eq!((mtime, mtime), get_symlink_times(at, symlink));
+}
+
+#[test]
+#[cfg(not(target_os = "freebsd"))]
+fn test_touch_no_deref_access_or_modification_only() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    let old = FileTime::from_unix_time(123, 0);
+    let new = str_to_filetime("%Y%m%d%H%M", "200001010000");
+
+    at.touch("file");
+    at.symlink_file("file", "link");
+    set_file_times(at, "file", old, old);
+    set_symlink_file_times(at.plus("link"), old, old).unwrap();
+
+    scene
+        .ucmd()
+        .args(&["-h", "-a", "-t", "200001010000", "link"])
+        .succeeds()
+        .no_output();
+    assert_eq!((new, old), get_symlink_times(at, "link"));
+
+    set_symlink_file_times(at.plus("link"), old, old).unwrap();
+    scene
+        .ucmd()
+        .args(&["-h", "-m", "-t", "200001010000", "link"])
+        .succeeds()
+        .no_output();
+    assert_eq!((old, new), get_symlink_times(at, "link"));
+
+    assert_eq!((old, old), get_file_times(at, "file"));
+}
+
 #[test]
 #[cfg(not(target_os = "freebsd"))]
 fn test_touch_reference_symlink_with_no_deref() {
@@ -26,12 +26,33 @@ fn test_uname_name() {
 }
 
 #[test]
+#[cfg(not(any(
+    target_vendor = "apple",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+)))]
 fn test_uname_processor() {
     let result = new_ucmd!().arg("-p").succeeds();
     assert_eq!(result.stdout_str().trim_end(), "unknown");
 }
 
 #[test]
+#[cfg(target_vendor = "apple")]
+fn test_uname_processor_macos() {
+    let expected = if cfg!(target_arch = "aarch64") {
+        "arm"
+    } else {
+        "i386"
+    };
+    new_ucmd!()
+        .arg("-p")
+        .succeeds()
+        .stdout_only(format!("{expected}\n"));
+}
+
+#[test]
+#[cfg(not(any(target_os = "freebsd", target_os = "netbsd", target_os = "dragonfly")))]
 fn test_uname_hardware_platform() {
     new_ucmd!()
         .arg("-i")
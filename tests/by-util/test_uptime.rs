@@ -312,6 +312,13 @@ fn test_uptime_pretty_print() {
         .stdout_contains("minute");
 }
 
+#[test]
+fn test_uptime_pretty_print_format() {
+    let re = Regex::new(r"^up \d+ (day|hour|minute)s?(, \d+ (hour|minute)s?)*\n$").unwrap();
+
+    new_ucmd!().arg("--pretty").succeeds().stdout_matches(&re);
+}
+
 /// Test uptime reliability on macOS with sysctl kern.boottime fallback.
 /// This addresses intermittent failures from issue #3621 by ensuring
 /// the command consistently succeeds when utmpx data is unavailable.
@@ -31,6 +31,16 @@ fn test_users_no_arg() {
     new_ucmd!().succeeds();
 }
 
+#[test]
+#[cfg(windows)]
+fn test_users_file_operand_windows() {
+    new_ucmd!()
+        .arg("wtmp")
+        .fails_with_code(1)
+        .no_stdout()
+        .stderr_is("users: 'wtmp': Windows has no file of login records\n");
+}
+
 #[test]
 #[cfg(any(target_vendor = "apple", target_os = "linux"))]
 #[ignore = "issue #3219"]
@@ -278,3 +278,34 @@ fn test_sorted_utils() {
     .unwrap();
     assert!(out.lines().filter(|s| !s.is_empty()).is_sorted());
 }
+
+#[test]
+#[cfg(all(unix, feature = "true"))]
+fn test_install_links() {
+    let s = TestScenario::new("install_links");
+    let dir = s.fixtures.plus("bin");
+    std::fs::create_dir(&dir).unwrap();
+    s.fixtures.write("bin/false", "not a link");
+
+    let status = std::process::Command::new(&s.bin_path)
+        .arg("--install")
+        .arg(&dir)
+        .status()
+        .unwrap();
+    assert_eq!(status.code(), Some(1));
+    assert!(dir.join("true").is_symlink());
+    assert_eq!(s.fixtures.read("bin/false"), "not a link");
+
+    // Running it again keeps the existing links.
+    std::fs::remove_file(dir.join("false")).unwrap();
+    let status = std::process::Command::new(&s.bin_path)
+        .arg("--install")
+        .arg(&dir)
+        .status()
+        .unwrap();
+    assert_eq!(status.code(), Some(0));
+    let status = std::process::Command::new(dir.join("true"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
@@ -638,6 +638,31 @@ impl CmdResult {
         self.stdout_is_any(&possible_values.collect::<Vec<_>>());
     }
 
+    /// Like `stdout_is`, but every [`Normalizer`] is first applied to stdout, in
+    /// order, so that varying parts such as timestamps, user names or paths can be
+    /// compared against a placeholder.
+    #[track_caller]
+    pub fn normalized_stdout_is<T: AsRef<str>>(&self, msg: T, normalizers: &[Normalizer]) -> &Self {
+        let mut stdout = self.stdout_str().to_string();
+        for normalizer in normalizers {
+            stdout = normalizer.apply(&stdout);
+        }
+        assert_eq!(stdout, msg.as_ref());
+        self
+    }
+
+    /// like `stdout_is_fixture()`, but normalizes stdout with `normalizers` as
+    /// `normalized_stdout_is()` does
+    #[track_caller]
+    pub fn stdout_is_normalized_fixture<T: AsRef<OsStr>>(
+        &self,
+        file_rel_path: T,
+        normalizers: &[Normalizer],
+    ) -> &Self {
+        let contents = read_scenario_fixture(self.tmpd.as_ref(), file_rel_path);
+        self.normalized_stdout_is(String::from_utf8(contents).unwrap(), normalizers)
+    }
+
     /// assert that the command resulted in stderr stream output that equals the
     /// passed in value.
     ///
@@ -931,6 +956,62 @@ impl CmdResult {
     }
 }
 
+/// A replacement applied to the output of a command before it is compared, see
+/// [`CmdResult::normalized_stdout_is`].
+pub struct Normalizer {
+    regex: regex::Regex,
+    replacement: String,
+}
+
+impl Normalizer {
+    /// Replace every match of `pattern` with `replacement`, which may refer to
+    /// capture groups as in [`regex::Regex::replace_all`].
+    pub fn new(pattern: &str, replacement: &str) -> Self {
+        Self {
+            regex: regex::Regex::new(pattern).unwrap(),
+            replacement: replacement.to_string(),
+        }
+    }
+
+    /// Replace the timestamps printed by `ls -l` and `stat` with `TIMESTAMP`:
+    /// `Mar 11 21:47`, `Mar 11  2019`, `03-11 21:47` and `2020-03-11 21:47`
+    /// with optional seconds and nanoseconds. The time zone is kept.
+    pub fn timestamps() -> Self {
+        Self::new(
+            r"\d{4}-\d{2}-\d{2}(?: \d{2}:\d{2}(?::\d{2}(?:\.\d+)?)?)?|\d{2}-\d{2} \d{2}:\d{2}|[A-Z][a-z]{2} [ \d]\d (?:\d{2}:\d{2}| \d{4})",
+            "TIMESTAMP",
+        )
+    }
+
+    /// Replace the names of the current user and of its group with `USER`.
+    pub fn users() -> Self {
+        #[cfg(unix)]
+        let names = [
+            uucore::entries::uid2usr(uucore::process::geteuid()).unwrap_or_else(|_| whoami()),
+            uucore::entries::gid2grp(uucore::process::getegid()).unwrap_or_else(|_| whoami()),
+        ];
+        #[cfg(not(unix))]
+        let names = [whoami()];
+        let names = names.iter().map(|name| regex::escape(name));
+        Self::new(
+            &format!(r"\b(?:{})\b", names.collect::<Vec<_>>().join("|")),
+            "USER",
+        )
+    }
+
+    /// Replace `path` with `PATH`, e.g. to hide the temporary directory of a
+    /// [`TestScenario`].
+    pub fn path<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(&regex::escape(&path.as_ref().to_string_lossy()), "PATH")
+    }
+
+    fn apply(&self, text: &str) -> String {
+        self.regex
+            .replace_all(text, self.replacement.as_str())
+            .into_owned()
+    }
+}
+
 pub fn log_info<T: AsRef<str>, U: AsRef<str>>(msg: T, par: U) {
     println!("{}: {}", msg.as_ref(), par.as_ref());
 }
@@ -3455,6 +3536,32 @@ mod tests {
         res.stdout_does_not_match(&positive);
     }
 
+    #[test]
+    fn test_normalized_stdout_is() {
+        let res = run_cmd("echo 2024-01-31 12:34 file");
+
+        res.normalized_stdout_is(
+            "TIMESTAMP file\n",
+            &[Normalizer::timestamps(), Normalizer::new("\r\n", "\n")],
+        );
+    }
+
+    #[test]
+    fn test_normalizers() {
+        let timestamps = Normalizer::timestamps();
+        std::assert_eq!(timestamps.apply("Mar 11 21:47 f"), "TIMESTAMP f");
+        std::assert_eq!(timestamps.apply("Mar  1  2019 f"), "TIMESTAMP f");
+        std::assert_eq!(timestamps.apply("03-11 21:47 f"), "TIMESTAMP f");
+        std::assert_eq!(timestamps.apply("2019-03-11  f"), "TIMESTAMP  f");
+        std::assert_eq!(
+            timestamps.apply("2020-03-11 21:47:21.711337459 +0000 f"),
+            "TIMESTAMP +0000 f"
+        );
+
+        let path = Normalizer::path("/tmp/a.b");
+        std::assert_eq!(path.apply("/tmp/a.b/f /tmp/aXb"), "PATH/f /tmp/aXb");
+    }
+
     #[test]
     #[cfg(unix)]
     fn test_parse_coreutil_version() {